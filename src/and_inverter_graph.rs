@@ -0,0 +1,509 @@
+/** This file stores and-inverter graphs (AIGs) and reads and writes them in the AIGER format used by hardware
+    verification tools.
+    Author: Steven Fletcher
+    Created: 10/18/2026
+    Last Updated: 10/18/2026
+*/
+use crate::logic::*;
+use std::collections::HashMap;
+
+///AIG literals follow the AIGER convention: variable v is the literal 2v, and its negation is 2v + 1.  Variable 0 is
+///the constant False.
+pub type AigLiteral = u32;
+
+///The literal for the constant False.
+pub const AIG_FALSE : AigLiteral = 0;
+///The literal for the constant True.
+pub const AIG_TRUE : AigLiteral = 1;
+
+///This struct stores an and-inverter graph.  Every gate is a two-input AND, and negation is a bit on the edges.
+///Structural hashing guarantees that no two AND gates have the same inputs, and constants are propagated as gates are
+///created, so no gate has a constant input.
+///
+///Inputs are the variables 1 to num_inputs, so input i is boolean i of a SimpleLogicNode.  AND gates are the variables
+///after the inputs, in the order they were created.  Every gate's inputs are created before the gate, which is the
+///order AIGER needs.
+pub struct AndInverterGraph {
+    num_inputs : u32,
+    and_gate_vec : Vec<(AigLiteral, AigLiteral)>,  //and_gate_vec[i] is the inputs of variable num_inputs + 1 + i
+    structural_hash_table : HashMap<(AigLiteral, AigLiteral), AigLiteral>,
+    output_vec : Vec<AigLiteral>
+} //End struct AndInverterGraph
+
+impl AndInverterGraph {
+    ///Creates an AIG with no gates or outputs.
+    pub fn new(num_inputs : u32) -> AndInverterGraph {
+        AndInverterGraph {
+            num_inputs : num_inputs,
+            and_gate_vec : Vec::new(),
+            structural_hash_table : HashMap::new(),
+            output_vec : Vec::new()
+        }
+    } //End new
+
+    pub fn get_num_inputs(&self) -> u32 {self.num_inputs}
+    pub fn get_num_and_gates(&self) -> u32 {self.and_gate_vec.len() as u32}
+    pub fn get_outputs(&self) -> &Vec<AigLiteral> {&self.output_vec}
+
+    ///Gets the largest variable index, the M in the AIGER header.
+    pub fn get_max_variable_index(&self) -> u32 {self.num_inputs + self.get_num_and_gates()}
+
+    ///Gets the literal of an input.
+    ///input_index is the index of the input (1 to num_inputs)
+    pub fn get_input_literal(&self, input_index : u32) -> AigLiteral {
+        assert!(input_index >= 1 && input_index <= self.num_inputs, "AIG input {} doesn't exist", input_index);
+        2 * input_index
+    } //End get_input_literal
+
+    ///Gets the inputs of an AND gate, or None if the literal isn't an AND gate.
+    pub fn get_and_gate_inputs(&self, literal : AigLiteral) -> Option<(AigLiteral, AigLiteral)> {
+        let variable = literal / 2;
+        if variable <= self.num_inputs {None}
+        else {Some(self.and_gate_vec[(variable - self.num_inputs - 1) as usize])}
+    } //End get_and_gate_inputs
+
+    ///Adds an output.
+    pub fn add_output(&mut self, literal : AigLiteral) {
+        self.output_vec.push(literal);
+    } //End add_output
+
+    //CONSTRUCTION
+    ///Negates a literal.
+    pub fn negate(literal : AigLiteral) -> AigLiteral {literal ^ 1}
+
+    ///Gets the AND of two literals.  Constants and trivial cases are simplified, and an existing gate with the same
+    ///inputs is reused.
+    pub fn create_and(&mut self, left : AigLiteral, right : AigLiteral) -> AigLiteral {
+        //Constant propagation
+        if left == AIG_FALSE || right == AIG_FALSE || left == AndInverterGraph::negate(right) {return AIG_FALSE;}
+        if left == AIG_TRUE || left == right {return right;}
+        if right == AIG_TRUE {return left;}
+
+        //Structural hashing.  The larger input comes first, like in AIGER.
+        let key = (left.max(right), left.min(right));
+        if let Some(existing_gate) = self.structural_hash_table.get(&key) {return *existing_gate;}
+
+        self.and_gate_vec.push(key);
+        let new_gate = 2 * self.get_max_variable_index();
+        self.structural_hash_table.insert(key, new_gate);
+        new_gate
+    } //End create_and
+
+    ///Gets the OR of two literals using De Morgan's laws.
+    pub fn create_or(&mut self, left : AigLiteral, right : AigLiteral) -> AigLiteral {
+        let and_gate = self.create_and(AndInverterGraph::negate(left), AndInverterGraph::negate(right));
+        AndInverterGraph::negate(and_gate)
+    } //End create_or
+
+    ///Gets the exclusive OR of two literals.  It takes three AND gates.
+    pub fn create_exclusive_or(&mut self, left : AigLiteral, right : AigLiteral) -> AigLiteral {
+        let left_only = self.create_and(left, AndInverterGraph::negate(right));
+        let right_only = self.create_and(AndInverterGraph::negate(left), right);
+        self.create_or(left_only, right_only)
+    } //End create_exclusive_or
+
+    ///Builds the AIG of a SimpleLogicNode.  Every boolean in the formula must be an input of this AIG.
+    ///Return value: the literal of the formula.  It isn't added as an output.
+    pub fn build_from_formula(&mut self, formula : &SimpleLogicNode) -> AigLiteral {
+        match formula {
+            SimpleLogicNode::False => AIG_FALSE,
+            SimpleLogicNode::True => AIG_TRUE,
+            SimpleLogicNode::Literal(literal) => {
+                let input_literal = self.get_input_literal(get_variable_index(*literal));
+                if is_positive_literal(*literal) {input_literal} else {AndInverterGraph::negate(input_literal)}
+            },
+            SimpleLogicNode::Conjunction(operands) => {
+                let mut result = AIG_TRUE;
+                for operand in operands {
+                    let operand_literal = self.build_from_formula(operand);
+                    result = self.create_and(result, operand_literal);
+                }
+                result
+            },
+            SimpleLogicNode::Disjunction(operands) => {
+                let mut result = AIG_FALSE;
+                for operand in operands {
+                    let operand_literal = self.build_from_formula(operand);
+                    result = self.create_or(result, operand_literal);
+                }
+                result
+            },
+            SimpleLogicNode::Equivalence(operands) => {
+                let mut result = AIG_TRUE;
+                for operand in operands {
+                    let operand_literal = self.build_from_formula(operand);
+                    result = AndInverterGraph::negate(self.create_exclusive_or(result, operand_literal));
+                }
+                result
+            },
+            SimpleLogicNode::ExclusiveDisjunction(operands) => {
+                let mut result = AIG_FALSE;
+                for operand in operands {
+                    let operand_literal = self.build_from_formula(operand);
+                    result = self.create_exclusive_or(result, operand_literal);
+                }
+                result
+            }
+        } //End match formula
+    } //End build_from_formula
+
+    //CONVERSION
+    ///Converts a literal of this AIG back into a SimpleLogicNode.  Shared gates are copied wherever they're used, and
+    ///negated gates are pushed down with De Morgan's laws.
+    pub fn to_formula(&self, literal : AigLiteral) -> SimpleLogicNode {
+        if literal == AIG_FALSE {return SimpleLogicNode::False;}
+        if literal == AIG_TRUE {return SimpleLogicNode::True;}
+
+        let is_negated = literal & 1 == 1;
+        let formula =
+            match self.get_and_gate_inputs(literal) {
+                None => SimpleLogicNode::Literal(literal / 2),
+                Some((left, right)) => create_conjunction(vec![self.to_formula(left), self.to_formula(right)])
+            };
+
+        if is_negated {formula.negate()} else {formula}
+    } //End to_formula
+
+    ///Counts the AND gates that the literals depend on, counting shared gates only once.  This is the cost of the
+    ///literals in the AIG cost model.
+    pub fn count_reachable_and_gates(&self, literal_list : &[AigLiteral]) -> u32 {
+        let mut visited = std::collections::HashSet::new();
+        let mut stack : Vec<AigLiteral> = literal_list.to_vec();
+        while let Some(current) = stack.pop() {
+            if let Some((left, right)) = self.get_and_gate_inputs(current) {
+                if visited.insert(current / 2) {
+                    stack.push(left);
+                    stack.push(right);
+                }
+            }
+        } //End while there are gates to visit
+
+        visited.len() as u32
+    } //End count_reachable_and_gates
+
+    //AIGER
+    ///Gets the AIG in the ASCII AIGER format (aag).  There are no latches.
+    ///symbol_list is a list of names for the inputs.  If it's given, a symbol table is written.
+    pub fn write_aiger_ascii(&self, symbol_list : Option<&Vec<String>>) -> String {
+        let mut text = format!("aag {} {} 0 {} {}\n", self.get_max_variable_index(), self.num_inputs,
+                               self.output_vec.len(), self.and_gate_vec.len());
+        for input_index in 1..=self.num_inputs {
+            text.push_str(&format!("{}\n", 2 * input_index));
+        }
+        for output in &self.output_vec {
+            text.push_str(&format!("{}\n", output));
+        }
+        for (gate_index, (left, right)) in self.and_gate_vec.iter().enumerate() {
+            text.push_str(&format!("{} {} {}\n", 2 * (self.num_inputs + 1 + gate_index as u32), left, right));
+        }
+        text.push_str(&self.get_aiger_symbol_table(symbol_list));
+        text
+    } //End write_aiger_ascii
+
+    ///Gets the AIG in the binary AIGER format (aig).  There are no latches.
+    ///symbol_list is a list of names for the inputs.  If it's given, a symbol table is written.
+    pub fn write_aiger_binary(&self, symbol_list : Option<&Vec<String>>) -> Vec<u8> {
+        //The header and outputs are text.  The inputs are implicit.
+        let mut text = format!("aig {} {} 0 {} {}\n", self.get_max_variable_index(), self.num_inputs,
+                               self.output_vec.len(), self.and_gate_vec.len());
+        for output in &self.output_vec {
+            text.push_str(&format!("{}\n", output));
+        }
+        let mut bytes = text.into_bytes();
+
+        //Each gate is two deltas: lhs - rhs0 and rhs0 - rhs1.
+        for (gate_index, (left, right)) in self.and_gate_vec.iter().enumerate() {
+            let gate_literal = 2 * (self.num_inputs + 1 + gate_index as u32);
+            encode_aiger_delta(&mut bytes, gate_literal - left);
+            encode_aiger_delta(&mut bytes, left - right);
+        }
+
+        bytes.extend(self.get_aiger_symbol_table(symbol_list).into_bytes());
+        bytes
+    } //End write_aiger_binary
+
+    ///Reads an AIG in either AIGER format.  The format is determined from the header.  Latches aren't supported, and
+    ///the symbol table and comments are ignored.  Gates are structurally hashed as they're read, so the result can
+    ///have fewer gates than the file.
+    pub fn read_aiger(bytes : &[u8]) -> Result<AndInverterGraph, String> {
+        let mut reader = AigerReader {bytes : bytes, position : 0};
+
+        //Header
+        let header_line = reader.read_line()?;
+        let header_field_list : Vec<&str> = header_line.split_whitespace().collect();
+        if header_field_list.len() < 6 {return Err(format!("Invalid AIGER header: {}", header_line));}
+        let is_binary =
+            match header_field_list[0] {
+                "aag" => false,
+                "aig" => true,
+                _ => return Err(format!("Invalid AIGER header: {}", header_line))
+            };
+        let mut header_number_list = Vec::with_capacity(5);
+        for field in &header_field_list[1..6] {
+            header_number_list.push(parse_aiger_number(field)?);
+        }
+        let (max_variable_index, num_inputs, num_latches, num_outputs, num_and_gates) =
+            (header_number_list[0], header_number_list[1], header_number_list[2], header_number_list[3],
+             header_number_list[4]);
+        if num_latches != 0 {return Err("AIGER files with latches aren't supported".to_string());}
+        if num_inputs.checked_add(num_and_gates).is_none_or(|num_variables| num_variables > max_variable_index) {
+            return Err(format!("Invalid AIGER header: {}", header_line));
+        }
+
+        //The header is checked before anything is allocated for it, so a bad header can't use up the memory.  Every
+        //output takes at least two bytes of the file, and the gates and inputs are limited by M.
+        if max_variable_index > MAX_AIGER_VARIABLE_INDEX {
+            return Err(format!("AIGER files with more than {} variables aren't supported", MAX_AIGER_VARIABLE_INDEX));
+        }
+        if num_outputs as usize > bytes.len() / 2 {
+            return Err(format!("Invalid AIGER header: {}", header_line));
+        }
+
+        //Inputs.  The file's literals are translated through this table, since hashing can merge gates.
+        let mut aig = AndInverterGraph::new(num_inputs);
+        let mut variable_literal_vec : Vec<Option<AigLiteral>> = vec![None; (max_variable_index + 1) as usize];
+        variable_literal_vec[0] = Some(AIG_FALSE);
+        for input_index in 1..=num_inputs {
+            let file_variable =
+                if is_binary {input_index}
+                else {
+                    let literal = parse_aiger_number(&reader.read_line()?)?;
+                    if literal % 2 == 1 || literal / 2 > max_variable_index {
+                        return Err(format!("Invalid AIGER input literal {}", literal));
+                    }
+                    literal / 2
+                };
+            if variable_literal_vec[file_variable as usize].is_some() {
+                return Err(format!("AIGER variable {} is defined more than once", file_variable));
+            }
+            variable_literal_vec[file_variable as usize] = Some(2 * input_index);
+        } //End for each input
+
+        //Outputs
+        let mut file_output_vec = Vec::with_capacity(num_outputs as usize);
+        for _output in 0..num_outputs {
+            file_output_vec.push(parse_aiger_number(&reader.read_line()?)?);
+        }
+
+        //AND gates
+        let mut file_gate_vec : Vec<(u32, u32, u32)> = Vec::with_capacity(num_and_gates as usize);
+        for gate_index in 0..num_and_gates {
+            if is_binary {
+                let gate_literal = 2 * (num_inputs + 1 + gate_index);
+                let left = gate_literal.checked_sub(reader.read_delta()?)
+                    .ok_or_else(|| "Invalid AIGER delta".to_string())?;
+                let right = left.checked_sub(reader.read_delta()?).ok_or_else(|| "Invalid AIGER delta".to_string())?;
+                file_gate_vec.push((gate_literal, left, right));
+            }
+            else {
+                let line = reader.read_line()?;
+                let number_list = line.split_whitespace().map(parse_aiger_number).collect::<Result<Vec<u32>, String>>()?;
+                if number_list.len() != 3 || number_list[0] % 2 == 1 || number_list[0] / 2 > max_variable_index {
+                    return Err(format!("Invalid AIGER AND gate: {}", line));
+                }
+                file_gate_vec.push((number_list[0], number_list[1], number_list[2]));
+            }
+        } //End for each AND gate
+
+        //ASCII files can list gates in any order, so keep creating gates until every one is defined.
+        let mut remaining_gate_vec = file_gate_vec;
+        while !remaining_gate_vec.is_empty() {
+            let num_remaining_gates = remaining_gate_vec.len();
+            let mut undefined_gate_vec = Vec::new();
+            for (gate_literal, left, right) in remaining_gate_vec {
+                let translated_left = translate_aiger_literal(&variable_literal_vec, left)?;
+                let translated_right = translate_aiger_literal(&variable_literal_vec, right)?;
+                match (translated_left, translated_right) {
+                    (Some(new_left), Some(new_right)) => {
+                        if variable_literal_vec[(gate_literal / 2) as usize].is_some() {
+                            return Err(format!("AIGER variable {} is defined more than once", gate_literal / 2));
+                        }
+                        let new_gate = aig.create_and(new_left, new_right);
+                        variable_literal_vec[(gate_literal / 2) as usize] = Some(new_gate);
+                    },
+                    _ => undefined_gate_vec.push((gate_literal, left, right))
+                };
+            } //End for each remaining gate
+
+            if undefined_gate_vec.len() == num_remaining_gates {
+                return Err("AIGER AND gates are cyclic or use undefined literals".to_string());
+            }
+            remaining_gate_vec = undefined_gate_vec;
+        } //End while there are gates to define
+
+        for output in file_output_vec {
+            match translate_aiger_literal(&variable_literal_vec, output)? {
+                Some(new_output) => aig.add_output(new_output),
+                None => return Err(format!("AIGER output {} is undefined", output))
+            };
+        } //End for each output
+
+        Ok(aig)
+    } //End read_aiger
+
+    //PRIVATE
+    //Gets the AIGER symbol table for the inputs, or nothing if there are no symbols.
+    fn get_aiger_symbol_table(&self, symbol_list : Option<&Vec<String>>) -> String {
+        let mut text = String::new();
+        if let Some(symbol_list) = symbol_list {
+            for (input_index, symbol) in symbol_list.iter().take(self.num_inputs as usize).enumerate() {
+                text.push_str(&format!("i{} {}\n", input_index, symbol));
+            }
+        }
+        text
+    } //End get_aiger_symbol_table
+} //End impl AndInverterGraph
+
+//PRIVATE//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+//The largest M that read_aiger accepts.  The variable table it allocates has M + 1 entries.
+const MAX_AIGER_VARIABLE_INDEX : u32 = 1 << 24;
+
+//This struct reads an AIGER file one line or delta at a time.
+struct AigerReader<'a> {
+    bytes : &'a [u8],
+    position : usize
+} //End struct AigerReader
+
+impl AigerReader<'_> {
+    //Reads the next line without the newline.
+    fn read_line(&mut self) -> Result<String, String> {
+        if self.position >= self.bytes.len() {return Err("Unexpected end of AIGER file".to_string());}
+
+        let line_start = self.position;
+        while self.position < self.bytes.len() && self.bytes[self.position] != b'\n' {
+            self.position = self.position + 1;
+        }
+        let line = String::from_utf8_lossy(&self.bytes[line_start..self.position]).trim_end().to_string();
+        self.position = self.position + 1;  //Skip the newline
+        Ok(line)
+    } //End read_line
+
+    //Reads a delta of the binary format.  It's stored 7 bits at a time, least significant first, and the high bit of
+    //each byte is set if another byte follows.
+    fn read_delta(&mut self) -> Result<u32, String> {
+        let mut delta : u32 = 0;
+        let mut shift = 0;
+        loop {
+            if self.position >= self.bytes.len() {return Err("Unexpected end of AIGER file".to_string());}
+            let byte = self.bytes[self.position];
+            if shift > 28 || (shift == 28 && byte & 0x7f > 0xf) {return Err("Invalid AIGER delta".to_string());}
+
+            self.position = self.position + 1;
+            delta = delta | (((byte & 0x7f) as u32) << shift);
+            if byte & 0x80 == 0 {return Ok(delta);}
+            shift = shift + 7;
+        } //End loop over the bytes
+    } //End read_delta
+} //End impl AigerReader
+
+//Writes a delta of the binary format.  See read_delta.
+fn encode_aiger_delta(bytes : &mut Vec<u8>, delta : u32) {
+    let mut remaining_delta = delta;
+    while remaining_delta >= 0x80 {
+        bytes.push((remaining_delta & 0x7f) as u8 | 0x80);
+        remaining_delta = remaining_delta >> 7;
+    }
+    bytes.push(remaining_delta as u8);
+} //End encode_aiger_delta
+
+fn parse_aiger_number(text : &str) -> Result<u32, String> {
+    text.trim().parse::<u32>().map_err(|_| format!("Invalid AIGER number: {}", text))
+} //End parse_aiger_number
+
+//Translates a literal of an AIGER file into a literal of the AIG being read.
+//Return value: the literal, None if its variable isn't defined yet, or an error if the variable is out of range
+fn translate_aiger_literal(variable_literal_vec : &Vec<Option<AigLiteral>>, literal : u32)
+    -> Result<Option<AigLiteral>, String>
+{
+    match variable_literal_vec.get((literal / 2) as usize) {
+        Some(translated_literal) => Ok(translated_literal.map(|translated_literal| translated_literal ^ (literal & 1))),
+        None => Err(format!("AIGER literal {} is out of range", literal))
+    }
+} //End translate_aiger_literal
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::formula_parser::*;
+    use crate::truth_table_size_5::*;
+
+    //Builds an AIG with an output for each formula over p1 to p3.
+    fn build_test_aig(formula_text_list : &[&str]) -> AndInverterGraph {
+        let boolean_name_list : Vec<String> = (1..=3).map(|i| format!("p{}", i)).collect();
+        let mut aig = AndInverterGraph::new(3);
+        for formula_text in formula_text_list {
+            let literal = aig.build_from_formula(&parse_formula(formula_text, &boolean_name_list).unwrap());
+            aig.add_output(literal);
+        }
+        aig
+    } //End build_test_aig
+
+    fn get_output_truth_tables(aig : &AndInverterGraph) -> Vec<u32> {
+        let computer = TruthTableSize5Computer::new(aig.get_num_inputs());
+        let mask = get_truth_table_mask(aig.get_num_inputs());
+        aig.get_outputs().iter().map(|output| computer.compute_truth_table(&aig.to_formula(*output)) & mask).collect()
+    } //End get_output_truth_tables
+
+    const TEST_FORMULAS : [&str; 5] = ["p1 ^ p2", "(p1 & ~p3) | p2", "p1 <=> p2 <=> p3", "~p2", "TRUE"];
+
+    #[test]
+    fn ascii_round_trip_keeps_every_output() {
+        let aig = build_test_aig(&TEST_FORMULAS);
+        let read_aig = AndInverterGraph::read_aiger(aig.write_aiger_ascii(None).as_bytes()).unwrap();
+        assert_eq!(read_aig.get_num_inputs(), 3);
+        assert_eq!(read_aig.get_num_and_gates(), aig.get_num_and_gates());
+        assert_eq!(get_output_truth_tables(&read_aig), get_output_truth_tables(&aig));
+    }
+
+    #[test]
+    fn binary_round_trip_keeps_every_output() {
+        let symbol_list = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let aig = build_test_aig(&TEST_FORMULAS);
+        let read_aig = AndInverterGraph::read_aiger(&aig.write_aiger_binary(Some(&symbol_list))).unwrap();
+        assert_eq!(read_aig.get_num_and_gates(), aig.get_num_and_gates());
+        assert_eq!(get_output_truth_tables(&read_aig), get_output_truth_tables(&aig));
+    }
+
+    #[test]
+    fn ascii_gates_can_come_in_any_order() {
+        //Output 10 is ~p1 & (p1 & p2), which is always False, and it's defined before its input 8.
+        let read_aig = AndInverterGraph::read_aiger(b"aag 5 2 0 2 2\n2\n4\n10\n8\n10 8 3\n8 4 2\n").unwrap();
+        assert_eq!(get_output_truth_tables(&read_aig), vec![0, 0b1000]);
+    }
+
+    #[test]
+    fn malformed_headers_are_errors() {
+        let malformed_file_list : [&[u8]; 8] = [
+            b"aag 4294967295 1 0 0 0\n2\n",    //M + 1 overflows
+            b"aag 1 4294967295 0 0 1\n",       //I + A overflows
+            b"aag 1000000000 0 0 0 0\n",       //M is too big to allocate
+            b"aag 1 1 0 1000000000 0\n2\n",    //More outputs than the file has room for
+            b"aag 2 1 0 0 2\n2\n",             //I + A > M
+            b"aag 1 0 1 0 0\n2 3\n",           //Latches
+            b"aig 1 1 0 1\n2\n",               //Too few header fields
+            b"abc 1 1 0 0 0\n2\n"              //Unknown format
+        ];
+        for malformed_file in malformed_file_list {
+            assert!(AndInverterGraph::read_aiger(malformed_file).is_err(), "{}",
+                    String::from_utf8_lossy(malformed_file));
+        }
+    }
+
+    #[test]
+    fn malformed_literals_are_errors() {
+        let malformed_file_list : [&[u8]; 8] = [
+            b"aag 2 1 0 1 1\n2\n4\n100 2 3\n",  //Gate lhs above M
+            b"aag 2 1 0 1 1\n2\n4\n4 2 100\n",  //Gate rhs above M
+            b"aag 2 1 0 1 1\n2\n100\n4 2 3\n",  //Output above M
+            b"aag 2 1 0 1 1\n9\n4\n4 2 3\n",    //Input above M
+            b"aag 2 1 0 1 1\n2\n4\n2 4 3\n",    //Gate redefines an input
+            b"aag 3 1 0 1 2\n2\n4\n4 6 2\n6 4 2\n",  //Cyclic gates
+            b"aig 2 1 0 1 1\n4\n\x08",          //Delta bigger than the gate literal
+            b"aig 2 1 0 1 1\n4\n\x01"           //Missing delta
+        ];
+        for malformed_file in malformed_file_list {
+            assert!(AndInverterGraph::read_aiger(malformed_file).is_err(), "{}",
+                    String::from_utf8_lossy(malformed_file));
+        }
+    }
+} //End mod tests
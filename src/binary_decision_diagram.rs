@@ -0,0 +1,1123 @@
+/** This file stores reduced ordered binary decision diagrams (ROBDDs).
+    Author: Steven Fletcher
+    Created: 10/18/2026
+    Last Updated: 10/18/2026
+*/
+use crate::logic::*;
+use crate::truth_table_size_5::*;
+use std::collections::HashMap;
+
+///BDD nodes are referenced by their index in the BddManager that created them.  Ids are only meaningful inside of
+///that BddManager.
+pub type BddNodeId = u32;
+
+///The terminal node for False.
+pub const BDD_FALSE : BddNodeId = 0;
+///The terminal node for True.
+pub const BDD_TRUE : BddNodeId = 1;
+
+///The binary operators that BddManager.apply can compute.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum BddOperator {
+    Conjunction,
+    Disjunction,
+    ExclusiveDisjunction
+} //End enum BddOperator
+
+///This struct creates and stores the nodes of reduced ordered binary decision diagrams.  Every node is unique (the
+///unique table guarantees that no two nodes have the same variable and children), so two functions are equivalent
+///exactly when they have the same BddNodeId.
+///
+///Variables are numbered from 1 to n like the booleans in SimpleLogicNode.  There's no limit on the number of
+///variables.  Variables that haven't been seen before are added to the bottom of the variable order.
+///
+///The variable order can be changed by reordering (see reorder_by_sifting and reorder_exactly).  Reordering swaps
+///levels in place, so every BddNodeId that's still in use keeps representing the same function.  With a threshold set
+///by set_auto_reordering_threshold, the operations that build BDDs reorder by sifting on their own.
+pub struct BddManager {
+    node_vec : Vec<BddNode>,
+    unique_table : HashMap<(u32, BddNodeId, BddNodeId), BddNodeId>,
+    computed_cache : HashMap<(BddOperator, BddNodeId, BddNodeId), BddNodeId>,
+    variable_order : Vec<u32>,  //variable_order[level] is the variable at that level
+    variable_levels : Vec<u32>, //variable_levels[variable - 1] is the level of the variable
+
+    //Reordering
+    variable_node_vec : Vec<Vec<BddNodeId>>, //variable_node_vec[variable - 1] is every node with that variable
+    free_node_vec : Vec<BddNodeId>,          //Nodes freed by garbage collection that can be reused
+    auto_reordering_threshold : Option<usize>
+} //End struct BddManager
+
+impl BddManager {
+    ///Creates a BddManager with the variables 1 to num_variables in increasing order.
+    pub fn new(num_variables : u32) -> BddManager {
+        let mut manager = BddManager {
+            node_vec : Vec::new(),
+            unique_table : HashMap::new(),
+            computed_cache : HashMap::new(),
+            variable_order : Vec::new(),
+            variable_levels : Vec::new(),
+
+            variable_node_vec : Vec::new(),
+            free_node_vec : Vec::new(),
+            auto_reordering_threshold : None
+        };
+
+        //The terminals are the first two nodes.  They are below every variable.
+        manager.node_vec.push(BddNode {variable : TERMINAL_VARIABLE, low : BDD_FALSE, high : BDD_FALSE});
+        manager.node_vec.push(BddNode {variable : TERMINAL_VARIABLE, low : BDD_TRUE, high : BDD_TRUE});
+        manager.add_variables_up_to(num_variables);
+
+        manager
+    } //End new
+
+    ///Gets the variable order, from the top of the diagrams to the bottom.
+    #[cfg(test)]
+    pub fn get_variable_order(&self) -> &Vec<u32> {&self.variable_order}
+
+    ///Gets the BDD for a single variable.
+    #[cfg(test)]
+    pub fn get_variable(&mut self, variable : u32) -> BddNodeId {
+        self.add_variables_up_to(variable);
+        self.make_node(variable, BDD_FALSE, BDD_TRUE)
+    } //End get_variable
+
+    ///Gets the BDD for a literal (sign bit and variable index).
+    pub fn get_literal(&mut self, literal : u32) -> BddNodeId {
+        let variable = get_variable_index(literal);
+        self.add_variables_up_to(variable);
+        if is_positive_literal(literal) {self.make_node(variable, BDD_FALSE, BDD_TRUE)}
+        else {self.make_node(variable, BDD_TRUE, BDD_FALSE)}
+    } //End get_literal
+
+    ///Builds the BDD of a SimpleLogicNode.
+    pub fn build_from_formula(&mut self, formula : &SimpleLogicNode) -> BddNodeId {
+        let result = self.build_from_formula_helper(formula);
+        self.reorder_if_needed(&[result]);
+        result
+    } //End build_from_formula
+
+    fn build_from_formula_helper(&mut self, formula : &SimpleLogicNode) -> BddNodeId {
+        match formula {
+            SimpleLogicNode::False => BDD_FALSE,
+            SimpleLogicNode::True => BDD_TRUE,
+            SimpleLogicNode::Literal(literal) => self.get_literal(*literal),
+            SimpleLogicNode::Conjunction(operands) => {
+                let mut result = BDD_TRUE;
+                for operand in operands {
+                    let operand_bdd = self.build_from_formula_helper(operand);
+                    result = self.apply_helper(BddOperator::Conjunction, result, operand_bdd);
+                }
+                result
+            },
+            SimpleLogicNode::Disjunction(operands) => {
+                let mut result = BDD_FALSE;
+                for operand in operands {
+                    let operand_bdd = self.build_from_formula_helper(operand);
+                    result = self.apply_helper(BddOperator::Disjunction, result, operand_bdd);
+                }
+                result
+            },
+            SimpleLogicNode::Equivalence(operands) => {
+                let mut result = BDD_TRUE;
+                for operand in operands {
+                    let operand_bdd = self.build_from_formula_helper(operand);
+                    let exclusive_disjunction = self.apply_helper(BddOperator::ExclusiveDisjunction, result,
+                                                                  operand_bdd);
+                    result = self.apply_helper(BddOperator::ExclusiveDisjunction, exclusive_disjunction, BDD_TRUE);
+                }
+                result
+            },
+            SimpleLogicNode::ExclusiveDisjunction(operands) => {
+                let mut result = BDD_FALSE;
+                for operand in operands {
+                    let operand_bdd = self.build_from_formula_helper(operand);
+                    result = self.apply_helper(BddOperator::ExclusiveDisjunction, result, operand_bdd);
+                }
+                result
+            }
+        } //End match formula
+    } //End build_from_formula_helper
+
+    ///Builds the BDD of a truth table.
+    ///truth_table is a truth table over num_booleans booleans (see truth_table_size_5.rs for the format)
+    pub fn build_from_truth_table(&mut self, truth_table : u32, num_booleans : u32) -> BddNodeId {
+        self.add_variables_up_to(num_booleans);
+        let result =
+            self.build_from_truth_table_helper(truth_table & get_truth_table_mask(num_booleans), num_booleans, 0);
+        self.reorder_if_needed(&[result]);
+        result
+    } //End build_from_truth_table
+
+    ///Combines two BDDs with a binary operator.
+    pub fn apply(&mut self, operator : BddOperator, left : BddNodeId, right : BddNodeId) -> BddNodeId {
+        let result = self.apply_helper(operator, left, right);
+        self.reorder_if_needed(&[result]);
+        result
+    } //End apply
+
+    fn apply_helper(&mut self, operator : BddOperator, left : BddNodeId, right : BddNodeId) -> BddNodeId {
+        //Terminal cases
+        match operator {
+            BddOperator::Conjunction => {
+                if left == BDD_FALSE || right == BDD_FALSE {return BDD_FALSE;}
+                if left == BDD_TRUE {return right;}
+                if right == BDD_TRUE || left == right {return left;}
+            },
+            BddOperator::Disjunction => {
+                if left == BDD_TRUE || right == BDD_TRUE {return BDD_TRUE;}
+                if left == BDD_FALSE {return right;}
+                if right == BDD_FALSE || left == right {return left;}
+            },
+            BddOperator::ExclusiveDisjunction => {
+                if left == right {return BDD_FALSE;}
+                if left == BDD_FALSE {return right;}
+                if right == BDD_FALSE {return left;}
+                if left == BDD_TRUE && right == BDD_TRUE {return BDD_FALSE;}
+            }
+        } //End match operator
+
+        //Every operator is commutative, so the operands can be sorted to share cache entries.
+        let cache_key = (operator, left.min(right), left.max(right));
+        if let Some(result) = self.computed_cache.get(&cache_key) {return *result;}
+
+        //Shannon expansion on the top variable.
+        let variable = self.get_top_variable(left, right);
+        let (left_low, left_high) = self.get_cofactors(left, variable);
+        let (right_low, right_high) = self.get_cofactors(right, variable);
+        let low = self.apply_helper(operator, left_low, right_low);
+        let high = self.apply_helper(operator, left_high, right_high);
+        let result = self.make_node(variable, low, high);
+
+        self.computed_cache.insert(cache_key, result);
+        result
+    } //End apply_helper
+
+    ///Negates a BDD.
+    #[cfg(test)]
+    pub fn negate(&mut self, bdd : BddNodeId) -> BddNodeId {
+        self.apply(BddOperator::ExclusiveDisjunction, bdd, BDD_TRUE)
+    } //End negate
+
+    ///Gets the BDD of if condition then then_bdd else else_bdd, which is (condition & then_bdd) | (~condition &
+    ///else_bdd).
+    #[cfg(test)]
+    pub fn if_then_else(&mut self, condition : BddNodeId, then_bdd : BddNodeId, else_bdd : BddNodeId) -> BddNodeId {
+        let then_part = self.apply(BddOperator::Conjunction, condition, then_bdd);
+        let negated_condition = self.negate(condition);
+        let else_part = self.apply(BddOperator::Conjunction, negated_condition, else_bdd);
+        self.apply(BddOperator::Disjunction, then_part, else_part)
+    } //End if_then_else
+
+    ///Restricts a BDD by fixing a variable to a value.
+    #[cfg(test)]
+    pub fn restrict(&mut self, bdd : BddNodeId, variable : u32, value : bool) -> BddNodeId {
+        self.add_variables_up_to(variable);
+        let mut memo = HashMap::new();
+        self.restrict_helper(bdd, variable, value, &mut memo)
+    } //End restrict
+
+    ///Existentially quantifies variables out of a BDD.  The result is True wherever some value of the variables makes
+    ///the BDD True.
+    #[cfg(test)]
+    pub fn exists(&mut self, bdd : BddNodeId, variables : &[u32]) -> BddNodeId {
+        let mut result = bdd;
+        for variable in variables {
+            let low = self.restrict(result, *variable, false);
+            let high = self.restrict(result, *variable, true);
+            result = self.apply(BddOperator::Disjunction, low, high);
+        }
+        result
+    } //End exists
+
+    ///Universally quantifies variables out of a BDD.  The result is True wherever every value of the variables makes
+    ///the BDD True.
+    #[cfg(test)]
+    pub fn for_all(&mut self, bdd : BddNodeId, variables : &[u32]) -> BddNodeId {
+        let mut result = bdd;
+        for variable in variables {
+            let low = self.restrict(result, *variable, false);
+            let high = self.restrict(result, *variable, true);
+            result = self.apply(BddOperator::Conjunction, low, high);
+        }
+        result
+    } //End for_all
+
+    ///Counts the satisfying assignments of a BDD over the variables 1 to num_variables.  Every variable in the BDD
+    ///must be in that range, and num_variables must be less than 128.
+    #[cfg(test)]
+    pub fn count_models(&self, bdd : BddNodeId, num_variables : u32) -> u128 {
+        //Count the models over the variables below each node's level, then scale up for the skipped levels.  Variables
+        //in range that this BddManager hasn't seen yet aren't in any level, and they double the count too.
+        let mut memo = HashMap::new();
+        let levels_in_range : Vec<u32> = self.variable_order.iter().enumerate()
+            .filter(|(_, variable)| **variable <= num_variables).map(|(level, _)| level as u32).collect();
+        let num_unseen_variables = num_variables - levels_in_range.len() as u32;
+        let count = self.count_models_helper(bdd, &levels_in_range, &mut memo);
+        count << (self.count_levels_above(bdd, &levels_in_range) + num_unseen_variables)
+    } //End count_models
+
+    ///Gets the satisfying assignments of a BDD as cubes.  Each cube is a list of literals in variable order.  Variables
+    ///that don't appear in a cube can have either value.  The cubes are disjoint.
+    pub fn get_satisfying_cubes(&self, bdd : BddNodeId) -> Vec<Vec<u32>> {
+        let mut cube_vec = Vec::new();
+        self.get_satisfying_cubes_helper(bdd, &mut Vec::new(), &mut cube_vec);
+        cube_vec
+    } //End get_satisfying_cubes
+
+    ///Converts a BDD back into a SimpleLogicNode.  Each node becomes an if-then-else on its variable, with the
+    ///special cases simplified.
+    #[cfg(test)]
+    pub fn to_formula(&self, bdd : BddNodeId) -> SimpleLogicNode {
+        if bdd == BDD_FALSE {return SimpleLogicNode::False;}
+        if bdd == BDD_TRUE {return SimpleLogicNode::True;}
+
+        let node = &self.node_vec[bdd as usize];
+        let positive_literal = SimpleLogicNode::Literal(node.variable);
+        let negative_literal = SimpleLogicNode::Literal(node.variable | NEGATIVITY_FLAG);
+
+        if node.low == BDD_FALSE && node.high == BDD_TRUE {return positive_literal;}
+        if node.low == BDD_TRUE && node.high == BDD_FALSE {return negative_literal;}
+        if node.low == BDD_FALSE {return create_conjunction(vec![positive_literal, self.to_formula(node.high)]);}
+        if node.high == BDD_FALSE {return create_conjunction(vec![negative_literal, self.to_formula(node.low)]);}
+        if node.high == BDD_TRUE {return create_disjunction(vec![positive_literal, self.to_formula(node.low)]);}
+        if node.low == BDD_TRUE {return create_disjunction(vec![negative_literal, self.to_formula(node.high)]);}
+
+        create_disjunction(vec![
+            create_conjunction(vec![positive_literal, self.to_formula(node.high)]),
+            create_conjunction(vec![negative_literal, self.to_formula(node.low)])
+        ])
+    } //End to_formula
+
+    ///Converts a BDD into a truth table over the booleans 1 to num_booleans.  Every variable in the BDD must be in that
+    ///range, and num_booleans must be at most 5.
+    #[cfg(test)]
+    pub fn to_truth_table(&self, bdd : BddNodeId, num_booleans : u32) -> u32 {
+        let mut memo = HashMap::new();
+        self.to_truth_table_helper(bdd, num_booleans, &mut memo)
+    } //End to_truth_table
+
+    ///Evaluates a BDD for an assignment of the variables.  Variables that aren't in truth_values are treated as False.
+    #[cfg(test)]
+    pub fn evaluate(&self, bdd : BddNodeId, truth_values : &HashMap<u32,bool>) -> bool {
+        let mut current = bdd;
+        while current != BDD_FALSE && current != BDD_TRUE {
+            let node = &self.node_vec[current as usize];
+            current = if *truth_values.get(&node.variable).unwrap_or(&false) {node.high} else {node.low};
+        }
+        current == BDD_TRUE
+    } //End evaluate
+
+    ///Counts the nodes of a BDD, including the terminals it reaches.
+    #[cfg(test)]
+    pub fn count_nodes(&self, bdd : BddNodeId) -> usize {
+        self.count_shared_nodes(&[bdd])
+    } //End count_nodes
+
+    ///Counts the nodes of several BDDs, counting shared nodes only once.
+    pub fn count_shared_nodes(&self, bdd_list : &[BddNodeId]) -> usize {
+        let mut visited = std::collections::HashSet::new();
+        let mut stack : Vec<BddNodeId> = bdd_list.to_vec();
+        while let Some(current) = stack.pop() {
+            if !visited.insert(current) {continue;}
+            if current != BDD_FALSE && current != BDD_TRUE {
+                stack.push(self.node_vec[current as usize].low);
+                stack.push(self.node_vec[current as usize].high);
+            }
+        } //End while there are nodes to visit
+
+        visited.len()
+    } //End count_shared_nodes
+
+    ///Determines whether two formulas are equivalent by comparing their BDDs.
+    pub fn are_equivalent(&mut self, formula1 : &SimpleLogicNode, formula2 : &SimpleLogicNode) -> bool {
+        self.build_from_formula(formula1) == self.build_from_formula(formula2)
+    } //End are_equivalent
+
+    ///Gets the variable of a node.  Terminals don't have a variable.
+    pub fn get_node_variable(&self, bdd : BddNodeId) -> Option<u32> {
+        if bdd == BDD_FALSE || bdd == BDD_TRUE {None}
+        else {Some(self.node_vec[bdd as usize].variable)}
+    } //End get_node_variable
+
+    ///Gets the children of a node as (low, high).  Terminals don't have children.
+    pub fn get_node_children(&self, bdd : BddNodeId) -> Option<(BddNodeId, BddNodeId)> {
+        if bdd == BDD_FALSE || bdd == BDD_TRUE {None}
+        else {Some((self.node_vec[bdd as usize].low, self.node_vec[bdd as usize].high))}
+    } //End get_node_children
+
+    //REORDERING
+    ///Frees every node that can't be reached from the roots.  BddNodeIds that aren't reachable from the roots must not
+    ///be used afterwards.
+    pub fn collect_garbage(&mut self, root_list : &[BddNodeId]) {
+        let mut is_reachable_vec = vec![false; self.node_vec.len()];
+        let mut stack : Vec<BddNodeId> = root_list.to_vec();
+        while let Some(current) = stack.pop() {
+            if is_reachable_vec[current as usize] {continue;}
+            is_reachable_vec[current as usize] = true;
+            if current != BDD_FALSE && current != BDD_TRUE {
+                stack.push(self.node_vec[current as usize].low);
+                stack.push(self.node_vec[current as usize].high);
+            }
+        } //End while there are nodes to visit
+
+        for variable_index in 0..self.variable_node_vec.len() {
+            let node_list = std::mem::take(&mut self.variable_node_vec[variable_index]);
+            for node_id in node_list {
+                if is_reachable_vec[node_id as usize] {
+                    self.variable_node_vec[variable_index].push(node_id);
+                }
+                else {
+                    let node = self.node_vec[node_id as usize];
+                    self.unique_table.remove(&(node.variable, node.low, node.high));
+                    self.free_node_vec.push(node_id);
+                }
+            } //End for each node of the variable
+        } //End for each variable
+
+        //The cache might refer to freed nodes.
+        self.computed_cache.clear();
+    } //End collect_garbage
+
+    ///Sets the node count that triggers automatic reordering.  Whenever apply, build_from_formula or
+    ///build_from_truth_table makes a BDD with more nodes than the threshold, the variables are reordered by sifting to
+    ///make that BDD smaller.  None turns automatic reordering off, which is the default.
+    pub fn set_auto_reordering_threshold(&mut self, threshold : Option<usize>) {
+        self.auto_reordering_threshold = threshold;
+    } //End set_auto_reordering_threshold
+
+    //Reorders by sifting if automatic reordering is on and the roots have more nodes than the threshold.  Afterwards
+    //the threshold is doubled if the BDDs are still larger than half of it, so reordering doesn't happen constantly.
+    //Nothing is freed, since the caller can still be using BDDs that aren't roots.
+    //Return value: the report if reordering happened
+    fn reorder_if_needed(&mut self, root_list : &[BddNodeId]) -> Option<ReorderingReport> {
+        let threshold = self.auto_reordering_threshold?;
+        if self.count_shared_nodes(root_list) <= threshold {return None;}
+
+        let report = self.sift(root_list, false);
+        if report.size_after * 2 > threshold {
+            self.auto_reordering_threshold = Some(threshold * 2);
+        }
+        Some(report)
+    } //End reorder_if_needed
+
+    ///Reorders the variables by sifting.  Each variable in turn is moved through every level, and it's left at the
+    ///level where the roots have the fewest nodes.  Nodes that can't be reached from the roots are freed.
+    pub fn reorder_by_sifting(&mut self, root_list : &[BddNodeId]) -> ReorderingReport {
+        self.sift(root_list, true)
+    } //End reorder_by_sifting
+
+    //Sifts every variable.  If free_unreachable_nodes is true, nodes that can't be reached from the roots are freed
+    //before and during sifting.
+    fn sift(&mut self, root_list : &[BddNodeId], free_unreachable_nodes : bool) -> ReorderingReport {
+        if free_unreachable_nodes {self.collect_garbage(root_list);}
+        let size_before = self.count_shared_nodes(root_list);
+        let order_before = self.variable_order.clone();
+        let num_levels = self.variable_order.len();
+
+        //Sift the variables with the most nodes first.
+        let mut sift_order : Vec<u32> = self.variable_order.clone();
+        sift_order.sort_by_key(|variable| std::cmp::Reverse(self.variable_node_vec[(*variable - 1) as usize].len()));
+
+        for variable in sift_order {
+            let mut level = self.variable_levels[(variable - 1) as usize] as usize;
+            let mut best_level = level;
+            let mut best_size = self.count_shared_nodes(root_list);
+
+            //Move the variable to the bottom.
+            while level + 1 < num_levels {
+                self.swap_adjacent_levels(level);
+                level = level + 1;
+                let size = self.count_shared_nodes(root_list);
+                if size < best_size {
+                    best_size = size;
+                    best_level = level;
+                }
+            } //End while the variable isn't at the bottom
+
+            //Move the variable to the top.
+            while level > 0 {
+                self.swap_adjacent_levels(level - 1);
+                level = level - 1;
+                let size = self.count_shared_nodes(root_list);
+                if size < best_size {
+                    best_size = size;
+                    best_level = level;
+                }
+            } //End while the variable isn't at the top
+
+            //Move the variable back to the best level.
+            while level < best_level {
+                self.swap_adjacent_levels(level);
+                level = level + 1;
+            }
+
+            if free_unreachable_nodes {self.collect_garbage(root_list);}
+        } //End for each variable
+
+        ReorderingReport {
+            method : ReorderingMethod::Sifting,
+            size_before : size_before,
+            size_after : self.count_shared_nodes(root_list),
+            order_before : order_before,
+            order_after : self.variable_order.clone()
+        }
+    } //End sift
+
+    ///Finds the variable order with the fewest nodes by trying every permutation.  The permutations are visited with
+    ///the Steinhaus-Johnson-Trotter algorithm, so each one is only a single swap of adjacent levels away from the last.
+    ///Nodes that can't be reached from the roots are freed.
+    ///Return value: the report, or an error message if there are too many variables
+    pub fn reorder_exactly(&mut self, root_list : &[BddNodeId]) -> Result<ReorderingReport, String> {
+        let num_levels = self.variable_order.len();
+        if num_levels > MAX_EXACT_REORDERING_VARIABLES {
+            return Err(format!("Exact reordering is limited to {} variables, but there are {}",
+                               MAX_EXACT_REORDERING_VARIABLES, num_levels));
+        }
+
+        self.collect_garbage(root_list);
+        let size_before = self.count_shared_nodes(root_list);
+        let order_before = self.variable_order.clone();
+
+        //Steinhaus-Johnson-Trotter: every element has a direction.  The largest mobile element moves each step.
+        //The elements here are the original levels, tracked by position.
+        let mut permutation : Vec<usize> = (0..num_levels).collect();
+        let mut is_moving_left_vec = vec![true; num_levels];
+        let mut best_size = size_before;
+        let mut best_order = self.variable_order.clone();
+        loop {
+            //Find the largest mobile element.
+            let mut mobile_position : Option<usize> = None;
+            for position in 0..num_levels {
+                let element = permutation[position];
+                let is_mobile =
+                    if is_moving_left_vec[element] {position > 0 && permutation[position - 1] < element}
+                    else {position + 1 < num_levels && permutation[position + 1] < element};
+                if is_mobile && mobile_position.is_none_or(|other| permutation[other] < element) {
+                    mobile_position = Some(position);
+                }
+            } //End for each position
+
+            let position =
+                match mobile_position {
+                    Some(position) => position,
+                    None => break
+                };
+
+            //Swap it in the direction it's moving, in both the permutation and the BDDs.
+            let element = permutation[position];
+            let swap_level = if is_moving_left_vec[element] {position - 1} else {position};
+            permutation.swap(swap_level, swap_level + 1);
+            self.swap_adjacent_levels(swap_level);
+            for is_moving_left in is_moving_left_vec.iter_mut().skip(element + 1) {
+                *is_moving_left = !*is_moving_left;
+            }
+
+            let size = self.count_shared_nodes(root_list);
+            if size < best_size {
+                best_size = size;
+                best_order = self.variable_order.clone();
+            }
+        } //End loop over the permutations
+
+        self.move_to_order(&best_order);
+        self.collect_garbage(root_list);
+
+        Ok(ReorderingReport {
+            method : ReorderingMethod::Exact,
+            size_before : size_before,
+            size_after : self.count_shared_nodes(root_list),
+            order_before : order_before,
+            order_after : self.variable_order.clone()
+        })
+    } //End reorder_exactly
+
+    ///Reorders the variables with a reordering method.  See reorder_by_sifting and reorder_exactly.
+    ///Return value: the report, or an error message if there are too many variables for the method
+    pub fn reorder(&mut self, method : ReorderingMethod, root_list : &[BddNodeId])
+        -> Result<ReorderingReport, String>
+    {
+        match method {
+            ReorderingMethod::Sifting => Ok(self.reorder_by_sifting(root_list)),
+            ReorderingMethod::Exact => self.reorder_exactly(root_list)
+        }
+    } //End reorder
+
+    ///Changes the variable order to a specific order using swaps of adjacent levels.
+    ///new_order must contain every variable exactly once, from the top level to the bottom level.
+    pub fn move_to_order(&mut self, new_order : &Vec<u32>) {
+        //Bubble each variable up to its target level.
+        for (target_level, variable) in new_order.iter().enumerate() {
+            let mut level = self.variable_levels[(*variable - 1) as usize] as usize;
+            while level > target_level {
+                self.swap_adjacent_levels(level - 1);
+                level = level - 1;
+            }
+        } //End for each variable in the new order
+    } //End move_to_order
+
+    ///Swaps the variables at level and level + 1.  Nodes of the upper variable are rewritten in place, so every
+    ///BddNodeId keeps representing the same function.
+    pub fn swap_adjacent_levels(&mut self, level : usize) {
+        let upper_variable = self.variable_order[level];
+        let lower_variable = self.variable_order[level + 1];
+
+        //Swap the levels first, so the new nodes are created in the new order.
+        self.variable_order.swap(level, level + 1);
+        self.variable_levels[(upper_variable - 1) as usize] = (level + 1) as u32;
+        self.variable_levels[(lower_variable - 1) as usize] = level as u32;
+
+        let upper_node_list = std::mem::take(&mut self.variable_node_vec[(upper_variable - 1) as usize]);
+        for node_id in upper_node_list {
+            let node = self.node_vec[node_id as usize];
+            let is_low_lower = self.node_vec[node.low as usize].variable == lower_variable;
+            let is_high_lower = self.node_vec[node.high as usize].variable == lower_variable;
+
+            //If neither child uses the lower variable, the node doesn't change.
+            if !is_low_lower && !is_high_lower {
+                self.variable_node_vec[(upper_variable - 1) as usize].push(node_id);
+                continue;
+            }
+
+            //f = upper ? (lower ? f11 : f10) : (lower ? f01 : f00) becomes lower ? (upper ? f11 : f01) : (upper ? f10 : f00)
+            let (f00, f01) = self.get_cofactors(node.low, lower_variable);
+            let (f10, f11) = self.get_cofactors(node.high, lower_variable);
+            let new_low = self.make_node(upper_variable, f00, f10);
+            let new_high = self.make_node(upper_variable, f01, f11);
+
+            self.unique_table.remove(&(upper_variable, node.low, node.high));
+            self.node_vec[node_id as usize] = BddNode {variable : lower_variable, low : new_low, high : new_high};
+            self.unique_table.insert((lower_variable, new_low, new_high), node_id);
+            self.variable_node_vec[(lower_variable - 1) as usize].push(node_id);
+        } //End for each node of the upper variable
+    } //End swap_adjacent_levels
+
+    //PRIVATE
+    //Adds variables to the bottom of the order until variable is known.
+    fn add_variables_up_to(&mut self, variable : u32) {
+        while (self.variable_order.len() as u32) < variable {
+            let new_variable = self.variable_order.len() as u32 + 1;
+            self.variable_levels.push(self.variable_order.len() as u32);
+            self.variable_order.push(new_variable);
+            self.variable_node_vec.push(Vec::new());
+        }
+    } //End add_variables_up_to
+
+    //Gets the level of a node.  Terminals are below every level.
+    fn get_level(&self, bdd : BddNodeId) -> u32 {
+        let variable = self.node_vec[bdd as usize].variable;
+        if variable == TERMINAL_VARIABLE {TERMINAL_VARIABLE}
+        else {self.variable_levels[(variable - 1) as usize]}
+    } //End get_level
+
+    //Gets the variable of whichever node is higher in the order.
+    fn get_top_variable(&self, left : BddNodeId, right : BddNodeId) -> u32 {
+        if self.get_level(left) <= self.get_level(right) {self.node_vec[left as usize].variable}
+        else {self.node_vec[right as usize].variable}
+    } //End get_top_variable
+
+    //Gets the (low, high) cofactors of a node with respect to a variable at or above its level.
+    fn get_cofactors(&self, bdd : BddNodeId, variable : u32) -> (BddNodeId, BddNodeId) {
+        let node = &self.node_vec[bdd as usize];
+        if node.variable == variable {(node.low, node.high)}
+        else {(bdd, bdd)}
+    } //End get_cofactors
+
+    //Gets the node for (variable, low, high), creating it if necessary.  Redundant nodes are never created.
+    fn make_node(&mut self, variable : u32, low : BddNodeId, high : BddNodeId) -> BddNodeId {
+        if low == high {return low;}
+
+        let key = (variable, low, high);
+        if let Some(existing_node) = self.unique_table.get(&key) {return *existing_node;}
+
+        let node = BddNode {variable : variable, low : low, high : high};
+        let new_node =
+            match self.free_node_vec.pop() {
+                Some(free_node) => {
+                    self.node_vec[free_node as usize] = node;
+                    free_node
+                },
+                None => {
+                    self.node_vec.push(node);
+                    (self.node_vec.len() - 1) as BddNodeId
+                }
+            };
+        self.unique_table.insert(key, new_node);
+        self.variable_node_vec[(variable - 1) as usize].push(new_node);
+        new_node
+    } //End make_node
+
+    fn build_from_truth_table_helper(&mut self, truth_table : u32, num_booleans : u32, level : usize) -> BddNodeId {
+        if truth_table == 0 {return BDD_FALSE;}
+        if truth_table == get_truth_table_mask(num_booleans) {return BDD_TRUE;}
+
+        //Skip variables that aren't in the truth table.
+        let variable = self.variable_order[level];
+        if variable > num_booleans {
+            return self.build_from_truth_table_helper(truth_table, num_booleans, level + 1);
+        }
+
+        let false_cofactor = compute_cofactor(truth_table, num_booleans, variable, false);
+        let true_cofactor = compute_cofactor(truth_table, num_booleans, variable, true);
+        let low = self.build_from_truth_table_helper(false_cofactor, num_booleans, level + 1);
+        let high = self.build_from_truth_table_helper(true_cofactor, num_booleans, level + 1);
+        self.make_node(variable, low, high)
+    } //End build_from_truth_table_helper
+
+    #[cfg(test)]
+    fn restrict_helper(&mut self, bdd : BddNodeId, variable : u32, value : bool,
+                       memo : &mut HashMap<BddNodeId, BddNodeId>) -> BddNodeId
+    {
+        //Nodes below the variable don't depend on it.
+        if self.get_level(bdd) > self.variable_levels[(variable - 1) as usize] {return bdd;}
+        if let Some(result) = memo.get(&bdd) {return *result;}
+
+        let node = self.node_vec[bdd as usize];
+        let result =
+            if node.variable == variable {
+                if value {node.high} else {node.low}
+            }
+            else {
+                let low = self.restrict_helper(node.low, variable, value, memo);
+                let high = self.restrict_helper(node.high, variable, value, memo);
+                self.make_node(node.variable, low, high)
+            };
+
+        memo.insert(bdd, result);
+        result
+    } //End restrict_helper
+
+    //Counts the models over the levels from this node's level down.
+    #[cfg(test)]
+    fn count_models_helper(&self, bdd : BddNodeId, levels_in_range : &Vec<u32>, memo : &mut HashMap<BddNodeId, u128>)
+        -> u128
+    {
+        if bdd == BDD_FALSE {return 0;}
+        if bdd == BDD_TRUE {return 1;}
+        if let Some(count) = memo.get(&bdd) {return *count;}
+
+        let node = &self.node_vec[bdd as usize];
+        let level = self.get_level(bdd);
+        let mut count : u128 = 0;
+        for child in [node.low, node.high].iter() {
+            //Every level skipped between this node and the child doubles the count.
+            let num_skipped_levels = self.count_levels_between(level, self.get_level(*child), levels_in_range);
+            count = count + (self.count_models_helper(*child, levels_in_range, memo) << num_skipped_levels);
+        }
+
+        memo.insert(bdd, count);
+        count
+    } //End count_models_helper
+
+    //Counts the levels in range that are strictly between two levels.
+    #[cfg(test)]
+    fn count_levels_between(&self, upper_level : u32, lower_level : u32, levels_in_range : &Vec<u32>) -> u32 {
+        levels_in_range.iter().filter(|level| **level > upper_level && **level < lower_level).count() as u32
+    } //End count_levels_between
+
+    //Counts the levels in range that are above a node.
+    #[cfg(test)]
+    fn count_levels_above(&self, bdd : BddNodeId, levels_in_range : &Vec<u32>) -> u32 {
+        let level = self.get_level(bdd);
+        levels_in_range.iter().filter(|other_level| **other_level < level).count() as u32
+    } //End count_levels_above
+
+    fn get_satisfying_cubes_helper(&self, bdd : BddNodeId, current_cube : &mut Vec<u32>,
+                                   cube_vec : &mut Vec<Vec<u32>>)
+    {
+        if bdd == BDD_FALSE {return;}
+        if bdd == BDD_TRUE {
+            cube_vec.push(current_cube.clone());
+            return;
+        }
+
+        let node = &self.node_vec[bdd as usize];
+        current_cube.push(node.variable | NEGATIVITY_FLAG);
+        self.get_satisfying_cubes_helper(node.low, current_cube, cube_vec);
+        current_cube.pop();
+
+        current_cube.push(node.variable);
+        self.get_satisfying_cubes_helper(node.high, current_cube, cube_vec);
+        current_cube.pop();
+    } //End get_satisfying_cubes_helper
+
+    #[cfg(test)]
+    fn to_truth_table_helper(&self, bdd : BddNodeId, num_booleans : u32, memo : &mut HashMap<BddNodeId, u32>) -> u32 {
+        if bdd == BDD_FALSE {return 0;}
+        if bdd == BDD_TRUE {return get_truth_table_mask(num_booleans);}
+        if let Some(truth_table) = memo.get(&bdd) {return *truth_table;}
+
+        let node = &self.node_vec[bdd as usize];
+        let positive_rows = get_boolean_truth_table(node.variable, num_booleans);
+        let low_truth_table = self.to_truth_table_helper(node.low, num_booleans, memo);
+        let high_truth_table = self.to_truth_table_helper(node.high, num_booleans, memo);
+        let truth_table = (positive_rows & high_truth_table) | (!positive_rows & low_truth_table);
+
+        memo.insert(bdd, truth_table);
+        truth_table
+    } //End to_truth_table_helper
+} //End impl BddManager
+
+///The reordering algorithms.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ReorderingMethod {
+    Sifting,
+    Exact
+} //End enum ReorderingMethod
+
+impl ReorderingMethod {
+    ///Parses a reordering method from its command line name.
+    pub fn parse(text : &str) -> Option<ReorderingMethod> {
+        match text {
+            "sift" => Some(ReorderingMethod::Sifting),
+            "exact" => Some(ReorderingMethod::Exact),
+            _ => None
+        }
+    } //End parse
+} //End impl ReorderingMethod
+
+///This struct reports the result of reordering.  Sizes are the number of nodes reachable from the roots, including
+///terminals.  Orders list the variables from the top level to the bottom level.
+pub struct ReorderingReport {
+    method : ReorderingMethod,
+    size_before : usize,
+    size_after : usize,
+    order_before : Vec<u32>,
+    order_after : Vec<u32>
+} //End struct ReorderingReport
+
+impl ReorderingReport {
+    pub fn get_size_before(&self) -> usize {self.size_before}
+    pub fn get_size_after(&self) -> usize {self.size_after}
+
+    ///Gets a text representation of this report.
+    ///boolean_name_list is a list of the names of the variables
+    pub fn get_as_text(&self, boolean_name_list : &Vec<String>) -> String {
+        let get_order_text = |order : &Vec<u32>| -> String {
+            order.iter().map(|variable| boolean_name_list[(*variable - 1) as usize].clone())
+                .collect::<Vec<String>>().join(" < ")
+        };
+
+        format!("{:?} reordering\nBefore: {} nodes with order {}\nAfter: {} nodes with order {}\n", self.method,
+                self.size_before, get_order_text(&self.order_before), self.size_after,
+                get_order_text(&self.order_after))
+    } //End get_as_text
+} //End impl ReorderingReport
+
+//PRIVATE//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+///Exact reordering tries n! orders, so it's limited to small numbers of variables.
+const MAX_EXACT_REORDERING_VARIABLES : usize = 8;
+
+///The variable stored in the terminal nodes.  It's larger than any real variable, so terminals sort last.
+const TERMINAL_VARIABLE : u32 = u32::MAX;
+
+#[derive(Clone, Copy)]
+struct BddNode {
+    variable : u32,
+    low : BddNodeId,
+    high : BddNodeId
+} //End struct BddNode
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NUM_TEST_BOOLEANS : u32 = 3;
+
+    #[test]
+    fn truth_tables_round_trip() {
+        let mut manager = BddManager::new(NUM_TEST_BOOLEANS);
+        let mask = get_truth_table_mask(NUM_TEST_BOOLEANS);
+        for truth_table in 0..=mask {
+            let bdd = manager.build_from_truth_table(truth_table, NUM_TEST_BOOLEANS);
+            assert_eq!(manager.to_truth_table(bdd, NUM_TEST_BOOLEANS), truth_table);
+        }
+    }
+
+    #[test]
+    fn apply_matches_truth_tables() {
+        let mut manager = BddManager::new(NUM_TEST_BOOLEANS);
+        let mask = get_truth_table_mask(NUM_TEST_BOOLEANS);
+        for left_truth_table in 0..=mask {
+            let left = manager.build_from_truth_table(left_truth_table, NUM_TEST_BOOLEANS);
+            let negation = manager.negate(left);
+            assert_eq!(manager.to_truth_table(negation, NUM_TEST_BOOLEANS), !left_truth_table & mask);
+
+            for right_truth_table in 0..=mask {
+                let right = manager.build_from_truth_table(right_truth_table, NUM_TEST_BOOLEANS);
+                let conjunction = manager.apply(BddOperator::Conjunction, left, right);
+                let disjunction = manager.apply(BddOperator::Disjunction, left, right);
+                let exclusive_disjunction = manager.apply(BddOperator::ExclusiveDisjunction, left, right);
+                assert_eq!(manager.to_truth_table(conjunction, NUM_TEST_BOOLEANS),
+                           left_truth_table & right_truth_table);
+                assert_eq!(manager.to_truth_table(disjunction, NUM_TEST_BOOLEANS),
+                           left_truth_table | right_truth_table);
+                assert_eq!(manager.to_truth_table(exclusive_disjunction, NUM_TEST_BOOLEANS),
+                           left_truth_table ^ right_truth_table);
+            } //End for each right truth table
+        } //End for each left truth table
+    }
+
+    #[test]
+    fn if_then_else_matches_truth_tables() {
+        let mut manager = BddManager::new(NUM_TEST_BOOLEANS);
+        let truth_table_list = [0x00, 0xff, 0xf0, 0xcc, 0xaa, 0x96, 0xe8, 0x17];
+        for condition_truth_table in truth_table_list {
+            for then_truth_table in truth_table_list {
+                for else_truth_table in truth_table_list {
+                    let condition = manager.build_from_truth_table(condition_truth_table, NUM_TEST_BOOLEANS);
+                    let then_bdd = manager.build_from_truth_table(then_truth_table, NUM_TEST_BOOLEANS);
+                    let else_bdd = manager.build_from_truth_table(else_truth_table, NUM_TEST_BOOLEANS);
+                    let result = manager.if_then_else(condition, then_bdd, else_bdd);
+                    let expected_truth_table =
+                        (condition_truth_table & then_truth_table) | (!condition_truth_table & else_truth_table);
+                    assert_eq!(manager.to_truth_table(result, NUM_TEST_BOOLEANS), expected_truth_table);
+                } //End for each else truth table
+            } //End for each then truth table
+        } //End for each condition truth table
+    }
+
+    #[test]
+    fn restrict_and_quantifiers_match_cofactors() {
+        let mut manager = BddManager::new(NUM_TEST_BOOLEANS);
+        for truth_table in 0..=get_truth_table_mask(NUM_TEST_BOOLEANS) {
+            let bdd = manager.build_from_truth_table(truth_table, NUM_TEST_BOOLEANS);
+            for variable in 1..=NUM_TEST_BOOLEANS {
+                let low_cofactor = compute_cofactor(truth_table, NUM_TEST_BOOLEANS, variable, false);
+                let high_cofactor = compute_cofactor(truth_table, NUM_TEST_BOOLEANS, variable, true);
+                let low = manager.restrict(bdd, variable, false);
+                let high = manager.restrict(bdd, variable, true);
+                let exists = manager.exists(bdd, &[variable]);
+                let for_all = manager.for_all(bdd, &[variable]);
+                assert_eq!(manager.to_truth_table(low, NUM_TEST_BOOLEANS), low_cofactor);
+                assert_eq!(manager.to_truth_table(high, NUM_TEST_BOOLEANS), high_cofactor);
+                assert_eq!(manager.to_truth_table(exists, NUM_TEST_BOOLEANS), low_cofactor | high_cofactor);
+                assert_eq!(manager.to_truth_table(for_all, NUM_TEST_BOOLEANS), low_cofactor & high_cofactor);
+            } //End for each variable
+        } //End for each truth table
+    }
+
+    #[test]
+    fn count_models_matches_truth_tables() {
+        let mut manager = BddManager::new(NUM_TEST_BOOLEANS);
+        for truth_table in 0..=get_truth_table_mask(NUM_TEST_BOOLEANS) {
+            let bdd = manager.build_from_truth_table(truth_table, NUM_TEST_BOOLEANS);
+            assert_eq!(manager.count_models(bdd, NUM_TEST_BOOLEANS), truth_table.count_ones() as u128);
+            assert_eq!(manager.count_models(bdd, NUM_TEST_BOOLEANS + 2), 4 * truth_table.count_ones() as u128);
+        }
+    }
+
+    #[test]
+    fn count_models_counts_variables_the_manager_has_not_seen() {
+        let mut manager = BddManager::new(0);
+        let mut exclusive_disjunction = BDD_FALSE;
+        for variable in 1..=40 {
+            let variable_bdd = manager.get_variable(variable);
+            exclusive_disjunction =
+                manager.apply(BddOperator::ExclusiveDisjunction, exclusive_disjunction, variable_bdd);
+        }
+        assert_eq!(manager.count_models(exclusive_disjunction, 40), 1 << 39);
+        assert_eq!(manager.count_models(exclusive_disjunction, 41), 1 << 40);
+        assert_eq!(manager.count_models(BDD_TRUE, 100), 1 << 100);
+        assert_eq!(manager.count_models(BDD_FALSE, 100), 0);
+    }
+
+    #[test]
+    fn to_formula_gives_back_the_same_function() {
+        let mut manager = BddManager::new(NUM_TEST_BOOLEANS);
+        let tt_computer = TruthTableSize5Computer::new(NUM_TEST_BOOLEANS);
+        for truth_table in 0..=get_truth_table_mask(NUM_TEST_BOOLEANS) {
+            let bdd = manager.build_from_truth_table(truth_table, NUM_TEST_BOOLEANS);
+            let formula = manager.to_formula(bdd);
+            assert_eq!(tt_computer.compute_truth_table(&formula), truth_table);
+            assert_eq!(manager.build_from_formula(&formula), bdd);
+        }
+    }
+
+    //Gets the truth table of a cube over the booleans 1 to num_booleans.
+    fn get_cube_truth_table(cube : &[u32], num_booleans : u32) -> u32 {
+        let mask = get_truth_table_mask(num_booleans);
+        cube.iter().fold(mask, |truth_table, literal| {
+            let boolean_truth_table = get_boolean_truth_table(get_variable_index(*literal), num_booleans);
+            truth_table & if is_positive_literal(*literal) {boolean_truth_table} else {!boolean_truth_table & mask}
+        })
+    } //End get_cube_truth_table
+
+    //Asserts that the satisfying cubes of a BDD are disjoint and cover exactly its truth table, and that each one lists
+    //the variables from the top level down.
+    fn assert_cubes_cover_the_models(manager : &BddManager, bdd : BddNodeId, truth_table : u32) {
+        let mut covered_truth_table = 0;
+        for cube in manager.get_satisfying_cubes(bdd) {
+            let levels : Vec<usize> = cube.iter().map(|literal| {
+                manager.get_variable_order().iter().position(|variable| *variable == get_variable_index(*literal))
+                    .unwrap()
+            }).collect();
+            assert!(levels.windows(2).all(|level_pair| level_pair[0] < level_pair[1]));
+
+            let cube_truth_table = get_cube_truth_table(&cube, NUM_TEST_BOOLEANS);
+            assert_eq!(covered_truth_table & cube_truth_table, 0);
+            covered_truth_table = covered_truth_table | cube_truth_table;
+        } //End for each cube
+        assert_eq!(covered_truth_table, truth_table);
+    } //End assert_cubes_cover_the_models
+
+    #[test]
+    fn satisfying_cubes_are_disjoint_and_cover_the_models() {
+        let mut manager = BddManager::new(NUM_TEST_BOOLEANS);
+        for truth_table in 0..=get_truth_table_mask(NUM_TEST_BOOLEANS) {
+            let bdd = manager.build_from_truth_table(truth_table, NUM_TEST_BOOLEANS);
+            assert_cubes_cover_the_models(&manager, bdd, truth_table);
+
+            //Reordering changes the order of the literals in the cubes, but not what they cover.
+            let mut reordered_manager = BddManager::new(NUM_TEST_BOOLEANS);
+            let reordered_bdd = reordered_manager.build_from_truth_table(truth_table, NUM_TEST_BOOLEANS);
+            reordered_manager.reorder_exactly(&[reordered_bdd]).unwrap();
+            assert_cubes_cover_the_models(&reordered_manager, reordered_bdd, truth_table);
+        } //End for each truth table
+    }
+
+    #[test]
+    fn evaluate_matches_truth_tables() {
+        let mut manager = BddManager::new(NUM_TEST_BOOLEANS);
+        for truth_table in 0..=get_truth_table_mask(NUM_TEST_BOOLEANS) {
+            let bdd = manager.build_from_truth_table(truth_table, NUM_TEST_BOOLEANS);
+            for row in 0..get_num_truth_table_rows(NUM_TEST_BOOLEANS) {
+                let truth_values : HashMap<u32,bool> = (1..=NUM_TEST_BOOLEANS)
+                    .map(|variable| (variable, is_boolean_true_in_row(row, variable, NUM_TEST_BOOLEANS))).collect();
+                assert_eq!(manager.evaluate(bdd, &truth_values), is_truth_table_true_in_row(truth_table, row));
+            }
+        } //End for each truth table
+    }
+
+    #[test]
+    fn cubes_of_a_large_function_count_its_models() {
+        //(p1 & p2) | (p29 & ~p30) over 30 variables, which is too many for a truth table
+        let mut manager = BddManager::new(30);
+        let left = SimpleLogicNode::Conjunction(vec![SimpleLogicNode::Literal(1), SimpleLogicNode::Literal(2)]);
+        let right = SimpleLogicNode::Conjunction(vec![SimpleLogicNode::Literal(29),
+                                                      SimpleLogicNode::Literal(30 | NEGATIVITY_FLAG)]);
+        let formula = SimpleLogicNode::Disjunction(vec![left, right]);
+        let bdd = manager.build_from_formula(&formula);
+        assert_eq!(manager.build_from_formula(&manager.to_formula(bdd)), bdd);
+
+        let cube_vec = manager.get_satisfying_cubes(bdd);
+        let num_cube_models : u128 = cube_vec.iter().map(|cube| 1u128 << (30 - cube.len())).sum();
+        assert_eq!(num_cube_models, manager.count_models(bdd, 30));
+        for cube in cube_vec {
+            let truth_values : HashMap<u32,bool> =
+                cube.iter().map(|literal| (get_variable_index(*literal), is_positive_literal(*literal))).collect();
+            assert!(manager.evaluate(bdd, &truth_values));
+        }
+    }
+
+    //Gets every permutation of the variables 1 to num_variables.
+    fn get_variable_orders(num_variables : u32) -> Vec<Vec<u32>> {
+        if num_variables == 0 {return vec![Vec::new()];}
+
+        let mut order_vec = Vec::new();
+        for shorter_order in get_variable_orders(num_variables - 1) {
+            for position in 0..=shorter_order.len() {
+                let mut order = shorter_order.clone();
+                order.insert(position, num_variables);
+                order_vec.push(order);
+            }
+        } //End for each order of the other variables
+        order_vec
+    } //End get_variable_orders
+
+    //Gets some truth tables over 4 booleans, spread out with a linear congruential generator.
+    fn get_test_truth_tables() -> Vec<u32> {
+        let mut truth_table : u32 = 1;
+        (0..200).map(|_| {
+            truth_table = truth_table.wrapping_mul(1103515245).wrapping_add(12345);
+            (truth_table >> 8) & get_truth_table_mask(4)
+        }).collect()
+    } //End get_test_truth_tables
+
+    #[test]
+    fn reordering_keeps_every_function() {
+        for reordering_method in [ReorderingMethod::Sifting, ReorderingMethod::Exact] {
+            let mut manager = BddManager::new(4);
+            let truth_table_list = get_test_truth_tables();
+            let root_list : Vec<BddNodeId> = truth_table_list.iter().map(|truth_table| {
+                manager.build_from_truth_table(*truth_table, 4)
+            }).collect();
+
+            let report = manager.reorder(reordering_method, &root_list).unwrap();
+            assert!(report.get_size_after() <= report.get_size_before());
+            assert_eq!(report.get_size_after(), manager.count_shared_nodes(&root_list));
+            for (root, truth_table) in root_list.iter().zip(truth_table_list.iter()) {
+                assert_eq!(manager.to_truth_table(*root, 4), *truth_table);
+            }
+
+            //The unique table still works, so rebuilding a function finds the same node.
+            for (root, truth_table) in root_list.iter().zip(truth_table_list.iter()) {
+                assert_eq!(manager.build_from_truth_table(*truth_table, 4), *root);
+            }
+        } //End for each reordering method
+    }
+
+    #[test]
+    fn exact_reordering_finds_the_smallest_order() {
+        let variable_orders = get_variable_orders(4);
+        for truth_table in get_test_truth_tables() {
+            //Build the BDD in every order to find the smallest size.
+            let mut smallest_size = usize::MAX;
+            for variable_order in &variable_orders {
+                let mut manager = BddManager::new(4);
+                manager.move_to_order(variable_order);
+                let bdd = manager.build_from_truth_table(truth_table, 4);
+                smallest_size = smallest_size.min(manager.count_nodes(bdd));
+            }
+
+            let mut manager = BddManager::new(4);
+            let bdd = manager.build_from_truth_table(truth_table, 4);
+            let report = manager.reorder_exactly(&[bdd]).unwrap();
+            assert_eq!(report.get_size_after(), smallest_size, "truth table {}", truth_table);
+            assert_eq!(manager.count_nodes(bdd), smallest_size);
+            assert_eq!(manager.to_truth_table(bdd, 4), truth_table);
+        } //End for each truth table
+    }
+
+    #[test]
+    fn sifting_shrinks_a_badly_ordered_comparator() {
+        //(p1 <=> p4) & (p2 <=> p5) & (p3 <=> p6) is exponential in the order p1 < p2 < p3 < p4 < p5 < p6, and linear
+        //when each pair is next to each other.
+        let comparator_formula = create_conjunction((1..=3).map(|variable| create_equivalence(vec![
+            SimpleLogicNode::Literal(variable), SimpleLogicNode::Literal(variable + 3)])).collect());
+
+        let mut manager = BddManager::new(6);
+        let comparator = manager.build_from_formula(&comparator_formula);
+        let size_before = manager.count_nodes(comparator);
+        let model_count = manager.count_models(comparator, 6);
+
+        let report = manager.reorder_by_sifting(&[comparator]);
+        assert_eq!(report.get_size_before(), size_before);
+        assert_eq!(report.get_size_after(), 11);
+        assert_eq!(manager.count_models(comparator, 6), model_count);
+    }
+
+    #[test]
+    fn building_past_the_threshold_reorders_automatically() {
+        let comparator_formula = create_conjunction((1..=3).map(|variable| create_equivalence(vec![
+            SimpleLogicNode::Literal(variable), SimpleLogicNode::Literal(variable + 3)])).collect());
+        let mut manager = BddManager::new(6);
+        let first_comparator = manager.build_from_formula(&comparator_formula);
+        let size_before = manager.count_nodes(first_comparator);
+
+        //Automatic reordering doesn't free anything, so BDDs built before it still work.
+        manager.set_auto_reordering_threshold(Some(size_before - 1));
+        let comparator = manager.build_from_formula(&comparator_formula);
+        assert_eq!(comparator, first_comparator);
+        assert_eq!(manager.count_nodes(comparator), 11);
+        assert_ne!(manager.get_variable_order(), &vec![1, 2, 3, 4, 5, 6]);
+
+        //Small BDDs don't reorder.
+        let order_after = manager.get_variable_order().clone();
+        let variable = manager.get_variable(1);
+        let other_variable = manager.get_variable(4);
+        let pair = manager.apply(BddOperator::ExclusiveDisjunction, variable, other_variable);
+        assert_eq!(manager.count_nodes(pair), 5);
+        assert_eq!(manager.get_variable_order(), &order_after);
+        assert_eq!(manager.count_models(comparator, 6), 8);
+    }
+} //End mod tests
@@ -0,0 +1,180 @@
+/** This file checks the formula buckets of a precompute run against an independent evaluation of every formula.
+    Author: Steven Fletcher
+    Created: 10/18/2026
+    Last Updated: 10/18/2026
+*/
+use crate::formula_precomputer::*;
+use crate::logic::*;
+use crate::truth_table_size_5::*;
+use std::collections::HashMap;
+
+///This struct reports what verify_formula_buckets found.  A truth table mismatch is a formula that isn't in the bucket
+///of its truth table, and a cost mismatch is a minimum formula whose cost is wrong or that isn't the cheapest formula
+///in its bucket.
+pub struct BucketVerificationReport {
+    num_formulas_checked : u64,
+    num_truth_table_mismatches : u64,
+    num_cost_mismatches : u64,
+    mismatch_text_vec : Vec<String>  //A description of the first MAX_REPORTED_MISMATCHES mismatches
+} //End struct BucketVerificationReport
+
+impl BucketVerificationReport {
+    ///Determines whether every formula was in the right bucket and every minimum formula was minimal.
+    pub fn is_correct(&self) -> bool {self.num_truth_table_mismatches == 0 && self.num_cost_mismatches == 0}
+
+    ///Gets the report as text, with one line per mismatch up to MAX_REPORTED_MISMATCHES.
+    pub fn get_as_text(&self) -> String {
+        let mut text = format!("Verification: {} formulas checked, {} in the wrong bucket, {} minimum formulas with \
+                                the wrong cost\n", self.num_formulas_checked, self.num_truth_table_mismatches,
+                               self.num_cost_mismatches);
+        for mismatch_text in self.mismatch_text_vec.iter() {
+            text.push_str("  ");
+            text.push_str(mismatch_text);
+            text.push('\n');
+        }
+
+        let num_mismatches = self.num_truth_table_mismatches + self.num_cost_mismatches;
+        if num_mismatches > self.mismatch_text_vec.len() as u64 {
+            text.push_str(&format!("  ...and {} more\n", num_mismatches - self.mismatch_text_vec.len() as u64));
+        }
+        text
+    } //End get_as_text
+
+    //PRIVATE
+    fn add_mismatch_text(&mut self, mismatch_text : String) {
+        if self.mismatch_text_vec.len() < MAX_REPORTED_MISMATCHES {self.mismatch_text_vec.push(mismatch_text);}
+    } //End add_mismatch_text
+} //End impl BucketVerificationReport
+
+///Checks every formula in every bucket by evaluating it with LogicFormula::evaluate in each of the 2^n rows, which
+///doesn't share any code with the bitmasks that put the formulas in their buckets.  It also recomputes the cost of
+///each minimum formula and checks that no formula kept in its bucket is cheaper.
+///tt_bucket_vec is the buckets, indexed by internal truth table
+///cost_model is the cost model that chose the minimum formulas
+///convention determines how the truth tables are numbered in the report
+pub fn verify_formula_buckets(tt_bucket_vec : &Vec<LogicFormulaBucket>, num_booleans : u32,
+                              cost_model : FormulaCostModel, convention : TruthTableConvention)
+    -> BucketVerificationReport
+{
+    let mut report = BucketVerificationReport {
+        num_formulas_checked : 0,
+        num_truth_table_mismatches : 0,
+        num_cost_mismatches : 0,
+        mismatch_text_vec : Vec::new()
+    };
+
+    let boolean_name_list : Vec<String> = (1..=num_booleans).map(|i| format!("p{}", i)).collect();
+    let truth_values_vec : Vec<HashMap<u32,bool>> = (0..get_num_truth_table_rows(num_booleans)).map(|row| {
+        (1..=num_booleans).map(|boolean_index| (boolean_index, is_boolean_true_in_row(row, boolean_index,
+                                                                                        num_booleans))).collect()
+    }).collect();
+
+    for (truth_table, bucket) in tt_bucket_vec.iter().enumerate() {
+        let truth_table = truth_table as u32;
+        let truth_table_text = convert_truth_table_from_internal(truth_table, num_booleans, convention).to_string();
+        let minimum_formula_option = bucket.get_minimum_formula();
+        let formula_vec = bucket.get_formula_vector();
+
+        //Truth tables.  The minimum formula is usually one of the formulas kept, so it's only checked on its own if
+        //it isn't.
+        let unkept_minimum_option = minimum_formula_option.as_ref().filter(|minimum_formula| {
+            !formula_vec.contains(minimum_formula)
+        });
+        for formula in unkept_minimum_option.into_iter().chain(formula_vec.iter()) {
+            report.num_formulas_checked = report.num_formulas_checked + 1;
+            let mismatch_row_option = (0..get_num_truth_table_rows(num_booleans)).find(|row| {
+                let expected_value = is_truth_table_true_in_row(truth_table, *row);
+                match formula.evaluate(&truth_values_vec[*row as usize]) {
+                    TruthValue::MustBeTrue => !expected_value,
+                    TruthValue::MustBeFalse => expected_value,
+                    TruthValue::Unrestricted => true
+                }
+            });
+
+            if let Some(row) = mismatch_row_option {
+                report.num_truth_table_mismatches = report.num_truth_table_mismatches + 1;
+                let row_text_vec : Vec<String> = (1..=num_booleans).map(|boolean_index| {
+                    let value_text = if truth_values_vec[row as usize][&boolean_index] {TRUE_TEXT} else {FALSE_TEXT};
+                    format!("{} = {}", boolean_name_list[(boolean_index - 1) as usize], value_text)
+                }).collect();
+                report.add_mismatch_text(format!("Truth table {}: {} is wrong in the row {}", truth_table_text,
+                                                 get_formula_text(formula, &boolean_name_list),
+                                                 row_text_vec.join(", ")));
+            }
+        } //End for each formula
+
+        //Costs
+        let minimum_formula = match minimum_formula_option {
+            Some(minimum_formula) => minimum_formula,
+            None => continue
+        };
+        let minimum_cost = bucket.get_minimum_cost().unwrap_or(0);
+        let recomputed_cost = cost_model.compute_cost(&minimum_formula, num_booleans);
+        let cheaper_formula_option = formula_vec.iter().find(|formula| {
+            cost_model.compute_cost(formula, num_booleans) < recomputed_cost
+        });
+
+        if recomputed_cost != minimum_cost {
+            report.num_cost_mismatches = report.num_cost_mismatches + 1;
+            report.add_mismatch_text(format!("Truth table {}: the minimum formula {} costs {}, not {}",
+                                             truth_table_text, get_formula_text(&minimum_formula, &boolean_name_list),
+                                             recomputed_cost, minimum_cost));
+        }
+        else if let Some(cheaper_formula) = cheaper_formula_option {
+            report.num_cost_mismatches = report.num_cost_mismatches + 1;
+            report.add_mismatch_text(format!("Truth table {}: {} is cheaper than the minimum formula {}",
+                                             truth_table_text, get_formula_text(cheaper_formula, &boolean_name_list),
+                                             get_formula_text(&minimum_formula, &boolean_name_list)));
+        }
+    } //End for each bucket
+
+    report
+} //End verify_formula_buckets
+
+//PRIVATE//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+const MAX_REPORTED_MISMATCHES : usize = 100;
+
+//Gets the text of a formula, even if it has booleans past the end of boolean_name_list, which is a mismatch of its own.
+fn get_formula_text(formula : &SimpleLogicNode, boolean_name_list : &Vec<String>) -> String {
+    let max_variable_index = formula.get_max_variable_index();
+    if max_variable_index as usize <= boolean_name_list.len() {return formula.get_as_text(boolean_name_list);}
+
+    let extended_name_list : Vec<String> = (1..=max_variable_index).map(|i| format!("p{}", i)).collect();
+    formula.get_as_text(&extended_name_list)
+} //End get_formula_text
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn finds_formulas_in_the_wrong_bucket() {
+        let precompute_settings = PrecomputeSettings {
+            cost_model : FormulaCostModel::BinaryOperators,
+            bucket_mode : BucketMode::Full,
+            num_threads : 2,
+            pruning_settings : PruningSettings::new(),
+            family_settings : FormulaFamilySettings::new(),
+            max_shape_operators : 0,
+            verify_duality : false,
+            progress_interval : Duration::ZERO,
+            memory_budget : None
+        };
+        let (mut formula_buckets, _) = generate_truth_tables_with_up_to_n_variables(2, &precompute_settings, None)
+                                           .unwrap();
+        let convention = TruthTableConvention::FirstBooleanMostSignificant;
+        let report = verify_formula_buckets(&formula_buckets, 2, precompute_settings.cost_model, convention);
+        assert!(report.is_correct());
+        let num_formulas = formula_buckets.iter().map(|bucket| bucket.get_num_formulas()).sum::<u64>();
+        assert_eq!(report.num_formulas_checked, num_formulas);
+
+        //Swapping two buckets puts every formula of both in the wrong bucket.
+        formula_buckets.swap(1, 2);
+        let report = verify_formula_buckets(&formula_buckets, 2, precompute_settings.cost_model, convention);
+        assert!(!report.is_correct());
+        assert_eq!(report.num_truth_table_mismatches,
+                   formula_buckets[1].get_num_formulas() + formula_buckets[2].get_num_formulas());
+        assert_eq!(report.num_cost_mismatches, 0);
+    } //End finds_formulas_in_the_wrong_bucket
+} //End mod tests
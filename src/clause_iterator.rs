@@ -0,0 +1,212 @@
+/** This file enumerates the clauses over some booleans.
+    Author: Steven Fletcher
+    Created: 10/18/2026
+    Last Updated: 10/18/2026
+*/
+use crate::logic::*;
+
+///This struct iterates over every clause with 1 to max_width literals over the booleans 1 to n.  A clause is a Vec of
+///literals with different booleans, in order of increasing boolean index.  Whether the literals are joined by & or |
+///is up to the caller.
+///
+///The clauses come in order of increasing width.  Within a width, the sets of booleans come in lexicographic order, and
+///each set of booleans comes with every assignment of signs.  The signs count up in binary, with the first literal as
+///the least significant bit.  Clauses with 1 or 2 literals start with every literal positive, so p1 & p2 is followed by
+///~p1 & p2, p1 & ~p2 and ~p1 & ~p2.  Wider clauses start with every literal negative, so ~p1 & ~p2 & ~p3 is followed
+///by p1 & ~p2 & ~p3.  This is the order of the nested loops the iterator replaced, and the order decides which of two
+///equally cheap formulas becomes the minimum formula of a truth table.
+pub struct ClauseIterator {
+    num_booleans : u32,
+    max_width : u32,
+    min_negative_first_width : u32,  //Clauses with at least this many literals start with every literal negative
+    is_single_boolean_set : bool,    //True if the iterator stops after the signs of its first set of booleans
+    boolean_index_vec : Vec<u32>,    //The booleans of the current clause.  Empty when the iterator is done.
+    sign_configuration : u64         //Bit i is 1 if literal i doesn't have its starting sign
+} //End struct ClauseIterator
+
+impl ClauseIterator {
+    ///Creates an iterator over every clause with 1 to max_width literals over the booleans 1 to num_booleans.
+    ///max_width is capped at num_booleans, since a clause can't have more literals than there are booleans.
+    pub fn new(num_booleans : u32, max_width : u32) -> ClauseIterator {
+        let max_width = max_width.min(num_booleans).min(MAX_CLAUSE_WIDTH);
+        ClauseIterator {
+            num_booleans : num_booleans,
+            max_width : max_width,
+            min_negative_first_width : MIN_NEGATIVE_FIRST_WIDTH,
+            is_single_boolean_set : false,
+            boolean_index_vec : if max_width >= 1 {vec![1]} else {Vec::new()},
+            sign_configuration : 0
+        }
+    } //End new
+
+    ///Creates an iterator over every assignment of signs to some booleans, starting with every literal negative.  The
+    ///clauses have the booleans in the order they're given.
+    pub fn with_booleans(boolean_index_vec : Vec<u32>) -> ClauseIterator {
+        assert!(boolean_index_vec.len() as u32 <= MAX_CLAUSE_WIDTH, "A clause can't have {} literals",
+                boolean_index_vec.len());
+        ClauseIterator {
+            num_booleans : boolean_index_vec.iter().copied().max().unwrap_or(0),
+            max_width : boolean_index_vec.len() as u32,
+            min_negative_first_width : 1,
+            is_single_boolean_set : true,
+            boolean_index_vec : boolean_index_vec,
+            sign_configuration : 0
+        }
+    } //End with_booleans
+
+    ///Counts the clauses this iterator generates from the start: the sum over each width w of
+    ///(n choose w) * 2^w.
+    pub fn count_clauses(num_booleans : u32, max_width : u32) -> u64 {
+        let mut num_clauses = 0;
+        let mut num_boolean_sets : u64 = 1;  //n choose width
+        for width in 1..=max_width.min(num_booleans).min(MAX_CLAUSE_WIDTH) {
+            num_boolean_sets = num_boolean_sets * (num_booleans - width + 1) as u64 / width as u64;
+            num_clauses = num_clauses + (num_boolean_sets << width);
+        }
+        num_clauses
+    } //End count_clauses
+
+    //Moves to the next set of booleans, which is the next width if every set of this width is done.
+    fn advance_boolean_set(&mut self) {
+        let width = self.boolean_index_vec.len() as u32;
+
+        //Find the last boolean that can still be increased, and reset the booleans after it.
+        for position in (0..width).rev() {
+            let boolean_index = self.boolean_index_vec[position as usize];
+            if boolean_index < self.num_booleans - (width - 1 - position) {
+                for later_position in position..width {
+                    self.boolean_index_vec[later_position as usize] = boolean_index + 1 + (later_position - position);
+                }
+                return;
+            }
+        } //End for each position from the last
+
+        //Every set of this width is done, so start the next width.
+        if width < self.max_width {self.boolean_index_vec = (1..=width + 1).collect();}
+        else {self.boolean_index_vec.clear();}
+    } //End advance_boolean_set
+} //End impl ClauseIterator
+
+impl Iterator for ClauseIterator {
+    type Item = Vec<u32>;
+
+    ///ClauseIterator.next to implement Iterator.next
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.boolean_index_vec.is_empty() {return None;}
+
+        let is_negative_first = self.boolean_index_vec.len() as u32 >= self.min_negative_first_width;
+        let clause = self.boolean_index_vec.iter().enumerate().map(|(position, boolean_index)| {
+            if (self.sign_configuration & (1 << position) != 0) != is_negative_first {*boolean_index | NEGATIVITY_FLAG}
+            else {*boolean_index}
+        }).collect();
+
+        //Move to the next signs, or to the next set of booleans if every sign assignment is done.
+        self.sign_configuration = self.sign_configuration + 1;
+        if self.sign_configuration >> self.boolean_index_vec.len() != 0 {
+            self.sign_configuration = 0;
+            if self.is_single_boolean_set {self.boolean_index_vec.clear();}
+            else {self.advance_boolean_set();}
+        }
+
+        Some(clause)
+    } //End next
+} //End impl Iterator for ClauseIterator
+
+//PRIVATE//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+//The widest clause whose sign assignments fit in a u64.  Boolean indexes can't go that high anyways.
+const MAX_CLAUSE_WIDTH : u32 = 63;
+//The narrowest clauses that ClauseIterator::new starts with every literal negative.
+const MIN_NEGATIVE_FIRST_WIDTH : u32 = 3;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //Gets every assignment of signs to some booleans the way the old AssignFlagsIterator did: the signs count up in
+    //binary with the first literal as the least significant bit, and a 0 bit is a negative literal.
+    fn get_negative_first_clauses(boolean_index_vec : &[u32]) -> Vec<Vec<u32>> {
+        (0..(1u32 << boolean_index_vec.len())).map(|configuration| {
+            boolean_index_vec.iter().enumerate().map(|(position, boolean_index)| {
+                if configuration & (1 << position) == 0 {*boolean_index | NEGATIVITY_FLAG}
+                else {*boolean_index}
+            }).collect()
+        }).collect()
+    } //End get_negative_first_clauses
+
+    //Gets every clause over n booleans with the nested loops that ClauseIterator replaced.
+    fn get_nested_loop_clauses(n : u32) -> Vec<Vec<u32>> {
+        let mut literal_subarray_vec = Vec::new();
+
+        //Single literals and pairs start with every literal positive.
+        for i in 1..=n {
+            literal_subarray_vec.push(vec![i]);
+            literal_subarray_vec.push(vec![i | NEGATIVITY_FLAG]);
+        }
+        for i in 1..=n {
+            for j in i+1..=n {
+                literal_subarray_vec.push(vec![i, j]);
+                literal_subarray_vec.push(vec![i | NEGATIVITY_FLAG, j]);
+                literal_subarray_vec.push(vec![i, j | NEGATIVITY_FLAG]);
+                literal_subarray_vec.push(vec![i | NEGATIVITY_FLAG, j | NEGATIVITY_FLAG]);
+            }
+        }
+
+        //Wider clauses start with every literal negative.
+        for i in 1..=n {
+            for j in i+1..=n {
+                for k in j+1..=n {
+                    literal_subarray_vec.extend(get_negative_first_clauses(&[i, j, k]));
+                }
+            }
+        }
+        for i in 1..=n {
+            for j in i+1..=n {
+                for k in j+1..=n {
+                    for l in k+1..=n {
+                        literal_subarray_vec.extend(get_negative_first_clauses(&[i, j, k, l]));
+                    }
+                }
+            }
+        }
+        for i in 1..=n {
+            for j in i+1..=n {
+                for k in j+1..=n {
+                    for l in k+1..=n {
+                        for m in l+1..=n {
+                            literal_subarray_vec.extend(get_negative_first_clauses(&[i, j, k, l, m]));
+                        }
+                    }
+                }
+            }
+        }
+
+        literal_subarray_vec
+    } //End get_nested_loop_clauses
+
+    #[test]
+    fn clauses_come_in_the_nested_loop_order() {
+        for n in 1..=5 {
+            assert_eq!(ClauseIterator::new(n, n).collect::<Vec<Vec<u32>>>(), get_nested_loop_clauses(n), "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn count_clauses_matches_each_width() {
+        for n in 1..=6 {
+            for max_width in 1..=n {
+                let clause_vec : Vec<Vec<u32>> = ClauseIterator::new(n, max_width).collect();
+                assert_eq!(clause_vec.len() as u64, ClauseIterator::count_clauses(n, max_width));
+
+                let num_widest_clauses = clause_vec.iter().filter(|clause| clause.len() as u32 == max_width).count();
+                assert_eq!(num_widest_clauses as u64,
+                           ClauseIterator::count_clauses(n, max_width) - ClauseIterator::count_clauses(n, max_width - 1));
+            } //End for each maximum width
+        } //End for each number of booleans
+    }
+
+    #[test]
+    fn with_booleans_starts_with_every_literal_negative() {
+        assert_eq!(ClauseIterator::with_booleans(vec![3, 1]).collect::<Vec<Vec<u32>>>(),
+                   get_negative_first_clauses(&[3, 1]));
+    }
+} //End mod tests
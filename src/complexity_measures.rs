@@ -0,0 +1,394 @@
+/** This file computes complexity measures of boolean functions from their truth tables.
+    Author: Steven Fletcher
+    Created: 10/18/2026
+    Last Updated: 10/18/2026
+*/
+use crate::html_text::*;
+use crate::truth_table_size_5::*;
+
+///This struct stores the complexity measures of a truth table with at most 5 booleans.  Every measure is computed
+///exactly.
+///
+///Sensitivity of an input x: the number of booleans that change the value of the function when flipped.
+///Block sensitivity of x: the largest number of disjoint blocks of booleans that each change the value when flipped.
+///Certificate complexity of x: the smallest number of booleans of x that force the value of the function.
+///Influence of a boolean: the fraction of inputs where flipping the boolean changes the value.
+///Decision tree depth: the depth of the shallowest decision tree that computes the function.
+pub struct ComplexityMeasures {
+    num_booleans : u32,
+    average_sensitivity : f64,
+    max_sensitivity : u32,
+    block_sensitivity : u32,
+    certificate_complexity : u32,
+    influence_vec : Vec<f64>,  //influence_vec[i - 1] is the influence of boolean i
+    decision_tree_depth : u32
+} //End struct ComplexityMeasures
+
+impl ComplexityMeasures {
+    ///Computes all the complexity measures of a truth table.
+    ///truth_table is a truth table over num_booleans booleans (see truth_table_size_5.rs for the format)
+    pub fn new(truth_table : u32, num_booleans : u32) -> ComplexityMeasures {
+        let truth_table = truth_table & get_truth_table_mask(num_booleans);
+        let num_rows = get_num_truth_table_rows(num_booleans);
+
+        let mut total_sensitivity = 0;
+        let mut max_sensitivity = 0;
+        let mut block_sensitivity = 0;
+        let mut certificate_complexity = 0;
+        let mut num_sensitive_rows_vec = vec![0; num_booleans as usize];
+
+        for row in 0..num_rows {
+            //Sensitivity and influence
+            let mut sensitivity = 0;
+            for boolean_index in 1..=num_booleans {
+                if is_sensitive_to_block(truth_table, row, get_boolean_block(boolean_index, num_booleans)) {
+                    sensitivity = sensitivity + 1;
+                    let vec_index = (boolean_index - 1) as usize;
+                    num_sensitive_rows_vec[vec_index] = num_sensitive_rows_vec[vec_index] + 1;
+                }
+            } //End for each boolean
+            total_sensitivity = total_sensitivity + sensitivity;
+            max_sensitivity = max_sensitivity.max(sensitivity);
+
+            //Block sensitivity and certificate complexity
+            block_sensitivity = block_sensitivity.max(compute_block_sensitivity_at_row(truth_table, num_booleans, row));
+            certificate_complexity =
+                certificate_complexity.max(compute_certificate_complexity_at_row(truth_table, num_booleans, row));
+        } //End for each row
+
+        let influence_vec = num_sensitive_rows_vec.iter()
+            .map(|num_sensitive_rows| *num_sensitive_rows as f64 / num_rows as f64).collect();
+
+        ComplexityMeasures {
+            num_booleans : num_booleans,
+            average_sensitivity : total_sensitivity as f64 / num_rows as f64,
+            max_sensitivity : max_sensitivity,
+            block_sensitivity : block_sensitivity,
+            certificate_complexity : certificate_complexity,
+            influence_vec : influence_vec,
+            decision_tree_depth : compute_decision_tree_depth(truth_table, num_booleans)
+        }
+    } //End new
+
+    ///Gets the influence of a boolean.
+    ///boolean_index is the index of the boolean (1 to n)
+    pub fn get_influence(&self, boolean_index : u32) -> f64 {
+        self.influence_vec[(boolean_index - 1) as usize]
+    } //End get_influence
+
+    ///Gets the total influence, the sum of the influences of every boolean.  This always equals the average
+    ///sensitivity.
+    pub fn get_total_influence(&self) -> f64 {
+        self.influence_vec.iter().sum()
+    } //End get_total_influence
+
+    ///Adds the html for these complexity measures as a table.
+    ///boolean_name_list is a list of the names of the booleans.  They're used to label the influences.
+    pub fn add_html(&self, html_generator : &mut HtmlGenerator, boolean_name_list : &Vec<String>) {
+        html_generator.table_create_with_border(TABLE_BORDER_THICKNESS);
+        add_html_row(html_generator, "Average Sensitivity", &format!("{:.4}", self.average_sensitivity));
+        add_html_row(html_generator, "Maximum Sensitivity", &self.max_sensitivity.to_string());
+        add_html_row(html_generator, "Block Sensitivity", &self.block_sensitivity.to_string());
+        add_html_row(html_generator, "Certificate Complexity", &self.certificate_complexity.to_string());
+        for boolean_index in 1..=self.num_booleans {
+            add_html_row(html_generator, &format!("Influence of {}", boolean_name_list[(boolean_index - 1) as usize]),
+                         &format!("{:.4}", self.get_influence(boolean_index)));
+        } //End for each boolean
+        add_html_row(html_generator, "Total Influence", &format!("{:.4}", self.get_total_influence()));
+        add_html_row(html_generator, "Decision Tree Depth", &self.decision_tree_depth.to_string());
+        html_generator.table_end();
+    } //End add_html
+} //End impl ComplexityMeasures
+
+//PRIVATE//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+const TABLE_BORDER_THICKNESS : u8 = 1;
+const TAG_EMPTY_PROPERTIES : &str = "";
+
+fn add_html_row(html_generator : &mut HtmlGenerator, measure_name : &str, measure_value : &str) {
+    html_generator.table_add_row(TAG_EMPTY_PROPERTIES);
+    html_generator.table_add_header(TAG_EMPTY_PROPERTIES, measure_name);
+    html_generator.table_add_data(TAG_EMPTY_PROPERTIES, measure_value);
+} //End add_html_row
+
+//Blocks of booleans are stored as bitmasks over the bits of a row index.  Flipping a block is just xor.
+fn get_boolean_block(boolean_index : u32, num_booleans : u32) -> u32 {
+    1 << (num_booleans - boolean_index)
+} //End get_boolean_block
+
+//Determines whether flipping a block of booleans in a row changes the value of the truth table.
+fn is_sensitive_to_block(truth_table : u32, row : u32, block : u32) -> bool {
+    is_truth_table_true_in_row(truth_table, row) != is_truth_table_true_in_row(truth_table, row ^ block)
+} //End is_sensitive_to_block
+
+//Computes the block sensitivity at a row.  The blocks are packed with dynamic programming over the set of booleans
+//that haven't been used yet.  Every packing can use the lowest available boolean or skip it.
+fn compute_block_sensitivity_at_row(truth_table : u32, num_booleans : u32, row : u32) -> u32 {
+    let num_blocks = get_num_truth_table_rows(num_booleans);
+    let mut max_packing_vec : Vec<u32> = vec![0; num_blocks as usize];  //Indexed by the available booleans
+    for available in 1..num_blocks {
+        let lowest_boolean = available & available.wrapping_neg();
+
+        //Skip the lowest boolean.
+        let mut max_packing = max_packing_vec[(available ^ lowest_boolean) as usize];
+
+        //Use a sensitive block containing the lowest boolean.
+        let mut block = available;
+        while block != 0 {
+            if block & lowest_boolean != 0 && is_sensitive_to_block(truth_table, row, block) {
+                max_packing = max_packing.max(1 + max_packing_vec[(available ^ block) as usize]);
+            }
+            block = (block - 1) & available;
+        } //End for each subset of the available booleans
+
+        max_packing_vec[available as usize] = max_packing;
+    } //End for each set of available booleans
+
+    max_packing_vec[(num_blocks - 1) as usize]
+} //End compute_block_sensitivity_at_row
+
+//Computes the certificate complexity at a row.  A set of booleans is a certificate if every row that agrees with this
+//row on those booleans has the same value.
+fn compute_certificate_complexity_at_row(truth_table : u32, num_booleans : u32, row : u32) -> u32 {
+    let num_rows = get_num_truth_table_rows(num_booleans);
+    let value = is_truth_table_true_in_row(truth_table, row);
+
+    let mut certificate_complexity = num_booleans;
+    for certificate in 0..num_rows {
+        if certificate.count_ones() >= certificate_complexity {continue;}
+
+        let is_certificate = (0..num_rows)
+            .filter(|other_row| (other_row ^ row) & certificate == 0)
+            .all(|other_row| is_truth_table_true_in_row(truth_table, other_row) == value);
+        if is_certificate {
+            certificate_complexity = certificate.count_ones();
+        }
+    } //End for each set of booleans
+
+    certificate_complexity
+} //End compute_certificate_complexity_at_row
+
+//Computes the minimum decision tree depth with dynamic programming over every subcube.  A subcube fixes some of the
+//booleans.  It is encoded in base 3 with one digit per boolean: 0 = False, 1 = True, 2 = free.
+fn compute_decision_tree_depth(truth_table : u32, num_booleans : u32) -> u32 {
+    let num_subcubes = 3usize.pow(num_booleans);
+    let mut depth_vec : Vec<Option<u32>> = vec![None; num_subcubes];
+    compute_subcube_depth(truth_table, num_booleans, num_subcubes - 1, &mut depth_vec)
+} //End compute_decision_tree_depth
+
+fn compute_subcube_depth(truth_table : u32, num_booleans : u32, subcube : usize, depth_vec : &mut Vec<Option<u32>>)
+    -> u32
+{
+    if let Some(depth) = depth_vec[subcube] {return depth;}
+
+    //Find the values of the truth table on the subcube.
+    let mut contains_true = false;
+    let mut contains_false = false;
+    for row in 0..get_num_truth_table_rows(num_booleans) {
+        if !is_row_in_subcube(row, num_booleans, subcube) {continue;}
+        if is_truth_table_true_in_row(truth_table, row) {contains_true = true;}
+        else {contains_false = true;}
+    } //End for each row
+
+    let depth =
+        //If the function is constant on the subcube, no more queries are needed.
+        if !(contains_true && contains_false) {0}
+        //Else query the boolean that leads to the shallowest tree.
+        else {
+            //Find the free booleans.
+            let mut free_digit_vec = Vec::with_capacity(num_booleans as usize);
+            let mut digit_value = 1;
+            for _boolean in 0..num_booleans {
+                if (subcube / digit_value) % 3 == 2 {free_digit_vec.push(digit_value);}
+                digit_value = digit_value * 3;
+            }
+
+            let mut min_depth = u32::MAX;
+            for digit_value in free_digit_vec {
+                let false_depth = compute_subcube_depth(truth_table, num_booleans, subcube - 2 * digit_value, depth_vec);
+                let true_depth = compute_subcube_depth(truth_table, num_booleans, subcube - digit_value, depth_vec);
+                min_depth = min_depth.min(1 + false_depth.max(true_depth));
+            }
+            min_depth
+        };
+
+    depth_vec[subcube] = Some(depth);
+    depth
+} //End compute_subcube_depth
+
+//Determines whether a row is in a subcube.  The least significant base 3 digit is the last boolean, matching the
+//least significant bit of the row.
+fn is_row_in_subcube(row : u32, num_booleans : u32, subcube : usize) -> bool {
+    let mut remaining_subcube = subcube;
+    for bit in 0..num_booleans {
+        let digit = (remaining_subcube % 3) as u32;
+        if digit != 2 && digit != (row >> bit) & 1 {return false;}
+        remaining_subcube = remaining_subcube / 3;
+    } //End for each boolean
+
+    true
+} //End is_row_in_subcube
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAX_TEST_BOOLEANS : u32 = 3;
+    const TOLERANCE : f64 = 1e-9;
+
+    //Gets the row that differs from a row only in one boolean.
+    fn get_flipped_row(row : u32, boolean_index : u32, num_booleans : u32) -> u32 {
+        (0..get_num_truth_table_rows(num_booleans)).find(|other_row| {
+            (1..=num_booleans).all(|other_index| {
+                (is_boolean_true_in_row(row, other_index, num_booleans) !=
+                 is_boolean_true_in_row(*other_row, other_index, num_booleans)) == (other_index == boolean_index)
+            })
+        }).unwrap()
+    } //End get_flipped_row
+
+    //Gets the row with every boolean in a block flipped.  block is a list of boolean indexes.
+    fn get_row_with_block_flipped(row : u32, block : &[u32], num_booleans : u32) -> u32 {
+        block.iter().fold(row, |flipped_row, boolean_index| get_flipped_row(flipped_row, *boolean_index, num_booleans))
+    } //End get_row_with_block_flipped
+
+    //Gets every nonempty subset of a list of booleans.
+    fn get_nonempty_subsets(boolean_vec : &[u32]) -> Vec<Vec<u32>> {
+        (1..(1u32 << boolean_vec.len())).map(|subset| {
+            boolean_vec.iter().enumerate().filter(|(index, _)| subset & (1 << index) != 0)
+                .map(|(_, boolean_index)| *boolean_index).collect()
+        }).collect()
+    } //End get_nonempty_subsets
+
+    //The most disjoint sensitive blocks that fit in the available booleans, trying every block.
+    fn get_naive_block_sensitivity(truth_table : u32, num_booleans : u32, row : u32, available_vec : &[u32]) -> u32 {
+        let value = is_truth_table_true_in_row(truth_table, row);
+        let mut max_blocks = 0;
+        for block in get_nonempty_subsets(available_vec) {
+            let flipped_row = get_row_with_block_flipped(row, &block, num_booleans);
+            if is_truth_table_true_in_row(truth_table, flipped_row) == value {continue;}
+
+            let remaining_vec : Vec<u32> =
+                available_vec.iter().cloned().filter(|boolean_index| !block.contains(boolean_index)).collect();
+            let num_remaining_blocks = get_naive_block_sensitivity(truth_table, num_booleans, row, &remaining_vec);
+            max_blocks = max_blocks.max(1 + num_remaining_blocks);
+        } //End for each block
+
+        max_blocks
+    } //End get_naive_block_sensitivity
+
+    //The fewest booleans that force the value of a row, trying every set of booleans.
+    fn get_naive_certificate_complexity(truth_table : u32, num_booleans : u32, row : u32) -> u32 {
+        let value = is_truth_table_true_in_row(truth_table, row);
+        let boolean_vec : Vec<u32> = (1..=num_booleans).collect();
+        let mut subset_vec = get_nonempty_subsets(&boolean_vec);
+        subset_vec.push(Vec::new());
+
+        subset_vec.iter().filter(|subset| {
+            (0..get_num_truth_table_rows(num_booleans))
+                .filter(|other_row| subset.iter().all(|boolean_index| {
+                    is_boolean_true_in_row(*other_row, *boolean_index, num_booleans) ==
+                    is_boolean_true_in_row(row, *boolean_index, num_booleans)
+                }))
+                .all(|other_row| is_truth_table_true_in_row(truth_table, other_row) == value)
+        }).map(|subset| subset.len() as u32).min().unwrap()
+    } //End get_naive_certificate_complexity
+
+    //The depth of the shallowest decision tree on the rows that match a partial assignment.
+    fn get_naive_decision_tree_depth(truth_table : u32, num_booleans : u32, assignment_vec : &mut Vec<Option<bool>>)
+        -> u32
+    {
+        let value_vec : Vec<bool> = (0..get_num_truth_table_rows(num_booleans))
+            .filter(|row| (1..=num_booleans).all(|boolean_index| {
+                match assignment_vec[(boolean_index - 1) as usize] {
+                    Some(value) => is_boolean_true_in_row(*row, boolean_index, num_booleans) == value,
+                    None => true
+                }
+            }))
+            .map(|row| is_truth_table_true_in_row(truth_table, row)).collect();
+        if value_vec.iter().all(|value| *value == value_vec[0]) {return 0;}
+
+        let mut min_depth = u32::MAX;
+        for index in 0..num_booleans as usize {
+            if assignment_vec[index].is_some() {continue;}
+            assignment_vec[index] = Some(false);
+            let false_depth = get_naive_decision_tree_depth(truth_table, num_booleans, assignment_vec);
+            assignment_vec[index] = Some(true);
+            let true_depth = get_naive_decision_tree_depth(truth_table, num_booleans, assignment_vec);
+            assignment_vec[index] = None;
+            min_depth = min_depth.min(1 + false_depth.max(true_depth));
+        } //End for each free boolean
+
+        min_depth
+    } //End get_naive_decision_tree_depth
+
+    #[test]
+    fn measures_match_the_definitions() {
+        for num_booleans in 1..=MAX_TEST_BOOLEANS {
+            let num_rows = get_num_truth_table_rows(num_booleans);
+            let boolean_vec : Vec<u32> = (1..=num_booleans).collect();
+            for truth_table in 0..=get_truth_table_mask(num_booleans) {
+                let measures = ComplexityMeasures::new(truth_table, num_booleans);
+
+                let mut sensitivity_vec = Vec::new();
+                let mut block_sensitivity = 0;
+                let mut certificate_complexity = 0;
+                for row in 0..num_rows {
+                    let value = is_truth_table_true_in_row(truth_table, row);
+                    sensitivity_vec.push(boolean_vec.iter().filter(|boolean_index| {
+                        is_truth_table_true_in_row(truth_table, get_flipped_row(row, **boolean_index, num_booleans)) !=
+                        value
+                    }).count() as u32);
+                    block_sensitivity = block_sensitivity.max(
+                        get_naive_block_sensitivity(truth_table, num_booleans, row, &boolean_vec));
+                    certificate_complexity = certificate_complexity.max(
+                        get_naive_certificate_complexity(truth_table, num_booleans, row));
+                } //End for each row
+
+                let total_sensitivity : u32 = sensitivity_vec.iter().sum();
+                assert!((measures.average_sensitivity - total_sensitivity as f64 / num_rows as f64).abs() < TOLERANCE);
+                assert_eq!(measures.max_sensitivity, *sensitivity_vec.iter().max().unwrap());
+                assert_eq!(measures.block_sensitivity, block_sensitivity);
+                assert_eq!(measures.certificate_complexity, certificate_complexity);
+                assert_eq!(measures.decision_tree_depth,
+                           get_naive_decision_tree_depth(truth_table, num_booleans,
+                                                         &mut vec![None; num_booleans as usize]));
+
+                for boolean_index in 1..=num_booleans {
+                    let num_sensitive_rows = (0..num_rows).filter(|row| {
+                        is_truth_table_true_in_row(truth_table, *row) !=
+                        is_truth_table_true_in_row(truth_table, get_flipped_row(*row, boolean_index, num_booleans))
+                    }).count();
+                    assert!((measures.get_influence(boolean_index) - num_sensitive_rows as f64 / num_rows as f64).abs()
+                            < TOLERANCE);
+                }
+                assert!((measures.get_total_influence() - measures.average_sensitivity).abs() < TOLERANCE);
+            } //End for each truth table
+        } //End for each number of booleans
+    }
+
+    //Checks the measures of a symmetric function over 3 booleans, where every boolean has the same influence.
+    fn check_known_measures(truth_table : u32, max_sensitivity : u32, block_sensitivity : u32,
+                            certificate_complexity : u32, influence : f64, decision_tree_depth : u32)
+    {
+        let measures = ComplexityMeasures::new(truth_table, 3);
+        assert_eq!(measures.max_sensitivity, max_sensitivity);
+        assert_eq!(measures.block_sensitivity, block_sensitivity);
+        assert_eq!(measures.certificate_complexity, certificate_complexity);
+        assert_eq!(measures.decision_tree_depth, decision_tree_depth);
+        for boolean_index in 1..=3 {
+            assert!((measures.get_influence(boolean_index) - influence).abs() < TOLERANCE);
+        }
+        assert!((measures.average_sensitivity - 3.0 * influence).abs() < TOLERANCE);
+    } //End check_known_measures
+
+    #[test]
+    fn known_functions_have_known_measures() {
+        let p = get_boolean_truth_table(1, 3);
+        let q = get_boolean_truth_table(2, 3);
+        let r = get_boolean_truth_table(3, 3);
+
+        check_known_measures(p ^ q ^ r, 3, 3, 3, 1.0, 3);                    //Parity
+        check_known_measures(p & q & r, 3, 3, 3, 0.25, 3);                   //AND
+        check_known_measures(p | q | r, 3, 3, 3, 0.25, 3);                   //OR
+        check_known_measures((p & q) | (p & r) | (q & r), 2, 2, 2, 0.5, 3);  //Majority
+    }
+} //End mod tests
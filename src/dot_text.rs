@@ -0,0 +1,448 @@
+/** This file generates the text of a Graphviz DOT graph.
+    Author: Steven Fletcher
+    Created: 10/18/2026
+    Last Updated: 10/18/2026
+*/
+use crate::and_inverter_graph::*;
+use crate::binary_decision_diagram::*;
+use crate::logic::*;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+///This struct is used to generate the text of a directed Graphviz graph.  Nodes and edges can be grouped into clusters,
+///which Graphviz draws as labeled boxes.  Clusters can't be nested.
+///
+///Node names must be unique in the whole graph, so every function that adds a drawing takes a prefix for its node
+///names.
+pub struct DotGenerator {
+    graph_name : String,
+    graph_properties : Vec<String>,
+    graph_body : String,
+
+    is_in_cluster : bool,
+    num_clusters : u32
+} //End struct DotGenerator
+
+impl DotGenerator {
+    ///Constructor.  graph_name is the name of the digraph.  It's quoted, so it can contain any text.
+    pub fn new(graph_name : &str) -> DotGenerator {
+        DotGenerator {
+            graph_name : graph_name.to_string(),
+            graph_properties : Vec::new(),
+            graph_body : String::from(""),
+
+            is_in_cluster : false,
+            num_clusters : 0
+        }
+    } //End new
+
+    ///Adds a property of the whole graph, like "rankdir=LR".
+    pub fn add_graph_property(&mut self, property : &str) {
+        self.graph_properties.push(property.to_string());
+    } //End add_graph_property
+
+    ///Adds a label to the whole graph.
+    pub fn add_graph_label(&mut self, label : &str) {
+        self.add_graph_property(&format!("label=\"{}\"", escape_dot_text(label)));
+    } //End add_graph_label
+
+    ///Starts a cluster.  Every node and edge added before cluster_end is drawn inside of it.  If a cluster is already
+    ///open, it's ended first.
+    ///Parameter label is drawn at the top of the cluster.  An empty label draws nothing.
+    ///Parameter cluster_properties is added to the cluster, like "color=red".
+    pub fn cluster_create(&mut self, label : &str, cluster_properties : &str) {
+        if self.is_in_cluster {self.cluster_end();}
+
+        self.graph_body.push_str(&format!("    subgraph cluster_{} {{\n", self.num_clusters));
+        if !label.is_empty() {
+            self.graph_body.push_str(&format!("        label=\"{}\";\n", escape_dot_text(label)));
+        }
+        if !cluster_properties.is_empty() {
+            self.graph_body.push_str(&format!("        graph [{}];\n", cluster_properties));
+        }
+
+        self.is_in_cluster = true;
+        self.num_clusters = self.num_clusters + 1;
+    } //End cluster_create
+
+    ///Ends the current cluster.
+    pub fn cluster_end(&mut self) {
+        if !self.is_in_cluster {return;}
+        self.graph_body.push_str("    }\n");
+        self.is_in_cluster = false;
+    } //End cluster_end
+
+    ///Adds a node.
+    ///Parameter node_name is the unique name of the node.
+    ///Parameter label is the text drawn in the node.
+    ///Parameter node_properties is added to the node's attributes, like "shape=box".
+    pub fn add_node(&mut self, node_name : &str, label : &str, node_properties : &str) {
+        self.add_indentation();
+        self.graph_body.push_str(&format!("\"{}\" [label=\"{}\"", node_name, escape_dot_text(label)));
+        if !node_properties.is_empty() {
+            self.graph_body.push_str(", ");
+            self.graph_body.push_str(node_properties);
+        }
+        self.graph_body.push_str("];\n");
+    } //End add_node
+
+    ///Adds an edge from one node to another.
+    ///Parameter edge_properties is added to the edge's attributes, like "style=dashed".
+    pub fn add_edge(&mut self, from_node_name : &str, to_node_name : &str, edge_properties : &str) {
+        self.add_indentation();
+        self.graph_body.push_str(&format!("\"{}\" -> \"{}\"", from_node_name, to_node_name));
+        if !edge_properties.is_empty() {
+            self.graph_body.push_str(&format!(" [{}]", edge_properties));
+        }
+        self.graph_body.push_str(";\n");
+    } //End add_edge
+
+    //PRIVATE
+    fn add_indentation(&mut self) {
+        self.graph_body.push_str(if self.is_in_cluster {"        "} else {"    "});
+    } //End add_indentation
+} //End impl DotGenerator
+
+///Implementation of fmt::Display for DotGenerator.  An open cluster is closed in the output.
+impl fmt::Display for DotGenerator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = format!("digraph \"{}\" {{\n", escape_dot_text(&self.graph_name));
+        for property in &self.graph_properties {
+            text.push_str(&format!("    {};\n", property));
+        }
+        text.push_str(&self.graph_body);
+        if self.is_in_cluster {text.push_str("    }\n");}
+        text.push_str("}\n");
+
+        write!(f, "{}", text)
+    } //End fmt
+} //End impl fmt::Display for DotGenerator
+
+///This struct stores the options for drawing formulas and decision diagrams.
+///show_labels: label the clusters with the formulas they draw and label the decision diagram edges with 0 and 1
+///share_nodes: draw identical subformulas (and decision diagram terminals) once instead of once per parent
+///highlight_minimum: draw the minimum formula of each truth table in bold red
+#[derive(Clone, Copy)]
+pub struct DotOptions {
+    pub show_labels : bool,
+    pub share_nodes : bool,
+    pub highlight_minimum : bool
+} //End struct DotOptions
+
+impl DotOptions {
+    ///Gets the default options: labels and highlighting, but no sharing.
+    pub fn new() -> DotOptions {
+        DotOptions {
+            show_labels : true,
+            share_nodes : false,
+            highlight_minimum : true
+        }
+    } //End new
+
+    ///Parses a comma separated list of the options that are turned on, like "labels,shared,highlight".  Options that
+    ///aren't listed are turned off.  "none" turns every option off.
+    ///Return value: the options, or None if an option isn't recognized
+    pub fn parse(text : &str) -> Option<DotOptions> {
+        let mut options = DotOptions {show_labels : false, share_nodes : false, highlight_minimum : false};
+        if text == DOT_OPTION_NONE {return Some(options);}
+
+        for option_text in text.split(',') {
+            match option_text.trim() {
+                DOT_OPTION_LABELS => options.show_labels = true,
+                DOT_OPTION_SHARED => options.share_nodes = true,
+                DOT_OPTION_HIGHLIGHT => options.highlight_minimum = true,
+                _ => return None
+            };
+        } //End for each option
+
+        Some(options)
+    } //End parse
+} //End impl DotOptions
+
+impl Default for DotOptions {
+    fn default() -> Self {Self::new()}
+} //End impl Default for DotOptions
+
+///The node properties used for highlighted drawings.
+pub const DOT_HIGHLIGHT_PROPERTIES : &str = "color=red, fontcolor=red, penwidth=2";
+///The node properties used for decision diagram terminals.
+pub const DOT_TERMINAL_PROPERTIES : &str = "shape=box";
+
+///Joins two lists of DOT attributes with a comma.  Either list can be empty.
+pub fn join_dot_properties(properties1 : &str, properties2 : &str) -> String {
+    if properties1.is_empty() {properties2.to_string()}
+    else if properties2.is_empty() {properties1.to_string()}
+    else {format!("{}, {}", properties1, properties2)}
+} //End join_dot_properties
+
+///Escapes quotes and backslashes so text can be put inside of a quoted DOT string.  Newlines become DOT line breaks.
+pub fn escape_dot_text(text : &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+} //End escape_dot_text
+
+///Adds a drawing of a formula's syntax tree to a DOT graph.
+///node_name_prefix is put in front of every node name, so several formulas can be drawn in one graph
+///share_nodes determines whether identical subformulas are drawn once, which turns the tree into a DAG
+///properties is added to every node and edge, like DOT_HIGHLIGHT_PROPERTIES
+///Return value: the name of the root node
+pub fn add_dot_for_formula(dot_generator : &mut DotGenerator, formula : &SimpleLogicNode, node_name_prefix : &str,
+                           boolean_name_list : &Vec<String>, share_nodes : bool, properties : &str) -> String
+{
+    let mut shared_node_names : Option<HashMap<String, String>> = if share_nodes {Some(HashMap::new())} else {None};
+    let mut num_nodes : u32 = 0;
+    add_dot_for_formula_helper(dot_generator, formula, node_name_prefix, boolean_name_list, properties,
+                               &mut shared_node_names, &mut num_nodes)
+} //End add_dot_for_formula
+
+///Adds a drawing of BDDs to a DOT graph.  Low edges are dashed and high edges are solid.  Internal nodes are always
+///shared, since that's what makes it a BDD, but the terminals are only drawn once if options.share_nodes is set.
+///node_name_prefix is put in front of every node name, so several drawings can be put in one graph
+///boolean_name_list is a list of the names of the variables
+pub fn add_dot_for_bdd(dot_generator : &mut DotGenerator, bdd_manager : &BddManager, root_list : &[BddNodeId],
+                       node_name_prefix : &str, boolean_name_list : &Vec<String>, options : &DotOptions)
+{
+    let (low_properties, high_properties) =
+        if options.show_labels {("style=dashed, label=\"0\"", "label=\"1\"")}
+        else {("style=dashed", "")};
+
+    //Draw each node once.
+    let mut visited = HashSet::new();
+    let mut stack : Vec<BddNodeId> = root_list.to_vec();
+    let mut num_terminal_copies : u32 = 0;
+    while let Some(current) = stack.pop() {
+        if !visited.insert(current) {continue;}
+        let (variable, (low, high)) =
+            match (bdd_manager.get_node_variable(current), bdd_manager.get_node_children(current)) {
+                (Some(variable), Some(children)) => (variable, children),
+                _ => {
+                    //A terminal.  It's only drawn here if it's shared or a root, since it has no parent then.
+                    if options.share_nodes || root_list.contains(&current) {
+                        dot_generator.add_node(&format!("{}{}", node_name_prefix, current), &current.to_string(),
+                                               DOT_TERMINAL_PROPERTIES);
+                    }
+                    continue;
+                }
+            };
+
+        let node_name = format!("{}{}", node_name_prefix, current);
+        dot_generator.add_node(&node_name, &boolean_name_list[(variable - 1) as usize], "");
+        for (child, edge_properties) in [(low, low_properties), (high, high_properties)] {
+            let is_terminal = child == BDD_FALSE || child == BDD_TRUE;
+            if is_terminal && !options.share_nodes {
+                //Draw a copy of the terminal for this edge.
+                let copy_name = format!("{}t{}", node_name_prefix, num_terminal_copies);
+                num_terminal_copies = num_terminal_copies + 1;
+                dot_generator.add_node(&copy_name, &child.to_string(), DOT_TERMINAL_PROPERTIES);
+                dot_generator.add_edge(&node_name, &copy_name, edge_properties);
+            }
+            else {
+                dot_generator.add_edge(&node_name, &format!("{}{}", node_name_prefix, child), edge_properties);
+                stack.push(child);
+            }
+        } //End for each child
+    } //End while there are nodes to visit
+} //End add_dot_for_bdd
+
+///Adds a drawing of an AIG's outputs to a DOT graph.  Negated edges are dashed with a hollow arrowhead.  Gates are
+///always shared, but the constant is only drawn once if options.share_nodes is set.
+///node_name_prefix is put in front of every node name, so several drawings can be put in one graph
+///boolean_name_list is a list of the names of the inputs
+pub fn add_dot_for_aig(dot_generator : &mut DotGenerator, aig : &AndInverterGraph, node_name_prefix : &str,
+                       boolean_name_list : &Vec<String>, options : &DotOptions)
+{
+    let mut is_drawn_vec = vec![false; (aig.get_max_variable_index() + 1) as usize];
+    let mut num_constant_copies = 0;
+
+    //Draws a node if it hasn't been drawn yet, and gets its name.
+    let mut draw_node = |dot_generator : &mut DotGenerator, variable : u32, stack : &mut Vec<u32>| -> String {
+        if variable == 0 && !options.share_nodes {
+            let copy_name = format!("{}c{}", node_name_prefix, num_constant_copies);
+            num_constant_copies = num_constant_copies + 1;
+            dot_generator.add_node(&copy_name, "0", DOT_TERMINAL_PROPERTIES);
+            return copy_name;
+        }
+
+        let node_name = format!("{}{}", node_name_prefix, variable);
+        if !is_drawn_vec[variable as usize] {
+            is_drawn_vec[variable as usize] = true;
+            if variable == 0 {dot_generator.add_node(&node_name, "0", DOT_TERMINAL_PROPERTIES);}
+            else if variable <= aig.get_num_inputs() {
+                dot_generator.add_node(&node_name, &boolean_name_list[(variable - 1) as usize], "shape=plaintext");
+            }
+            else {
+                dot_generator.add_node(&node_name, CONJUNCTION_SYMBOL, "shape=circle");
+                stack.push(variable);
+            }
+        }
+        node_name
+    };
+
+    let mut stack : Vec<u32> = Vec::new();
+    for (output_index, output) in aig.get_outputs().iter().enumerate() {
+        let output_name = format!("{}o{}", node_name_prefix, output_index);
+        let label = if options.show_labels {format!("Output {}", output_index)} else {String::new()};
+        dot_generator.add_node(&output_name, &label, "shape=invtriangle");
+        let target_name = draw_node(dot_generator, output / 2, &mut stack);
+        dot_generator.add_edge(&output_name, &target_name, get_aig_edge_properties(*output));
+    } //End for each output
+
+    while let Some(variable) = stack.pop() {
+        let (left, right) = aig.get_and_gate_inputs(2 * variable).expect("only AND gates are pushed");
+        let node_name = format!("{}{}", node_name_prefix, variable);
+        for input in [left, right] {
+            let input_name = draw_node(dot_generator, input / 2, &mut stack);
+            dot_generator.add_edge(&node_name, &input_name, get_aig_edge_properties(input));
+        }
+    } //End while there are gates to draw
+} //End add_dot_for_aig
+
+//PRIVATE//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+const DOT_OPTION_LABELS : &str = "labels";
+const DOT_OPTION_SHARED : &str = "shared";
+const DOT_OPTION_HIGHLIGHT : &str = "highlight";
+const DOT_OPTION_NONE : &str = "none";
+
+//Does the work for add_dot_for_formula.
+//shared_node_names maps the text of each subformula that's already drawn to its node name.  It's None if nodes aren't
+//shared.
+fn add_dot_for_formula_helper(dot_generator : &mut DotGenerator, formula : &SimpleLogicNode, node_name_prefix : &str,
+                              boolean_name_list : &Vec<String>, properties : &str,
+                              shared_node_names : &mut Option<HashMap<String, String>>, num_nodes : &mut u32) -> String
+{
+    //Check for a node that was already drawn.
+    let formula_text = if shared_node_names.is_some() {formula.get_as_text(boolean_name_list)} else {String::new()};
+    if let Some(node_name) = shared_node_names.as_ref().and_then(|node_names| node_names.get(&formula_text)) {
+        return node_name.clone();
+    }
+
+    let node_name = format!("{}{}", node_name_prefix, num_nodes);
+    *num_nodes = *num_nodes + 1;
+
+    match formula {
+        SimpleLogicNode::False | SimpleLogicNode::True | SimpleLogicNode::Literal(_) => {
+            let label = formula.get_as_text(boolean_name_list);
+            dot_generator.add_node(&node_name, &label, &join_dot_properties("shape=plaintext", properties));
+        },
+        SimpleLogicNode::Conjunction(operands) | SimpleLogicNode::Disjunction(operands) |
+        SimpleLogicNode::Equivalence(operands) | SimpleLogicNode::ExclusiveDisjunction(operands) => {
+            let symbol = formula.get_operator_symbol();
+            dot_generator.add_node(&node_name, symbol, &join_dot_properties("shape=circle", properties));
+            for operand in operands {
+                let operand_name = add_dot_for_formula_helper(dot_generator, operand, node_name_prefix,
+                                                              boolean_name_list, properties, shared_node_names,
+                                                              num_nodes);
+                dot_generator.add_edge(&node_name, &operand_name, properties);
+            }
+        }
+    } //End match formula
+
+    if let Some(node_names) = shared_node_names.as_mut() {node_names.insert(formula_text, node_name.clone());}
+    node_name
+} //End add_dot_for_formula_helper
+
+fn get_aig_edge_properties(literal : AigLiteral) -> &'static str {
+    if literal & 1 == 1 {"style=dashed, arrowhead=odot"} else {""}
+} //End get_aig_edge_properties
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //Gets the node lines and the edge lines of a DOT graph.
+    //Return value: (the node lines, the edge lines)
+    fn get_node_and_edge_lines(dot_generator : &DotGenerator) -> (Vec<String>, Vec<String>) {
+        let text = dot_generator.to_string();
+        let node_line_vec = text.lines().filter(|line| line.contains("[label=") && !line.contains(" -> "))
+            .map(str::to_string).collect();
+        let edge_line_vec = text.lines().filter(|line| line.contains(" -> ")).map(str::to_string).collect();
+        (node_line_vec, edge_line_vec)
+    } //End get_node_and_edge_lines
+
+    //Gets (p1 & p2) | (p1 & p2) | p1, which repeats a subformula and a literal.
+    fn get_repeated_formula() -> SimpleLogicNode {
+        let conjunction = SimpleLogicNode::Conjunction(vec![SimpleLogicNode::Literal(1), SimpleLogicNode::Literal(2)]);
+        SimpleLogicNode::Disjunction(vec![conjunction.clone(), conjunction, SimpleLogicNode::Literal(1)])
+    } //End get_repeated_formula
+
+    fn get_boolean_name_list() -> Vec<String> {vec!["p".to_string(), "q".to_string()]}
+
+    #[test]
+    fn text_is_escaped() {
+        assert_eq!(escape_dot_text("a \"b\" \\c\nd"), "a \\\"b\\\" \\\\c\\nd");
+
+        let mut dot_generator = DotGenerator::new("Table \"1\"");
+        dot_generator.add_graph_label("x\\y");
+        dot_generator.cluster_create("first\nsecond", "");
+        dot_generator.add_node("n", "\"p\"", "");
+        let text = dot_generator.to_string();
+        assert!(text.starts_with("digraph \"Table \\\"1\\\"\" {\n"));
+        assert!(text.contains("    label=\"x\\\\y\";\n"));
+        assert!(text.contains("        label=\"first\\nsecond\";\n"));
+        assert!(text.contains("        \"n\" [label=\"\\\"p\\\"\"];\n"));
+        assert!(text.ends_with("    }\n}\n"));  //The open cluster is closed
+    }
+
+    #[test]
+    fn shared_formula_nodes_are_drawn_once() {
+        let formula = get_repeated_formula();
+
+        let mut dot_generator = DotGenerator::new("tree");
+        let root_name = add_dot_for_formula(&mut dot_generator, &formula, "f_", &get_boolean_name_list(), false, "");
+        assert_eq!(root_name, "f_0");
+        let (node_line_vec, edge_line_vec) = get_node_and_edge_lines(&dot_generator);
+        assert_eq!((node_line_vec.len(), edge_line_vec.len()), (8, 7));
+        assert!(node_line_vec.iter().all(|line| line.contains("\"f_")));
+
+        let mut dot_generator = DotGenerator::new("dag");
+        add_dot_for_formula(&mut dot_generator, &formula, "f_", &get_boolean_name_list(), true, "");
+        let (node_line_vec, edge_line_vec) = get_node_and_edge_lines(&dot_generator);
+        assert_eq!((node_line_vec.len(), edge_line_vec.len()), (4, 5));
+        assert_eq!(edge_line_vec.iter().filter(|line| line.contains("\"f_0\" -> \"f_1\"")).count(), 2);
+    }
+
+    #[test]
+    fn highlighting_covers_every_node_and_edge() {
+        for share_nodes in [false, true] {
+            let mut dot_generator = DotGenerator::new("highlighted");
+            add_dot_for_formula(&mut dot_generator, &get_repeated_formula(), "", &get_boolean_name_list(),
+                                share_nodes, DOT_HIGHLIGHT_PROPERTIES);
+            let (node_line_vec, edge_line_vec) = get_node_and_edge_lines(&dot_generator);
+            assert!(node_line_vec.iter().chain(edge_line_vec.iter())
+                .all(|line| line.contains(DOT_HIGHLIGHT_PROPERTIES)));
+
+            let mut dot_generator = DotGenerator::new("plain");
+            add_dot_for_formula(&mut dot_generator, &get_repeated_formula(), "", &get_boolean_name_list(),
+                                share_nodes, "");
+            assert!(!dot_generator.to_string().contains(DOT_HIGHLIGHT_PROPERTIES));
+        } //End for each sharing option
+    }
+
+    #[test]
+    fn shared_terminals_are_drawn_once() {
+        let mut options = DotOptions::new();
+        let mut bdd_manager = BddManager::new(2);
+        let bdd = bdd_manager.build_from_truth_table(0b1000, 2);  //p & q
+        let mut aig = AndInverterGraph::new(2);
+        let output = aig.create_and(aig.get_input_literal(1), AndInverterGraph::negate(aig.get_input_literal(2)));
+        aig.add_output(output);
+        aig.add_output(AIG_FALSE);
+        aig.add_output(AIG_TRUE);
+
+        //(share_nodes, BDD nodes, AIG nodes).  The AIG has 3 outputs, a gate and 2 inputs, plus its constants.
+        for (share_nodes, num_bdd_nodes, num_aig_nodes) in [(false, 5, 8), (true, 4, 7)] {
+            options.share_nodes = share_nodes;
+
+            let mut dot_generator = DotGenerator::new("bdd");
+            add_dot_for_bdd(&mut dot_generator, &bdd_manager, &[bdd], "bdd", &get_boolean_name_list(), &options);
+            let (node_line_vec, edge_line_vec) = get_node_and_edge_lines(&dot_generator);
+            assert_eq!((node_line_vec.len(), edge_line_vec.len()), (num_bdd_nodes, 4));
+            assert_eq!(edge_line_vec.iter().filter(|line| line.contains("style=dashed")).count(), 2);
+
+            let mut dot_generator = DotGenerator::new("aig");
+            add_dot_for_aig(&mut dot_generator, &aig, "aig", &get_boolean_name_list(), &options);
+            let (node_line_vec, edge_line_vec) = get_node_and_edge_lines(&dot_generator);
+            assert_eq!((node_line_vec.len(), edge_line_vec.len()), (num_aig_nodes, 5));
+            assert_eq!(edge_line_vec.iter().filter(|line| line.contains("arrowhead=odot")).count(), 2);
+        } //End for each sharing option
+    }
+} //End mod tests
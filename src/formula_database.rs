@@ -0,0 +1,401 @@
+/** This file stores the minimum formula of every truth table in a compact binary database, so precomputed results can
+    be looked up without precomputing them again.
+    Author: Steven Fletcher
+    Created: 10/18/2026
+    Last Updated: 10/18/2026
+*/
+use crate::formula_precomputer::*;
+use crate::logic::*;
+use crate::truth_table_size_5::*;
+use std::convert::TryInto;
+use std::path::Path;
+
+///The database has an offset for every truth table, so it's limited to 2^16 truth tables.
+pub const MAX_BOOLEANS_IN_FORMULA_DATABASE : u32 = 4;
+
+///This struct is a database with one record per truth table: the minimum formula, its cost and the number of formulas
+///that were generated with that truth table.
+///
+///The database is kept in the same format in memory and on disk, so loading it is just reading the file, and records
+///are only decoded when they're looked up.  All numbers are little endian.
+///header: the bytes "RLTDB", the format version, the number of booleans and the cost model
+///index: the offset of each truth table's record from the start of the database, as a u32
+///records: the cost as a u32, the number of formulas as a u64 and then the minimum formula in prefix order
+///In prefix order, a constant is one tag byte, a literal is its tag and then a byte with the boolean index in the low
+///7 bits and the negation in the high bit, and a conjunction, disjunction, equivalence or exclusive or is its tag,
+///its number of operands as a u16 and then its operands.
+pub struct FormulaDatabase {
+    num_booleans : u32,
+    cost_model : FormulaCostModel,
+    byte_vec : Vec<u8>
+} //End struct FormulaDatabase
+
+impl FormulaDatabase {
+    ///Builds a database from the buckets of every truth table over num_booleans booleans.
+    ///cost_model is the cost model that chose the minimum formulas
+    ///Return value: the database, or an error message if there are too many booleans or the wrong number of buckets
+    pub fn build(formula_buckets : &Vec<LogicFormulaBucket>, num_booleans : u32, cost_model : FormulaCostModel)
+        -> Result<FormulaDatabase, String>
+    {
+        if num_booleans == 0 || num_booleans > MAX_BOOLEANS_IN_FORMULA_DATABASE {
+            return Err(format!("The formula database needs 1 to {} booleans, not {}",
+                               MAX_BOOLEANS_IN_FORMULA_DATABASE, num_booleans));
+        }
+        let num_truth_tables = get_truth_table_mask(num_booleans) as usize + 1;
+        if formula_buckets.len() != num_truth_tables {
+            return Err(format!("{} booleans need {} buckets, not {}", num_booleans, num_truth_tables,
+                               formula_buckets.len()));
+        }
+
+        //Header
+        let mut byte_vec = Vec::new();
+        byte_vec.extend_from_slice(FORMULA_DATABASE_MAGIC);
+        byte_vec.push(FORMULA_DATABASE_VERSION);
+        byte_vec.push(num_booleans as u8);
+        byte_vec.push(get_cost_model_code(cost_model));
+
+        //Leave room for the index, then add each record and fill in its offset.
+        let index_start = byte_vec.len();
+        byte_vec.resize(index_start + 4 * num_truth_tables, 0);
+        for (truth_table, formula_bucket) in formula_buckets.iter().enumerate() {
+            let offset = byte_vec.len() as u32;
+            byte_vec[index_start + 4 * truth_table..index_start + 4 * truth_table + 4]
+                .copy_from_slice(&offset.to_le_bytes());
+
+            byte_vec.extend_from_slice(&formula_bucket.get_minimum_cost().unwrap_or(0).to_le_bytes());
+            byte_vec.extend_from_slice(&formula_bucket.get_num_formulas().to_le_bytes());
+            encode_optional_formula(&mut byte_vec, formula_bucket.get_minimum_formula().as_ref());
+        } //End for each truth table
+
+        Ok(FormulaDatabase {
+            num_booleans : num_booleans,
+            cost_model : cost_model,
+            byte_vec : byte_vec
+        })
+    } //End build
+
+    ///Loads a database from its bytes.  Only the header and the size of the index are checked here.  Records are
+    ///checked when they're looked up.
+    ///Return value: the database, or an error message if the bytes aren't a database
+    pub fn from_bytes(byte_vec : Vec<u8>) -> Result<FormulaDatabase, String> {
+        let header_length = FORMULA_DATABASE_MAGIC.len() + 3;
+        if byte_vec.len() < header_length || &byte_vec[..FORMULA_DATABASE_MAGIC.len()] != FORMULA_DATABASE_MAGIC {
+            return Err("Not a formula database".to_string());
+        }
+
+        let version = byte_vec[FORMULA_DATABASE_MAGIC.len()];
+        if version != FORMULA_DATABASE_VERSION {
+            return Err(format!("Unsupported formula database version {}", version));
+        }
+
+        let num_booleans = byte_vec[FORMULA_DATABASE_MAGIC.len() + 1] as u32;
+        if num_booleans == 0 || num_booleans > MAX_BOOLEANS_IN_FORMULA_DATABASE {
+            return Err(format!("Invalid number of booleans {} in formula database", num_booleans));
+        }
+
+        let cost_model =
+            match get_cost_model_from_code(byte_vec[FORMULA_DATABASE_MAGIC.len() + 2]) {
+                Some(cost_model) => cost_model,
+                None => return Err("Invalid cost model in formula database".to_string())
+            };
+
+        let num_truth_tables = get_truth_table_mask(num_booleans) as usize + 1;
+        if byte_vec.len() < header_length + 4 * num_truth_tables {
+            return Err("The formula database is truncated".to_string());
+        }
+
+        Ok(FormulaDatabase {
+            num_booleans : num_booleans,
+            cost_model : cost_model,
+            byte_vec : byte_vec
+        })
+    } //End from_bytes
+
+    ///Loads a database from a file.
+    ///Return value: the database, or an error message if the file can't be read or isn't a database
+    pub fn read_from_file(filepath : &Path) -> Result<FormulaDatabase, String> {
+        match std::fs::read(filepath) {
+            Ok(byte_vec) => FormulaDatabase::from_bytes(byte_vec),
+            Err(message) => Err(format!("Cannot read {}: {}", filepath.display(), message))
+        }
+    } //End read_from_file
+
+    pub fn get_num_booleans(&self) -> u32 {self.num_booleans}
+    pub fn get_cost_model(&self) -> FormulaCostModel {self.cost_model}
+
+    ///Gets the bytes of the database, which is also its file format.
+    pub fn get_bytes(&self) -> &Vec<u8> {&self.byte_vec}
+
+    ///Looks up the record of a truth table in the internal format.
+    ///Return value: the record, or an error message if the truth table is out of range or the record is corrupt
+    pub fn get_record(&self, truth_table : u32) -> Result<FormulaDatabaseRecord, String> {
+        if truth_table & !get_truth_table_mask(self.num_booleans) != 0 {
+            return Err(format!("Truth table {} is too large for {} booleans", truth_table, self.num_booleans));
+        }
+
+        let index_position = FORMULA_DATABASE_MAGIC.len() + 3 + 4 * truth_table as usize;
+        let mut reader = FormulaByteReader::new(&self.byte_vec, index_position);
+        reader.position = reader.read_u32()? as usize;
+
+        let minimum_cost = reader.read_u32()?;
+        let num_formulas = reader.read_u64()?;
+        let minimum_formula = reader.read_formula(self.num_booleans)?;
+
+        Ok(FormulaDatabaseRecord {
+            truth_table : truth_table,
+            minimum_cost : minimum_formula.as_ref().map(|_| minimum_cost),
+            minimum_formula : minimum_formula,
+            num_formulas : num_formulas
+        })
+    } //End get_record
+
+    ///Looks up the record of the truth table of a formula over the booleans in this database.
+    pub fn look_up_formula(&self, formula : &SimpleLogicNode) -> Result<FormulaDatabaseRecord, String> {
+        let truth_table = TruthTableSize5Computer::new(self.num_booleans).compute_truth_table(formula);
+        self.get_record(truth_table & get_truth_table_mask(self.num_booleans))
+    } //End look_up_formula
+} //End impl FormulaDatabase
+
+///This struct is the record of one truth table in a FormulaDatabase.
+pub struct FormulaDatabaseRecord {
+    truth_table : u32,                         //In the internal format
+    minimum_formula : Option<SimpleLogicNode>,
+    minimum_cost : Option<u32>,
+    num_formulas : u64
+} //End struct FormulaDatabaseRecord
+
+impl FormulaDatabaseRecord {
+    pub fn get_truth_table(&self) -> u32 {self.truth_table}
+    pub fn get_minimum_formula(&self) -> Option<&SimpleLogicNode> {self.minimum_formula.as_ref()}
+    pub fn get_minimum_cost(&self) -> Option<u32> {self.minimum_cost}
+    pub fn get_num_formulas(&self) -> u64 {self.num_formulas}
+} //End impl FormulaDatabaseRecord
+
+///Writes a formula that might be missing, like the minimum formula of an empty bucket.  FormulaByteReader::read_formula
+///reads it back.
+pub fn encode_optional_formula(byte_vec : &mut Vec<u8>, formula : Option<&SimpleLogicNode>) {
+    match formula {
+        Some(formula) => encode_formula(byte_vec, formula),
+        None => byte_vec.push(FORMULA_TAG_NONE)
+    };
+} //End encode_optional_formula
+
+///Writes a formula in prefix order, the format used by FormulaDatabase.  FormulaByteReader::read_formula reads it back.
+pub fn encode_formula(byte_vec : &mut Vec<u8>, formula : &SimpleLogicNode) {
+    match formula {
+        SimpleLogicNode::False => byte_vec.push(FORMULA_TAG_FALSE),
+        SimpleLogicNode::True => byte_vec.push(FORMULA_TAG_TRUE),
+        SimpleLogicNode::Literal(literal) => {
+            byte_vec.push(FORMULA_TAG_LITERAL);
+            let negation_bit = if is_positive_literal(*literal) {0} else {LITERAL_NEGATION_BIT};
+            byte_vec.push(get_variable_index(*literal) as u8 | negation_bit);
+        },
+        SimpleLogicNode::Conjunction(operands) | SimpleLogicNode::Disjunction(operands) |
+        SimpleLogicNode::Equivalence(operands) | SimpleLogicNode::ExclusiveDisjunction(operands) => {
+            let tag =
+                match formula {
+                    SimpleLogicNode::Conjunction(_) => FORMULA_TAG_CONJUNCTION,
+                    SimpleLogicNode::Disjunction(_) => FORMULA_TAG_DISJUNCTION,
+                    SimpleLogicNode::Equivalence(_) => FORMULA_TAG_EQUIVALENCE,
+                    _ => FORMULA_TAG_EXCLUSIVE_DISJUNCTION
+                };
+            byte_vec.push(tag);
+            byte_vec.extend_from_slice(&(operands.len() as u16).to_le_bytes());
+            for operand in operands {
+                encode_formula(byte_vec, operand);
+            }
+        }
+    } //End match formula
+} //End encode_formula
+
+///This struct reads little endian numbers and formulas written by encode_formula from bytes.
+pub struct FormulaByteReader<'a> {
+    byte_slice : &'a [u8],
+    position : usize
+} //End struct FormulaByteReader
+
+impl FormulaByteReader<'_> {
+    ///Creates a reader that starts reading at a position.
+    pub fn new(byte_slice : &[u8], position : usize) -> FormulaByteReader<'_> {
+        FormulaByteReader {
+            byte_slice : byte_slice,
+            position : position
+        }
+    } //End new
+
+    ///Reads some bytes.
+    ///Return value: the bytes, or an error message if there aren't enough bytes left
+    pub fn read_bytes(&mut self, num_bytes : usize) -> Result<&[u8], String> {
+        if self.position + num_bytes > self.byte_slice.len() {
+            return Err("Unexpected end of formula data".to_string());
+        }
+        let bytes = &self.byte_slice[self.position..self.position + num_bytes];
+        self.position = self.position + num_bytes;
+        Ok(bytes)
+    } //End read_bytes
+
+    pub fn read_u8(&mut self) -> Result<u8, String> {Ok(self.read_bytes(1)?[0])}
+
+    pub fn read_u16(&mut self) -> Result<u16, String> {
+        Ok(u16::from_le_bytes(self.read_bytes(2)?.try_into().unwrap()))
+    } //End read_u16
+
+    pub fn read_u32(&mut self) -> Result<u32, String> {
+        Ok(u32::from_le_bytes(self.read_bytes(4)?.try_into().unwrap()))
+    } //End read_u32
+
+    pub fn read_u64(&mut self) -> Result<u64, String> {
+        Ok(u64::from_le_bytes(self.read_bytes(8)?.try_into().unwrap()))
+    } //End read_u64
+
+    ///Reads a formula in prefix order.  See encode_formula.
+    ///num_booleans is the number of booleans the formula can use
+    ///Return value: the formula, None if the bucket was empty, or an error message if the formula is corrupt
+    pub fn read_formula(&mut self, num_booleans : u32) -> Result<Option<SimpleLogicNode>, String> {
+        let tag = self.read_u8()?;
+        let formula =
+            match tag {
+                FORMULA_TAG_NONE => return Ok(None),
+                FORMULA_TAG_FALSE => SimpleLogicNode::False,
+                FORMULA_TAG_TRUE => SimpleLogicNode::True,
+                FORMULA_TAG_LITERAL => {
+                    let literal_byte = self.read_u8()?;
+                    let boolean_index = (literal_byte & !LITERAL_NEGATION_BIT) as u32;
+                    if boolean_index == 0 || boolean_index > num_booleans {
+                        return Err(format!("Invalid boolean {} in formula data", boolean_index));
+                    }
+                    let negativity_flag = if literal_byte & LITERAL_NEGATION_BIT != 0 {NEGATIVITY_FLAG} else {0};
+                    SimpleLogicNode::Literal(boolean_index | negativity_flag)
+                },
+                FORMULA_TAG_CONJUNCTION | FORMULA_TAG_DISJUNCTION | FORMULA_TAG_EQUIVALENCE |
+                FORMULA_TAG_EXCLUSIVE_DISJUNCTION => {
+                    let num_operands = self.read_u16()? as usize;
+                    let mut operands = Vec::with_capacity(num_operands);
+                    for _i in 0..num_operands {
+                        match self.read_formula(num_booleans)? {
+                            Some(operand) => operands.push(operand),
+                            None => return Err("Missing operand in formula data".to_string())
+                        };
+                    } //End for each operand
+
+                    match tag {
+                        FORMULA_TAG_CONJUNCTION => SimpleLogicNode::Conjunction(operands),
+                        FORMULA_TAG_DISJUNCTION => SimpleLogicNode::Disjunction(operands),
+                        FORMULA_TAG_EQUIVALENCE => SimpleLogicNode::Equivalence(operands),
+                        _ => SimpleLogicNode::ExclusiveDisjunction(operands)
+                    }
+                },
+                _ => return Err(format!("Invalid formula tag {} in formula data", tag))
+            };
+
+        Ok(Some(formula))
+    } //End read_formula
+} //End impl FormulaByteReader
+
+///Gets the byte that stands for a cost model in binary files.
+pub fn get_cost_model_code(cost_model : FormulaCostModel) -> u8 {
+    match cost_model {
+        FormulaCostModel::BinaryOperators => 0,
+        FormulaCostModel::AigAndGates => 1,
+        FormulaCostModel::TwoInputOperators => 2
+    }
+} //End get_cost_model_code
+
+///Gets the cost model that a byte of a binary file stands for, or None if it isn't a cost model.
+pub fn get_cost_model_from_code(code : u8) -> Option<FormulaCostModel> {
+    match code {
+        0 => Some(FormulaCostModel::BinaryOperators),
+        1 => Some(FormulaCostModel::AigAndGates),
+        2 => Some(FormulaCostModel::TwoInputOperators),
+        _ => None
+    }
+} //End get_cost_model_from_code
+
+//PRIVATE//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+const FORMULA_DATABASE_MAGIC : &[u8] = b"RLTDB";
+const FORMULA_DATABASE_VERSION : u8 = 1;
+
+const FORMULA_TAG_FALSE : u8 = 0;
+const FORMULA_TAG_TRUE : u8 = 1;
+const FORMULA_TAG_LITERAL : u8 = 2;
+const FORMULA_TAG_CONJUNCTION : u8 = 3;
+const FORMULA_TAG_DISJUNCTION : u8 = 4;
+const FORMULA_TAG_NONE : u8 = 5;  //The bucket was empty
+const FORMULA_TAG_EQUIVALENCE : u8 = 6;
+const FORMULA_TAG_EXCLUSIVE_DISJUNCTION : u8 = 7;
+
+const LITERAL_NEGATION_BIT : u8 = 0x80;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    //Precomputes the buckets of every truth table over 3 booleans.
+    fn get_formula_buckets(cost_model : FormulaCostModel) -> Vec<LogicFormulaBucket> {
+        let precompute_settings = PrecomputeSettings {
+            cost_model : cost_model,
+            bucket_mode : BucketMode::Stream(DEFAULT_MAX_FORMULAS_PER_BUCKET),
+            num_threads : 2,
+            pruning_settings : PruningSettings::new(),
+            family_settings : FormulaFamilySettings::new(),
+            max_shape_operators : 0,
+            verify_duality : false,
+            progress_interval : Duration::ZERO,
+            memory_budget : None
+        };
+        generate_truth_tables_with_up_to_n_variables(3, &precompute_settings, None).unwrap().0
+    } //End get_formula_buckets
+
+    #[test]
+    fn database_file_round_trips() {
+        let filepath = std::env::temp_dir().join(format!("rust_logic_tools_database_{}.db", std::process::id()));
+        for cost_model in [FormulaCostModel::BinaryOperators, FormulaCostModel::TwoInputOperators,
+                           FormulaCostModel::AigAndGates] {
+            let formula_buckets = get_formula_buckets(cost_model);
+            let built_database = FormulaDatabase::build(&formula_buckets, 3, cost_model).unwrap();
+            std::fs::write(&filepath, built_database.get_bytes()).unwrap();
+            let formula_database = FormulaDatabase::read_from_file(&filepath).unwrap();
+
+            assert_eq!(formula_database.get_num_booleans(), 3);
+            assert_eq!(formula_database.get_cost_model(), cost_model);
+            for (truth_table, formula_bucket) in formula_buckets.iter().enumerate() {
+                let record = formula_database.get_record(truth_table as u32).unwrap();
+                assert_eq!(record.get_truth_table(), truth_table as u32);
+                assert_eq!(record.get_num_formulas(), formula_bucket.get_num_formulas());
+                assert_eq!(record.get_minimum_cost(), formula_bucket.get_minimum_cost());
+                assert!(record.get_minimum_formula() == formula_bucket.get_minimum_formula().as_ref());
+
+                //Looking up the minimum formula finds its own record.
+                let minimum_formula = record.get_minimum_formula().unwrap();
+                assert_eq!(formula_database.look_up_formula(minimum_formula).unwrap().get_truth_table(),
+                           truth_table as u32);
+            } //End for each truth table
+            assert!(formula_database.get_record(256).is_err());
+        } //End for each cost model
+
+        std::fs::remove_file(&filepath).unwrap();
+    } //End database_file_round_trips
+
+    #[test]
+    fn rejects_malformed_databases() {
+        let formula_buckets = get_formula_buckets(FormulaCostModel::BinaryOperators);
+        let byte_vec = FormulaDatabase::build(&formula_buckets, 3, FormulaCostModel::BinaryOperators).unwrap()
+                                       .get_bytes().clone();
+
+        let mut wrong_magic_vec = byte_vec.clone();
+        wrong_magic_vec[0] = b'X';
+        assert!(FormulaDatabase::from_bytes(wrong_magic_vec).is_err());
+        let mut wrong_version_vec = byte_vec.clone();
+        wrong_version_vec[FORMULA_DATABASE_MAGIC.len()] = FORMULA_DATABASE_VERSION + 1;
+        assert!(FormulaDatabase::from_bytes(wrong_version_vec).is_err());
+        assert!(FormulaDatabase::from_bytes(byte_vec[..100].to_vec()).is_err());
+
+        //A database cut off in its records loads, but the missing records can't be looked up.
+        let truncated_database = FormulaDatabase::from_bytes(byte_vec[..byte_vec.len() - 1].to_vec()).unwrap();
+        assert!(truncated_database.get_record(255).is_err());
+
+        assert!(FormulaDatabase::build(&formula_buckets, 2, FormulaCostModel::BinaryOperators).is_err());
+        assert!(FormulaDatabase::read_from_file(Path::new("/nonexistent/minimumformulas.db")).is_err());
+    } //End rejects_malformed_databases
+} //End mod tests
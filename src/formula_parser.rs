@@ -0,0 +1,228 @@
+/** This file parses boolean formulas from text, like "p1 & ~(p2 | p3)".
+    Author: Steven Fletcher
+    Created: 10/18/2026
+    Last Updated: 10/18/2026
+*/
+use crate::logic::*;
+
+///Parses a formula written with the same symbols that SimpleLogicNode::get_as_text uses.  From loosest to tightest,
+///the operators are <=> (equivalence), | (or), ^ (exclusive or), & (and) and ~ (not).  Parentheses group subformulas,
+///and TRUE and FALSE are the constants.
+///
+///SimpleLogicNode has no negation nodes, so negations are pushed down to the literals.  Nested operators of the same
+///kind are flattened.
+///text is the formula
+///boolean_name_list is the names of the booleans.  The name at index i is boolean i + 1.
+///Return value: the formula, or an error message if the text isn't a formula over the booleans
+pub fn parse_formula(text : &str, boolean_name_list : &Vec<String>) -> Result<SimpleLogicNode, String> {
+    let mut parser = FormulaParser {
+        token_vec : tokenize_formula(text)?,
+        position : 0,
+        boolean_name_list : boolean_name_list
+    };
+
+    let formula = parser.parse_equivalence()?;
+    match parser.peek() {
+        None => Ok(formula),
+        Some(token) => Err(format!("Unexpected {} in formula {}", token, text))
+    }
+} //End parse_formula
+
+//PRIVATE//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+//A recursive descent parser with one function per level of precedence.
+struct FormulaParser<'a> {
+    token_vec : Vec<String>,
+    position : usize,
+    boolean_name_list : &'a Vec<String>
+} //End struct FormulaParser
+
+impl FormulaParser<'_> {
+    fn peek(&self) -> Option<&str> {self.token_vec.get(self.position).map(|token| token.as_str())}
+
+    //Moves past the next token if it's the expected one.
+    //Return value: whether the next token was the expected one
+    fn accept(&mut self, expected_token : &str) -> bool {
+        if self.peek() == Some(expected_token) {
+            self.position = self.position + 1;
+            true
+        }
+        else {false}
+    } //End accept
+
+    fn parse_equivalence(&mut self) -> Result<SimpleLogicNode, String> {
+        let mut operand_vec = vec![self.parse_disjunction()?];
+        while self.accept(EQUIVALENCE_SYMBOL) {
+            operand_vec.push(self.parse_disjunction()?);
+        }
+        Ok(create_equivalence(operand_vec))
+    } //End parse_equivalence
+
+    fn parse_disjunction(&mut self) -> Result<SimpleLogicNode, String> {
+        let mut operand_vec = vec![self.parse_exclusive_disjunction()?];
+        while self.accept(DISJUNCTION_SYMBOL) {
+            operand_vec.push(self.parse_exclusive_disjunction()?);
+        }
+        Ok(create_disjunction(operand_vec))
+    } //End parse_disjunction
+
+    fn parse_exclusive_disjunction(&mut self) -> Result<SimpleLogicNode, String> {
+        let mut operand_vec = vec![self.parse_conjunction()?];
+        while self.accept(EXCLUSIVE_DISJUNCTION_SYMBOL) {
+            operand_vec.push(self.parse_conjunction()?);
+        }
+        Ok(create_exclusive_disjunction(operand_vec))
+    } //End parse_exclusive_disjunction
+
+    fn parse_conjunction(&mut self) -> Result<SimpleLogicNode, String> {
+        let mut operand_vec = vec![self.parse_negation()?];
+        while self.accept(CONJUNCTION_SYMBOL) {
+            operand_vec.push(self.parse_negation()?);
+        }
+        Ok(create_conjunction(operand_vec))
+    } //End parse_conjunction
+
+    fn parse_negation(&mut self) -> Result<SimpleLogicNode, String> {
+        if self.accept(NEGATION_SYMBOL) {
+            return Ok(self.parse_negation()?.negate());
+        }
+
+        let token =
+            match self.peek() {
+                Some(token) => token.to_string(),
+                None => return Err("Unexpected end of formula".to_string())
+            };
+        self.position = self.position + 1;
+
+        if token == "(" {
+            let formula = self.parse_equivalence()?;
+            if !self.accept(")") {return Err("Missing ) in formula".to_string());}
+            Ok(formula)
+        }
+        else if token == TRUE_TEXT {Ok(SimpleLogicNode::True)}
+        else if token == FALSE_TEXT {Ok(SimpleLogicNode::False)}
+        else {
+            match self.boolean_name_list.iter().position(|boolean_name| *boolean_name == token) {
+                Some(boolean_index) => Ok(SimpleLogicNode::Literal(boolean_index as u32 + 1)),
+                None => Err(format!("Unknown boolean {} in formula", token))
+            }
+        }
+    } //End parse_negation
+} //End impl FormulaParser
+
+//Splits a formula into names and symbols.  Whitespace only separates tokens.
+fn tokenize_formula(text : &str) -> Result<Vec<String>, String> {
+    let mut token_vec = Vec::new();
+    let mut character_iterator = text.chars().peekable();
+    while let Some(&character) = character_iterator.peek() {
+        if character.is_whitespace() {
+            character_iterator.next();
+        }
+        else if character.is_alphanumeric() || character == '_' {
+            let mut name = String::new();
+            while let Some(&name_character) = character_iterator.peek() {
+                if !(name_character.is_alphanumeric() || name_character == '_') {break;}
+                name.push(name_character);
+                character_iterator.next();
+            }
+            token_vec.push(name);
+        }
+        else if character == '<' {
+            //The only symbol with more than one character
+            let symbol : String = character_iterator.by_ref().take(EQUIVALENCE_SYMBOL.len()).collect();
+            if symbol != EQUIVALENCE_SYMBOL {return Err(format!("Unexpected character {} in formula", character));}
+            token_vec.push(symbol);
+        }
+        else if "()".contains(character) || [CONJUNCTION_SYMBOL, DISJUNCTION_SYMBOL, NEGATION_SYMBOL,
+                                              EXCLUSIVE_DISJUNCTION_SYMBOL].contains(&character.to_string().as_str())
+        {
+            token_vec.push(character.to_string());
+            character_iterator.next();
+        }
+        else {
+            return Err(format!("Unexpected character {} in formula", character));
+        }
+    } //End while there are characters left
+
+    Ok(token_vec)
+} //End tokenize_formula
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::formula_precomputer::*;
+    use crate::truth_table_size_5::*;
+
+    fn get_boolean_name_list() -> Vec<String> {
+        vec!["p1".to_string(), "p2".to_string(), "p3".to_string()]
+    } //End get_boolean_name_list
+
+    #[test]
+    fn parses_by_precedence() {
+        let boolean_name_list = get_boolean_name_list();
+        let p1 = SimpleLogicNode::Literal(1);
+        let p2 = SimpleLogicNode::Literal(2);
+        let p3 = SimpleLogicNode::Literal(3);
+        let not_p3 = SimpleLogicNode::Literal(3 | NEGATIVITY_FLAG);
+
+        assert!(parse_formula("p1 | p2 & ~p3", &boolean_name_list).unwrap() ==
+                SimpleLogicNode::Disjunction(vec![p1.clone(),
+                                                  SimpleLogicNode::Conjunction(vec![p2.clone(), not_p3.clone()])]));
+        assert!(parse_formula("(p1 | p2) & ~p3", &boolean_name_list).unwrap() ==
+                SimpleLogicNode::Conjunction(vec![SimpleLogicNode::Disjunction(vec![p1.clone(), p2.clone()]),
+                                                  not_p3.clone()]));
+        assert!(parse_formula("p1 <=> p2 ^ p3", &boolean_name_list).unwrap() ==
+                SimpleLogicNode::Equivalence(vec![p1.clone(),
+                                                  SimpleLogicNode::ExclusiveDisjunction(vec![p2.clone(), p3.clone()])]));
+        assert!(parse_formula("TRUE & FALSE", &boolean_name_list).unwrap() ==
+                SimpleLogicNode::Conjunction(vec![SimpleLogicNode::True, SimpleLogicNode::False]));
+    } //End parses_by_precedence
+
+    #[test]
+    fn pushes_negations_down_and_flattens() {
+        let boolean_name_list = get_boolean_name_list();
+        let not_p1 = SimpleLogicNode::Literal(1 | NEGATIVITY_FLAG);
+        let not_p2 = SimpleLogicNode::Literal(2 | NEGATIVITY_FLAG);
+
+        assert!(parse_formula("~(p1 & p2)", &boolean_name_list).unwrap() ==
+                SimpleLogicNode::Disjunction(vec![not_p1.clone(), not_p2.clone()]));
+        assert!(parse_formula("~~p1", &boolean_name_list).unwrap() == SimpleLogicNode::Literal(1));
+        assert!(parse_formula("p1 & (p2 & p3)", &boolean_name_list).unwrap() ==
+                SimpleLogicNode::Conjunction(vec![SimpleLogicNode::Literal(1), SimpleLogicNode::Literal(2),
+                                                  SimpleLogicNode::Literal(3)]));
+        assert!(parse_formula(" ( p1 ) ", &boolean_name_list).unwrap() == SimpleLogicNode::Literal(1));
+    } //End pushes_negations_down_and_flattens
+
+    #[test]
+    fn parses_the_text_of_every_kept_formula() {
+        let boolean_name_list = get_boolean_name_list();
+        let tt_computer = TruthTableSize5Computer::new(3);
+        let precompute_settings = PrecomputeSettings {
+            cost_model : FormulaCostModel::BinaryOperators,
+            bucket_mode : BucketMode::Stream(DEFAULT_MAX_FORMULAS_PER_BUCKET),
+            num_threads : 2,
+            pruning_settings : PruningSettings::new(),
+            family_settings : FormulaFamilySettings::new(),
+            max_shape_operators : 0,
+            verify_duality : false,
+            progress_interval : std::time::Duration::ZERO,
+            memory_budget : None
+        };
+        let (formula_buckets, _) = generate_truth_tables_with_up_to_n_variables(3, &precompute_settings, None).unwrap();
+        for formula_bucket in &formula_buckets {
+            for formula in formula_bucket.get_formula_vector() {
+                let parsed_formula = parse_formula(&formula.get_as_text(&boolean_name_list), &boolean_name_list)
+                                         .unwrap();
+                assert_eq!(tt_computer.compute_truth_table(&parsed_formula), tt_computer.compute_truth_table(&formula));
+            }
+        } //End for each bucket
+    } //End parses_the_text_of_every_kept_formula
+
+    #[test]
+    fn rejects_malformed_formulas() {
+        let boolean_name_list = get_boolean_name_list();
+        for text in ["", "p1 &", "& p1", "(p1 | p2", "p1 | p2)", "()", "p1 p2", "~", "p4", "q & p1", "p1 $ p2",
+                     "p1 <> p2", "p1 <= p2", "p1 && p2"] {
+            assert!(parse_formula(text, &boolean_name_list).is_err(), "{}", text);
+        }
+    } //End rejects_malformed_formulas
+} //End mod tests
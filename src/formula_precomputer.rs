@@ -1,62 +1,302 @@
 /** This file precomputes logic formulas and maps them to truth tables.
     Author: Steven Fletcher
     Created: 03/26/2021
-    Last Updated: 05/05/2021
+    Last Updated: 10/18/2026
 */
+use crate::and_inverter_graph::*;
+use crate::clause_iterator::*;
+use crate::dot_text::*;
+use crate::formula_database::*;
+use crate::formula_shape_enumerator::*;
 use crate::html_text::*;
 use crate::logic::*;
+use crate::packed_formula::*;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
 use std::vec::Vec;
 use crate::truth_table_size_5::*;
 
+///The number of cheapest distinct formulas BucketMode::Stream keeps if no other number is chosen.
+pub const DEFAULT_MAX_FORMULAS_PER_BUCKET : usize = 10;
+
+///The most booleans the normal method can precompute.  Every truth table gets a bucket, and a u32 can't count the 2^32
+///truth tables of 5 booleans.
+pub const MAX_BOOLEANS_FOR_NORMAL_FORMULAS : u32 = 4;
+
+///This enum determines how many of the formulas a LogicFormulaBucket keeps.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum BucketMode {
+    Full,          //Every formula is kept.  This runs out of memory for n >= 4.
+    Stream(usize)  //Only the minimum formula, the number of formulas and the k cheapest distinct formulas are kept
+} //End enum BucketMode
+
 ///This struct stores all the formulas that map to a specific truth table.
 ///The minimum cnf and dnf are stored separately from the other formulas.
+///In BucketMode::Stream, formula_vector only holds the cheapest distinct formulas, from cheapest to most expensive.
+///The formulas are packed, and they're only built as SimpleLogicNodes when they're output.
 pub struct LogicFormulaBucket {
-    minimum_formula : Option<SimpleLogicNode>,
-    formula_vector : Vec<SimpleLogicNode>
+    minimum_formula : Option<PackedFormula>,
+    minimum_cost : u32,
+    formula_vector : Vec<PackedFormula>,
+    formula_cost_vector : Vec<u32>,  //The costs of formula_vector.  Only kept in BucketMode::Stream.
+    formula_order_vector : Vec<u32>, //When each formula in formula_vector was generated
+    minimum_order : u32,             //When the minimum formula was generated
+    num_formulas : u64,
+    bucket_mode : BucketMode
 } //End struct LogicFormulaBucket
 
 impl LogicFormulaBucket {
+    ///Creates an empty bucket.
+    pub fn new(bucket_mode : BucketMode) -> LogicFormulaBucket {
+        LogicFormulaBucket {
+            minimum_formula : None,
+            minimum_cost : 0,
+            formula_vector : Vec::new(),
+            formula_cost_vector : Vec::new(),
+            formula_order_vector : Vec::new(),
+            minimum_order : 0,
+            num_formulas : 0,
+            bucket_mode : bucket_mode
+        }
+    } //End new
+
+    ///Creates a bucket that only holds one formula, which is also its minimum formula.
+    pub fn new_with_formula(formula : SimpleLogicNode, cost : u32) -> LogicFormulaBucket {
+        let mut bucket = LogicFormulaBucket::new(BucketMode::Full);
+        bucket.add_formula(PackedFormula::from_formula(formula), cost, 0);
+        bucket
+    } //End new_with_formula
+
     //Adds a formula to this bucket.
-    //formula is any SimpleLogicNode
-    fn add_formula(&mut self, formula : SimpleLogicNode) {
+    //formula is any formula
+    //cost is the cost of the formula in the cost model being used
+    //order is when the formula was generated.  Formulas must be added in order, except when merging.
+    fn add_formula(&mut self, formula : PackedFormula, cost : u32, order : u32) {
+        self.num_formulas = self.num_formulas + 1;
+
         //Add the formula to the Vector.
-        self.formula_vector.push(formula.clone());
-
-        //Check to see if the formula is the minimum formula in the bucket.
-        match &(self.minimum_formula) {
-            Option::None => {self.minimum_formula = Some(formula.clone());},
-            Option::Some(old_formula) => {
-                //If the new formula is smaller, make it the minimum formula.
-                if formula.count_binary_operators() < old_formula.count_binary_operators() {
-                    self.minimum_formula = Some(formula.to_owned());
-                }
-            } //End Some
+        match self.bucket_mode {
+            BucketMode::Full => {
+                self.formula_vector.push(formula.clone());
+                self.formula_order_vector.push(order);
+            },
+            BucketMode::Stream(max_formulas) => self.add_formula_to_cheapest(&formula, cost, order, max_formulas)
         };
+
+        self.update_minimum_formula(&formula, cost, order);
     } //End add_formula
 
+    //Makes a formula the minimum formula if it's cheaper than the current one.  Ties keep the formula generated first.
+    fn update_minimum_formula(&mut self, formula : &PackedFormula, cost : u32, order : u32) {
+        let is_new_minimum = match self.minimum_formula {
+            None => true,
+            Some(_) => (cost, order) < (self.minimum_cost, self.minimum_order)
+        };
+
+        if is_new_minimum {
+            self.minimum_formula = Some(formula.to_owned());
+            self.minimum_cost = cost;
+            self.minimum_order = order;
+        }
+    } //End update_minimum_formula
+
+    //Adds a formula to the cheapest distinct formulas if it's cheap enough.  Formulas with the same cost stay in the
+    //order they were generated.  If the formula is already kept, the copy generated first is kept.
+    fn add_formula_to_cheapest(&mut self, formula : &PackedFormula, cost : u32, order : u32, max_formulas : usize) {
+        let position = self.formula_cost_vector.iter().zip(&self.formula_order_vector)
+            .take_while(|(kept_cost, kept_order)| (**kept_cost, **kept_order) <= (cost, order)).count();
+        if position >= max_formulas {return;}
+
+        if let Some(kept_index) = self.formula_vector.iter().position(|kept_formula| kept_formula == formula) {
+            if kept_index < position {return;}
+            self.formula_vector.remove(kept_index);
+            self.formula_cost_vector.remove(kept_index);
+            self.formula_order_vector.remove(kept_index);
+        } //End if the formula is already kept
+
+        self.formula_vector.insert(position, formula.clone());
+        self.formula_cost_vector.insert(position, cost);
+        self.formula_order_vector.insert(position, order);
+        self.formula_vector.truncate(max_formulas);
+        self.formula_cost_vector.truncate(max_formulas);
+        self.formula_order_vector.truncate(max_formulas);
+    } //End add_formula_to_cheapest
+
+    //Switches a bucket that keeps every formula to streaming.  The result is the same as if the bucket had been
+    //streaming all along.
+    //cost_model and num_booleans are used to compute the costs, which full buckets don't keep
+    fn switch_to_stream(&mut self, max_formulas : usize, cost_model : FormulaCostModel, num_booleans : u32) {
+        if let BucketMode::Stream(_) = self.bucket_mode {return;}
+
+        let old_formula_vector = std::mem::take(&mut self.formula_vector);
+        let old_order_vector = std::mem::take(&mut self.formula_order_vector);
+        self.bucket_mode = BucketMode::Stream(max_formulas);
+        for (formula, order) in old_formula_vector.into_iter().zip(old_order_vector) {
+            let cost = formula.compute_cost(cost_model, num_booleans);
+            self.add_formula_to_cheapest(&formula, cost, order, max_formulas);
+        }
+    } //End switch_to_stream
+
+    //Merges another bucket of the same truth table into this one.  The result is the same as if every formula of both
+    //buckets had been added to this one in the order they were generated.
+    fn merge_from(&mut self, other : LogicFormulaBucket) {
+        self.num_formulas = self.num_formulas + other.num_formulas;
+
+        if let Some(formula) = &other.minimum_formula {
+            self.update_minimum_formula(formula, other.minimum_cost, other.minimum_order);
+        }
+
+        match self.bucket_mode {
+            BucketMode::Full => {
+                //Both vectors are sorted by order, so merge them.
+                let old_formula_vector = std::mem::take(&mut self.formula_vector);
+                let old_order_vector = std::mem::take(&mut self.formula_order_vector);
+                let mut old_iterator = old_formula_vector.into_iter().zip(old_order_vector).peekable();
+                let mut other_iterator = other.formula_vector.into_iter().zip(other.formula_order_vector).peekable();
+
+                loop {
+                    let take_old = match (old_iterator.peek(), other_iterator.peek()) {
+                        (None, None) => break,
+                        (Some(_), None) => true,
+                        (None, Some(_)) => false,
+                        (Some((_, old_order)), Some((_, other_order))) => old_order <= other_order
+                    };
+                    let (formula, order) =
+                        if take_old {old_iterator.next().unwrap()} else {other_iterator.next().unwrap()};
+                    self.formula_vector.push(formula);
+                    self.formula_order_vector.push(order);
+                } //End loop over both vectors
+            },
+            BucketMode::Stream(max_formulas) => {
+                for formula_index in 0..other.formula_vector.len() {
+                    self.add_formula_to_cheapest(&other.formula_vector[formula_index],
+                                                 other.formula_cost_vector[formula_index],
+                                                 other.formula_order_vector[formula_index], max_formulas);
+                } //End for each formula kept by the other bucket
+            }
+        } //End match self.bucket_mode
+    } //End merge_from
+
+    //Adds everything in this bucket to a checkpoint.  read_checkpoint_bytes reads it back.
+    fn add_checkpoint_bytes(&self, byte_vec : &mut Vec<u8>) {
+        byte_vec.extend_from_slice(&self.num_formulas.to_le_bytes());
+        encode_optional_formula(byte_vec, self.minimum_formula.as_ref().map(PackedFormula::to_formula).as_ref());
+        byte_vec.extend_from_slice(&self.minimum_cost.to_le_bytes());
+        byte_vec.extend_from_slice(&self.minimum_order.to_le_bytes());
+
+        byte_vec.extend_from_slice(&(self.formula_vector.len() as u32).to_le_bytes());
+        for formula_index in 0..self.formula_vector.len() {
+            byte_vec.extend_from_slice(&self.formula_order_vector[formula_index].to_le_bytes());
+            encode_formula(byte_vec, &self.formula_vector[formula_index].to_formula());
+        } //End for each formula
+
+        //The costs are only kept when streaming.
+        byte_vec.extend_from_slice(&(self.formula_cost_vector.len() as u32).to_le_bytes());
+        for cost in &self.formula_cost_vector {
+            byte_vec.extend_from_slice(&cost.to_le_bytes());
+        }
+    } //End add_checkpoint_bytes
+
+    //Reads a bucket written by add_checkpoint_bytes.
+    //Return value: the bucket, or an error message if the checkpoint is corrupt
+    fn read_checkpoint_bytes(reader : &mut FormulaByteReader, bucket_mode : BucketMode, num_booleans : u32)
+        -> Result<LogicFormulaBucket, String>
+    {
+        let mut bucket = LogicFormulaBucket::new(bucket_mode);
+        bucket.num_formulas = reader.read_u64()?;
+        bucket.minimum_formula = reader.read_formula(num_booleans)?.map(PackedFormula::from_formula);
+        bucket.minimum_cost = reader.read_u32()?;
+        bucket.minimum_order = reader.read_u32()?;
+
+        let num_kept_formulas = reader.read_u32()?;
+        for _i in 0..num_kept_formulas {
+            bucket.formula_order_vector.push(reader.read_u32()?);
+            match reader.read_formula(num_booleans)? {
+                Some(formula) => bucket.formula_vector.push(PackedFormula::from_formula(formula)),
+                None => return Err("Missing formula in checkpoint".to_string())
+            };
+        } //End for each formula
+
+        let num_costs = reader.read_u32()?;
+        for _i in 0..num_costs {
+            bucket.formula_cost_vector.push(reader.read_u32()?);
+        }
+
+        Ok(bucket)
+    } //End read_checkpoint_bytes
+
+    ///Builds the minimum formula, or returns None if the bucket is empty.
+    pub fn get_minimum_formula(&self) -> Option<SimpleLogicNode> {self.minimum_formula.as_ref().map(PackedFormula::to_formula)}
+
+    ///Builds every formula this bucket kept.
+    pub fn get_formula_vector(&self) -> Vec<SimpleLogicNode> {
+        self.formula_vector.iter().map(PackedFormula::to_formula).collect()
+    } //End get_formula_vector
+
+    ///Gets the number of formulas added to this bucket, including the ones that weren't kept.
+    pub fn get_num_formulas(&self) -> u64 {self.num_formulas}
+
+    ///Gets the cost of the minimum formula, or None if the bucket is empty.
+    pub fn get_minimum_cost(&self) -> Option<u32> {
+        self.minimum_formula.as_ref().map(|_| self.minimum_cost)
+    } //End get_minimum_cost
+
     ///Adds the Html for the formula list.
     pub fn add_html_for_formula_list(&self, html_generator : &mut HtmlGenerator, boolean_name_list : &Vec<String>) {
         //Minimum Formula
         let cnf_text =
-            match self.minimum_formula.as_ref() {
+            match self.get_minimum_formula() {
                 Some(formula) => formula.get_as_text(boolean_name_list),
                 None                         => NONE_TEXT.to_string()
             };
         html_generator.add_paragraph(&format!("Minimum Formula: {}", cnf_text));
 
+        //Only some of the formulas are kept when streaming.
+        if let BucketMode::Stream(_) = self.bucket_mode {
+            html_generator.add_paragraph(&format!("{} formulas have this truth table.  The {} cheapest distinct \
+                                                   formulas are:", self.num_formulas, self.formula_vector.len()));
+        }
+
         //List of all formulas.
-        for formula in &self.formula_vector {
+        html_generator.list_create(false, "");
+        for formula in self.get_formula_vector() {
             html_generator.list_add_row("", &formula.get_as_text(boolean_name_list));
         } //End for each formula
 
         html_generator.list_end();
     } //End add_html_for_formula_list
 
+    ///Adds a drawing of every formula in this bucket to a DOT graph, one cluster per formula.
+    ///node_name_prefix is put in front of every node name, so several buckets can be drawn in one graph
+    pub fn add_dot_for_formula_list(&self, dot_generator : &mut DotGenerator, node_name_prefix : &str,
+                                    boolean_name_list : &Vec<String>, options : &DotOptions)
+    {
+        //The minimum formula is a copy of one in the list.  Ties keep the first formula, so it's the first match.
+        let formula_vector = self.get_formula_vector();
+        let minimum_index = self.get_minimum_formula().and_then(|minimum_formula| {
+            let minimum_text = minimum_formula.get_as_text(boolean_name_list);
+            formula_vector.iter().position(|formula| formula.get_as_text(boolean_name_list) == minimum_text)
+        });
+
+        for (formula_index, formula) in formula_vector.iter().enumerate() {
+            let is_highlighted = options.highlight_minimum && Some(formula_index) == minimum_index;
+            let label = if options.show_labels {formula.get_as_text(boolean_name_list)} else {String::new()};
+            let properties = if is_highlighted {DOT_HIGHLIGHT_PROPERTIES} else {""};
+
+            dot_generator.cluster_create(&label, properties);
+            add_dot_for_formula(dot_generator, formula, &format!("{}f{}_", node_name_prefix, formula_index),
+                                boolean_name_list, options.share_nodes, properties);
+            dot_generator.cluster_end();
+        } //End for each formula
+    } //End add_dot_for_formula_list
+
     pub fn get_formula_list_as_text(&self, boolean_name_list : &Vec<String>) -> String {
         let mut formula_list_text = "".to_string();
 
         //List of all formulas.
-        for formula in &self.formula_vector {
+        for formula in self.get_formula_vector() {
             formula_list_text.push_str(&formula.get_as_text(boolean_name_list));
             formula_list_text.push('\n');
         } //End for each formula
@@ -65,111 +305,483 @@ impl LogicFormulaBucket {
     }  //End get_formula_list_as_text
 } //End impl LogicFormulaBucket
 
+///The ways to measure the size of a formula when choosing the minimum formula of a truth table.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum FormulaCostModel {
+    BinaryOperators,    //The number of binary operators in the formula
+    TwoInputOperators,  //The number of two-input operators in the formula, where k operands take k - 1 of them
+    AigAndGates         //The number of AND gates in the formula's and-inverter graph, after structural hashing
+} //End enum FormulaCostModel
+
+impl FormulaCostModel {
+    ///Parses a cost model from its command line name ("operators", "twoinput" or "aig").
+    pub fn parse(text : &str) -> Option<FormulaCostModel> {
+        match text {
+            "operators" => Some(FormulaCostModel::BinaryOperators),
+            "twoinput" => Some(FormulaCostModel::TwoInputOperators),
+            "aig" => Some(FormulaCostModel::AigAndGates),
+            _ => None
+        }
+    } //End parse
+
+    ///Gets the command line name of this cost model.
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            FormulaCostModel::BinaryOperators => "operators",
+            FormulaCostModel::TwoInputOperators => "twoinput",
+            FormulaCostModel::AigAndGates => "aig"
+        }
+    } //End get_name
+
+    ///Computes the cost of a formula over the booleans 1 to n.
+    pub fn compute_cost(&self, formula : &SimpleLogicNode, n : u32) -> u32 {
+        match self {
+            FormulaCostModel::BinaryOperators => formula.count_binary_operators(),
+            FormulaCostModel::TwoInputOperators => formula.count_two_input_operators(),
+            FormulaCostModel::AigAndGates => {
+                let mut aig = AndInverterGraph::new(n);
+                let literal = aig.build_from_formula(formula);
+                aig.count_reachable_and_gates(&[literal])
+            }
+        } //End match self
+    } //End compute_cost
+} //End impl FormulaCostModel
+
+///The rules that stop the normal formula generator from adding a clause to the clauses before it.  When a rule cuts a
+///clause, the formula with those clauses and every formula that starts with them are skipped, so turning a rule off
+///generates more formulas but takes longer.
+///
+///ClauseIterator never makes a clause with a literal and its negation, and a clause mask can't hold a literal twice,
+///so TautologicalClauses and DuplicateVariableClauses never cut anything with the current clause generator.  They're
+///kept so the rules still hold if the clauses come from somewhere else.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum PruningRule {
+    TautologicalClauses,       //The new clause has a literal and its negation
+    DuplicateVariableClauses,  //The new clause has the same literal twice
+    OppositeUnits,             //The new clause is a single literal, and its negation is an earlier clause
+    Subsumption,               //An earlier clause is a subset of the new clause, so the new clause is redundant
+    MergeableClauses           //The new clause and an earlier clause only differ in the sign of one literal, so they
+                               //resolve to a shorter clause
+} //End enum PruningRule
+
+///The number of pruning rules.
+pub const NUM_PRUNING_RULES : usize = 5;
+
+impl PruningRule {
+    ///Every pruning rule, in the order they're checked.
+    pub const ALL : [PruningRule; NUM_PRUNING_RULES] = [
+        PruningRule::TautologicalClauses,
+        PruningRule::DuplicateVariableClauses,
+        PruningRule::OppositeUnits,
+        PruningRule::Subsumption,
+        PruningRule::MergeableClauses
+    ];
+
+    ///Parses a pruning rule from its command line name.
+    pub fn parse(text : &str) -> Option<PruningRule> {
+        PruningRule::ALL.iter().copied().find(|pruning_rule| pruning_rule.get_name() == text)
+    } //End parse
+
+    ///Gets the command line name of this pruning rule.
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            PruningRule::TautologicalClauses => "tautological",
+            PruningRule::DuplicateVariableClauses => "duplicate",
+            PruningRule::OppositeUnits => "opposite",
+            PruningRule::Subsumption => "subsumption",
+            PruningRule::MergeableClauses => "mergeable"
+        }
+    } //End get_name
+
+    //Gets the position of this rule in PruningRule::ALL.
+    fn get_index(&self) -> usize {
+        PruningRule::ALL.iter().position(|pruning_rule| pruning_rule == self).unwrap()
+    } //End get_index
+
+    //Determines whether this rule cuts a new clause.
+    //prefix_clauses is the clauses before the new clause
+    fn cuts_clause(&self, prefix_clauses : &Vec<ClauseMask>, new_clause : ClauseMask) -> bool {
+        match self {
+            PruningRule::TautologicalClauses => is_tautological_clause(new_clause),
+            //A clause mask can't hold the same literal twice, so there's nothing to check.
+            PruningRule::DuplicateVariableClauses => false,
+            PruningRule::OppositeUnits => {
+                new_clause.count_ones() == 1 && prefix_clauses.iter().any(|prefix_clause| {
+                    prefix_clause.count_ones() == 1 && get_clause_booleans(*prefix_clause) == get_clause_booleans(new_clause) &&
+                        *prefix_clause != new_clause
+                })
+            },
+            PruningRule::Subsumption => {
+                prefix_clauses.iter().any(|prefix_clause| prefix_clause & !new_clause == 0)
+            },
+            PruningRule::MergeableClauses => {
+                prefix_clauses.iter().any(|prefix_clause| {
+                    get_clause_booleans(*prefix_clause) == get_clause_booleans(new_clause) &&
+                        get_clause_booleans(prefix_clause ^ new_clause).count_ones() == 1
+                })
+            }
+        } //End match self
+    } //End cuts_clause
+} //End impl PruningRule
+
+///This struct stores which pruning rules the normal formula generator uses.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PruningSettings {
+    is_enabled_array : [bool; NUM_PRUNING_RULES]
+} //End struct PruningSettings
+
+impl PruningSettings {
+    ///Gets the default settings, which generate the same formulas as before there were pruning rules: every rule but
+    ///mergeable clauses.
+    pub fn new() -> PruningSettings {
+        PruningSettings::parse(DEFAULT_PRUNING_RULES_TEXT).unwrap()
+    } //End new
+
+    ///Parses a comma separated list of the rules that are turned on, like "opposite,subsumption".  Rules that aren't
+    ///listed are turned off.  "none" turns every rule off.
+    ///Return value: the settings, or None if a rule isn't recognized
+    pub fn parse(text : &str) -> Option<PruningSettings> {
+        let mut pruning_settings = PruningSettings {is_enabled_array : [false; NUM_PRUNING_RULES]};
+        if text == PRUNING_RULES_NONE_TEXT {return Some(pruning_settings);}
+
+        for rule_text in text.split(',') {
+            pruning_settings.set_enabled(PruningRule::parse(rule_text.trim())?, true);
+        }
+        Some(pruning_settings)
+    } //End parse
+
+    pub fn is_enabled(&self, pruning_rule : PruningRule) -> bool {self.is_enabled_array[pruning_rule.get_index()]}
+
+    pub fn set_enabled(&mut self, pruning_rule : PruningRule, is_enabled : bool) {
+        self.is_enabled_array[pruning_rule.get_index()] = is_enabled;
+    } //End set_enabled
+
+    //Finds the first enabled rule that cuts a new clause.
+    fn find_cutting_rule(&self, prefix_clauses : &Vec<ClauseMask>, new_clause : ClauseMask) -> Option<PruningRule> {
+        PruningRule::ALL.iter().copied().find(|pruning_rule| {
+            self.is_enabled(*pruning_rule) && pruning_rule.cuts_clause(prefix_clauses, new_clause)
+        })
+    } //End find_cutting_rule
+} //End impl PruningSettings
+
+impl Default for PruningSettings {
+    fn default() -> Self {Self::new()}
+} //End impl Default for PruningSettings
+
+///The template families of formulas with equivalences and exclusive ors that the precomputer can add to the buckets.
+///The normal formula generator only generates CNF and DNF formulas, so without them the minimum formula of a
+///parity-like truth table is a huge DNF.  A family is generated for every assignment of booleans and signs, except that
+///negating two operands of an equivalence or exclusive or doesn't change it, so only its first operand is negated.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum FormulaFamily {
+    EquivalentDisjunction,       //p <=> a | b | c, a literal equivalent to a disjunction of 2 or more literals
+    EquivalenceChain,            //(p <=> q) <=> r, an equivalence of 2 or more literals
+    ChainEquivalence,            //(p <=> q) & (q <=> r), which is (p & q & r) | (~p & ~q & ~r), for 3 or more literals
+    ConjunctionWithEquivalence,  //p & (q <=> r)
+    ExclusiveDisjunctionChain    //p ^ q ^ r, an exclusive or of 2 or more literals
+} //End enum FormulaFamily
+
+///The number of formula families.
+pub const NUM_FORMULA_FAMILIES : usize = 5;
+
+impl FormulaFamily {
+    ///Every formula family, in the order they're added to the buckets.
+    pub const ALL : [FormulaFamily; NUM_FORMULA_FAMILIES] = [
+        FormulaFamily::EquivalentDisjunction,
+        FormulaFamily::EquivalenceChain,
+        FormulaFamily::ChainEquivalence,
+        FormulaFamily::ConjunctionWithEquivalence,
+        FormulaFamily::ExclusiveDisjunctionChain
+    ];
+
+    ///Parses a formula family from its command line name.
+    pub fn parse(text : &str) -> Option<FormulaFamily> {
+        FormulaFamily::ALL.iter().copied().find(|formula_family| formula_family.get_name() == text)
+    } //End parse
+
+    ///Gets the command line name of this formula family.
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            FormulaFamily::EquivalentDisjunction => "disjunction",
+            FormulaFamily::EquivalenceChain => "equivalence",
+            FormulaFamily::ChainEquivalence => "chain",
+            FormulaFamily::ConjunctionWithEquivalence => "conjunction",
+            FormulaFamily::ExclusiveDisjunctionChain => "xor"
+        }
+    } //End get_name
+
+    ///Generates every formula of this family over the booleans 1 to n.
+    pub fn generate_formulas(&self, n : u32) -> Vec<SimpleLogicNode> {
+        let mut formula_vec = Vec::new();
+        match self {
+            FormulaFamily::EquivalentDisjunction => {
+                for boolean_index in 1..=n {
+                    for disjunct_booleans in get_boolean_subsets(n, 2) {
+                        if disjunct_booleans.contains(&boolean_index) {continue;}
+
+                        for disjunct_literals in ClauseIterator::with_booleans(disjunct_booleans) {
+                            let disjunction = SimpleLogicNode::Disjunction(create_literal_nodes(&disjunct_literals));
+                            for literal in [boolean_index, boolean_index | NEGATIVITY_FLAG] {
+                                formula_vec.push(SimpleLogicNode::Equivalence(vec![SimpleLogicNode::Literal(literal),
+                                                                                   disjunction.clone()]));
+                            }
+                        } //End for each sign of the disjuncts
+                    } //End for each set of booleans in the disjunction
+                } //End for each boolean on the other side of the equivalence
+            },
+            FormulaFamily::EquivalenceChain | FormulaFamily::ExclusiveDisjunctionChain => {
+                for operand_booleans in get_boolean_subsets(n, 2) {
+                    for first_literal in [operand_booleans[0], operand_booleans[0] | NEGATIVITY_FLAG] {
+                        let mut operands = create_literal_nodes(&operand_booleans);
+                        operands[0] = SimpleLogicNode::Literal(first_literal);
+                        formula_vec.push(
+                            if *self == FormulaFamily::EquivalenceChain {SimpleLogicNode::Equivalence(operands)}
+                            else {SimpleLogicNode::ExclusiveDisjunction(operands)}
+                        );
+                    } //End for each sign of the first operand
+                } //End for each set of booleans in the chain
+            },
+            FormulaFamily::ChainEquivalence => {
+                //The signs say which booleans have to be false when the first one is true.
+                for chain_booleans in get_boolean_subsets(n, 3) {
+                    for later_literals in ClauseIterator::with_booleans(chain_booleans[1..].to_vec()) {
+                        let mut chain_literals = vec![chain_booleans[0]];
+                        chain_literals.extend(later_literals);
+                        let conjuncts = chain_literals.windows(2).map(|literal_pair| {
+                            SimpleLogicNode::Equivalence(create_literal_nodes(&literal_pair.to_vec()))
+                        }).collect();
+                        formula_vec.push(SimpleLogicNode::Conjunction(conjuncts));
+                    } //End for each sign of the later booleans
+                } //End for each set of booleans in the chain
+            },
+            FormulaFamily::ConjunctionWithEquivalence => {
+                for boolean_index in 1..=n {
+                    for equivalence_booleans in get_boolean_subsets(n, 2) {
+                        if equivalence_booleans.len() != 2 || equivalence_booleans.contains(&boolean_index) {continue;}
+
+                        for second_literal in [equivalence_booleans[1], equivalence_booleans[1] | NEGATIVITY_FLAG] {
+                            let equivalence = SimpleLogicNode::Equivalence(
+                                create_literal_nodes(&vec![equivalence_booleans[0], second_literal]));
+                            for literal in [boolean_index, boolean_index | NEGATIVITY_FLAG] {
+                                formula_vec.push(SimpleLogicNode::Conjunction(vec![SimpleLogicNode::Literal(literal),
+                                                                                   equivalence.clone()]));
+                            }
+                        } //End for each sign of the second operand of the equivalence
+                    } //End for each pair of booleans in the equivalence
+                } //End for each boolean in the conjunction
+            }
+        } //End match self
+
+        formula_vec
+    } //End generate_formulas
+
+    //Gets the position of this family in FormulaFamily::ALL.
+    fn get_index(&self) -> usize {
+        FormulaFamily::ALL.iter().position(|formula_family| formula_family == self).unwrap()
+    } //End get_index
+} //End impl FormulaFamily
+
+///This struct stores which formula families the precomputer adds to the buckets.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct FormulaFamilySettings {
+    is_enabled_array : [bool; NUM_FORMULA_FAMILIES]
+} //End struct FormulaFamilySettings
+
+impl FormulaFamilySettings {
+    ///Gets the default settings, which don't add any families, so only CNF and DNF formulas are generated.
+    pub fn new() -> FormulaFamilySettings {
+        FormulaFamilySettings {is_enabled_array : [false; NUM_FORMULA_FAMILIES]}
+    } //End new
+
+    ///Parses a comma separated list of the families that are turned on, like "equivalence,xor".  Families that aren't
+    ///listed are turned off.  "none" turns every family off and "all" turns every family on.
+    ///Return value: the settings, or None if a family isn't recognized
+    pub fn parse(text : &str) -> Option<FormulaFamilySettings> {
+        let mut family_settings = FormulaFamilySettings::new();
+        if text == FORMULA_FAMILIES_NONE_TEXT {return Some(family_settings);}
+        if text == FORMULA_FAMILIES_ALL_TEXT {
+            family_settings.is_enabled_array = [true; NUM_FORMULA_FAMILIES];
+            return Some(family_settings);
+        }
+
+        for family_text in text.split(',') {
+            family_settings.set_enabled(FormulaFamily::parse(family_text.trim())?, true);
+        }
+        Some(family_settings)
+    } //End parse
+
+    pub fn is_enabled(&self, formula_family : FormulaFamily) -> bool {
+        self.is_enabled_array[formula_family.get_index()]
+    } //End is_enabled
+
+    pub fn set_enabled(&mut self, formula_family : FormulaFamily, is_enabled : bool) {
+        self.is_enabled_array[formula_family.get_index()] = is_enabled;
+    } //End set_enabled
+} //End impl FormulaFamilySettings
+
+impl Default for FormulaFamilySettings {
+    fn default() -> Self {Self::new()}
+} //End impl Default for FormulaFamilySettings
+
+///This struct reports what happened during a run of generate_truth_tables_with_up_to_n_variables.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PrecomputeReport {
+    num_cuts_array : [u64; NUM_PRUNING_RULES],  //How many clauses each pruning rule cut
+    num_duality_checks : u64,                   //How many derived CNF truth tables were checked
+    num_duality_failures : u64,                 //How many of them were wrong
+    num_formulas_generated : u64,               //How many formulas were added to the buckets
+    is_complete : bool,                         //False if the run stopped early because it ran out of memory
+    normal_formula_duration : Duration,         //How long generating the CNF and DNF formulas took
+    family_duration : Duration,                 //How long adding the formula families took
+    shape_duration : Duration                   //How long adding the formulas of every shape took
+} //End struct PrecomputeReport
+
+impl PrecomputeReport {
+    ///Creates an empty report.
+    pub fn new() -> PrecomputeReport {
+        PrecomputeReport {
+            num_cuts_array : [0; NUM_PRUNING_RULES],
+            num_duality_checks : 0,
+            num_duality_failures : 0,
+            num_formulas_generated : 0,
+            is_complete : true,
+            normal_formula_duration : Duration::ZERO,
+            family_duration : Duration::ZERO,
+            shape_duration : Duration::ZERO
+        }
+    } //End new
+
+    ///Gets the number of clauses a pruning rule cut.  Each cut skips a formula and every formula that starts with it,
+    ///so it's a lower bound on the formulas the rule removed.
+    pub fn get_num_cuts(&self, pruning_rule : PruningRule) -> u64 {self.num_cuts_array[pruning_rule.get_index()]}
+    pub fn get_num_formulas_generated(&self) -> u64 {self.num_formulas_generated}
+
+    ///Determines whether every formula was generated.  If the run exceeded its memory budget, the buckets only have
+    ///some of the formulas.
+    pub fn is_complete(&self) -> bool {self.is_complete}
+
+    ///Gets the name and duration of each phase of the run, in the order they ran.  A resumed run only counts the time
+    ///since it resumed.
+    pub fn get_phase_durations(&self) -> [(&'static str, Duration); 3] {
+        [("Normal formulas", self.normal_formula_duration),
+         ("Formula families", self.family_duration),
+         ("Formula shapes", self.shape_duration)]
+    } //End get_phase_durations
+
+    ///Gets a text report with the number of formulas, one line per pruning rule, and a line for the duality check if
+    ///it was on.  The pruning rules count clauses, not formulas, since counting the formulas under each cut would
+    ///cost as much as generating them.
+    pub fn get_as_text(&self, pruning_settings : &PruningSettings) -> String {
+        let mut text = String::new();
+        if !self.is_complete {
+            text.push_str("The run exceeded its memory budget and stopped early\n");
+        }
+        text.push_str(&format!("{} formulas generated\n", self.num_formulas_generated));
+        for pruning_rule in PruningRule::ALL.iter() {
+            if pruning_settings.is_enabled(*pruning_rule) {
+                text.push_str(&format!("Pruning rule {} cut {} clauses, each skipping every formula that starts with the \
+                                        clauses up to it\n", pruning_rule.get_name(),
+                                       self.get_num_cuts(*pruning_rule)));
+            }
+            else {
+                text.push_str(&format!("Pruning rule {} is off\n", pruning_rule.get_name()));
+            }
+        } //End for each pruning rule
+
+        if self.num_duality_checks > 0 {
+            text.push_str(&format!("Duality check: {} of {} CNF truth tables derived from their DNF were wrong\n",
+                                   self.num_duality_failures, self.num_duality_checks));
+        }
+
+        text
+    } //End get_as_text
+
+    //Adds the counts of another report to this one.
+    fn add(&mut self, other : &PrecomputeReport) {
+        for rule_index in 0..NUM_PRUNING_RULES {
+            self.num_cuts_array[rule_index] = self.num_cuts_array[rule_index] + other.num_cuts_array[rule_index];
+        }
+        self.num_duality_checks = self.num_duality_checks + other.num_duality_checks;
+        self.num_duality_failures = self.num_duality_failures + other.num_duality_failures;
+        self.num_formulas_generated = self.num_formulas_generated + other.num_formulas_generated;
+    } //End add
+} //End impl PrecomputeReport
+
+impl Default for PrecomputeReport {
+    fn default() -> Self {Self::new()}
+} //End impl Default for PrecomputeReport
+
+///This struct determines where and how often generate_truth_tables_with_up_to_n_variables saves its progress.
+///filepath is the checkpoint file.  It's deleted when the run finishes.
+///interval is the time between checkpoints.  A zero interval never saves a checkpoint, but can still resume from one.
+///resume determines whether the run starts from the checkpoint instead of from scratch
+pub struct CheckpointSettings {
+    pub filepath : PathBuf,
+    pub interval : Duration,
+    pub resume : bool
+} //End struct CheckpointSettings
+
+///This struct determines which formulas generate_truth_tables_with_up_to_n_variables generates and how it keeps them.
+///cost_model determines how the minimum formula of each truth table is chosen.
+///bucket_mode determines how many formulas each bucket keeps.
+///num_threads is the number of threads that generate normal formulas.  The result is the same for any number of
+///threads.
+///pruning_settings determines which clauses of the normal formulas are skipped.
+///family_settings determines which formula families are added after the CNF and DNF formulas.
+///max_shape_operators is the most binary operators in the formulas of every shape that are added after the families.
+///0 doesn't add any.
+///verify_duality determines whether the truth table of every CNF formula, which is derived from the truth table of the
+///DNF formula with the same clauses, is also computed directly to check it.  This is slow, so it's meant for small n.
+///progress_interval is the time between progress reports.  A zero interval never reports progress.
+///memory_budget is the most resident memory the run should use, in bytes.  None has no budget.  When the budget is
+///exceeded, full buckets switch to streaming.  If the buckets are already streaming and the memory keeps growing, the
+///run stops early with a partial report, after saving a checkpoint if there are checkpoint settings.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PrecomputeSettings {
+    pub cost_model : FormulaCostModel,
+    pub bucket_mode : BucketMode,
+    pub num_threads : usize,
+    pub pruning_settings : PruningSettings,
+    pub family_settings : FormulaFamilySettings,
+    pub max_shape_operators : u32,
+    pub verify_duality : bool,
+    pub progress_interval : Duration,
+    pub memory_budget : Option<u64>
+} //End struct PrecomputeSettings
+
 ///Generates all the truth tables with up to n variables.  Also maps a ton of boolean formulas to those truth tables.
 ///Trivial subformulas like p & p or p | ~p do not appear.
-///tt_computer is the computation struct that computes the truth tables.
-///For this function n <= 5 to avoid overflow.  For large n, this function is intractable anyways.  It's O(16^n).
-pub fn generate_truth_tables_with_up_to_n_variables(n : u32) -> Vec<LogicFormulaBucket> {
+///precompute_settings determines which formulas are generated and how the buckets keep them.
+///checkpoint_settings determines whether the progress is saved to a checkpoint and whether the run resumes from one.
+///The result is the same as if the run had never been interrupted.
+///For this function n <= 4, since every truth table gets a bucket and n = 5 would need 2^32 of them.  For large n,
+///this function is intractable anyways.  It's O(16^n).
+///Return value: the buckets and a report of the run, or an error message if n is too big or a checkpoint can't be
+///written or resumed from
+pub fn generate_truth_tables_with_up_to_n_variables(n : u32, precompute_settings : &PrecomputeSettings,
+                                                   checkpoint_settings : Option<&CheckpointSettings>)
+    -> Result<(Vec<LogicFormulaBucket>, PrecomputeReport), String>
+{
     //Ignore n < 1.
     if n < 1 {
         panic!("Cannot generate truth tables for n < 1.");
     } //End if n < 1
+    if n > MAX_BOOLEANS_FOR_NORMAL_FORMULAS {
+        return Err(format!("The normal method needs n <= {}, not {}", MAX_BOOLEANS_FOR_NORMAL_FORMULAS, n));
+    }
+    let cost_model = precompute_settings.cost_model;
 
     //This object generates CNF and DNF formulas.
-    let two_to_n  = compute_two_to_n(n) as usize;
     let num_truth_tables = compute_two_to_two_to_n(n) as usize;
 
     //Create a bucket of formulas for each truth table.
-    let mut formula_buckets: Vec<LogicFormulaBucket> = Vec::with_capacity(num_truth_tables);
-    for _i in 0..num_truth_tables {
-        formula_buckets.push(LogicFormulaBucket {
-            minimum_formula : None,
-            formula_vector : Vec::with_capacity(two_to_n)
-        });
-    } //End for each bucket to add
+    let mut formula_buckets = create_formula_buckets(num_truth_tables, precompute_settings.bucket_mode);
+    formula_buckets[0].add_formula(PackedFormula::from_formula(SimpleLogicNode::False), 0, 0);
+    formula_buckets[num_truth_tables - 1].add_formula(PackedFormula::from_formula(SimpleLogicNode::True), 0, 0);
 
-    formula_buckets[0].add_formula(SimpleLogicNode::False);
-    formula_buckets[num_truth_tables - 1].add_formula(SimpleLogicNode::True);
-
-    //Compute n factorial.
-    let mut n_factorial = 1;
-    for i in 2..=n {n_factorial = n_factorial * i;}
-
-    //This isn't the correct capacity, but it should be close enough.
-    let literal_subarray_capacity = (2 * n + n_factorial * compute_two_to_n(n)) as usize;
-
-    //Store all different possible subarrays of the variables to generate truth tables for.
-    let mut literal_subarray_vec : Vec<Vec<u32>> = Vec::with_capacity(literal_subarray_capacity);
-
-    //Create all the possible literals.
-    for i in 1..=n {
-        literal_subarray_vec.push(vec![i]);
-        literal_subarray_vec.push(vec![i | NEGATIVITY_FLAG]);
-    } //End for each boolean
-
-    //If n is at least 2, add pairs of literals.
-    if n >= 2 {
-        //Create each clause with 2 literals.
-        for i in 1..=n {
-            for j in i+1..=n {
-                literal_subarray_vec.push(vec![i, j]);
-                literal_subarray_vec.push(vec![i | NEGATIVITY_FLAG, j]);
-                literal_subarray_vec.push(vec![i, j | NEGATIVITY_FLAG]);
-                literal_subarray_vec.push(vec![i | NEGATIVITY_FLAG, j | NEGATIVITY_FLAG]);
-            } //End for each second literal of the pair
-        } //End for each first literal of the pair
-    } //End if n is at least 2
-
-    //If n is at least 3, add triples of literals.
-    if n >= 3 {
-        //Create each clause with 3 literals.
-        for i in 1..=n {
-            for j in i+1..=n {
-                for k in j+1..=n {
-                    for flagged_boolean_indices_vec in AssignFlagsIterator::new(vec![i, j, k])
-                    {
-                        literal_subarray_vec.push(flagged_boolean_indices_vec);
-                    } //End for each Vec of flagged boolean indices
-                } //End for the third literal of the subarray
-            } //End for each second literal of the subarray
-        } //End for each first literal of the subarray
-    } //End if n is at least 3
-
-    //If n is at least 4, add quadruples of literals.
-    if n >= 4 {
-        //Create each clause with 4 literals.
-        for i in 1..=n {
-            for j in i+1..=n {
-                for k in j+1..=n {
-                    for l in k+1..=n {
-                        for flagged_boolean_indices_vec in AssignFlagsIterator::new(vec![i, j, k, l])
-                        {
-                            literal_subarray_vec.push(flagged_boolean_indices_vec);
-                        } //End for each Vec of flagged boolean indices
-                    } //End for the fourth literal of the subarray
-                } //End for the third literal of the subarray
-            } //End for each second literal of the subarray
-        } //End for each first literal of the subarray
-    } //End if n is at least 4
-
-    //If n is at least 5, add quintuples of literals.
-    if n >= 5 {
-        //Create each clause with 5 literals.
-        for i in 1..=n {
-            for j in i+1..=n {
-                for k in j+1..=n {
-                    for l in k+1..=n {
-                        for m in l+1..=n {
-                            for flagged_boolean_indices_vec in AssignFlagsIterator::new(vec![i, j, k, l])
-                            {
-                                literal_subarray_vec.push(flagged_boolean_indices_vec);
-                            } //End for each Vec of flagged boolean indices
-                        } //End for the fifth literal of the subarray
-                    } //End for the fourth literal of the subarray
-                } //End for the third literal of the subarray
-            } //End for each second literal of the subarray
-        } //End for each first literal of the subarray
-    } //End if n is at least 5
+    //Store every clause of the booleans to generate truth tables for, from the narrowest to the widest.
+    let mut literal_subarray_vec : Vec<Vec<u32>> = Vec::with_capacity(ClauseIterator::count_clauses(n, n) as usize);
+    literal_subarray_vec.extend(ClauseIterator::new(n, n));
 
     //Output all subarrays.
 /*    for literal_subarray in &literal_subarray_vec {
@@ -181,177 +793,580 @@ pub fn generate_truth_tables_with_up_to_n_variables(n : u32) -> Vec<LogicFormula
         println!("{}", SimpleLogicNode::Conjunction(literals_vec).get_as_text(&boolean_name_array))
     } //End for each literal subarray
 */
-    //Generate the normal formulas.
-    let mut nf_generator = NormalFormulaGenerator::new(formula_buckets, literal_subarray_vec, n);
-    nf_generator.generate_all_normal_formulas();
-    nf_generator.formula_buckets
-} //End generate_truth_tables_with_up_to_5_variables
+    //Generate the normal formulas, starting from the checkpoint if the run is being resumed.  The generator works on
+    //packed clauses.
+    let clause_mask_vec : Vec<ClauseMask> =
+        literal_subarray_vec.iter().map(|literal_subarray| get_clause_mask(literal_subarray)).collect();
+    let mut normal_formula_run = NormalFormulaRun::new(&clause_mask_vec, n, precompute_settings);
+    let mut next_work_unit_index = 0;
+    let mut precompute_report = PrecomputeReport::new();
+    if let Some(checkpoint_settings) = checkpoint_settings {
+        if checkpoint_settings.resume {
+            let (checkpoint_buckets, checkpoint_report, checkpoint_work_unit_index) =
+                normal_formula_run.read_checkpoint(&checkpoint_settings.filepath)?;
+            formula_buckets = checkpoint_buckets;
+            precompute_report = checkpoint_report;
+            next_work_unit_index = checkpoint_work_unit_index;
+        }
+    } //End if there are checkpoint settings
+
+    //Save a checkpoint, report the progress and check the memory every interval until every work unit is done.
+    let checkpoint_interval = checkpoint_settings.map(|checkpoint_settings| checkpoint_settings.interval)
+                                                 .filter(|interval| !interval.is_zero());
+    let progress_interval = Some(precompute_settings.progress_interval).filter(|interval| !interval.is_zero());
+    let memory_check_interval = precompute_settings.memory_budget.map(|_| MEMORY_CHECK_INTERVAL);
+    let mut next_checkpoint_time = checkpoint_interval.map(|interval| Instant::now() + interval);
+    let mut next_progress_time = progress_interval.map(|interval| Instant::now() + interval);
+    let mut progress_tracker = ProgressTracker::new(next_work_unit_index);
+    let phase_start_time = Instant::now();
+    if precompute_settings.memory_budget.is_some() && get_resident_memory().is_none() {
+        println!("The resident memory can't be read on this system, so the memory budget is ignored");
+    }
+    let num_work_units = normal_formula_run.work_unit_vec.len();
+    while next_work_unit_index < num_work_units {
+        let memory_check_time = memory_check_interval.map(|interval| Instant::now() + interval);
+        let deadline = [next_checkpoint_time, next_progress_time, memory_check_time].iter().flatten().min().copied();
+        next_work_unit_index = normal_formula_run.generate_work_units(&mut formula_buckets, &mut precompute_report,
+                                                                      next_work_unit_index, deadline);
+        if next_work_unit_index >= num_work_units {break;}
+
+        let now = Instant::now();
+        if next_progress_time.is_some_and(|progress_time| now >= progress_time) {
+            println!("{}", progress_tracker.get_progress_text(next_work_unit_index, num_work_units, &formula_buckets,
+                                                              &precompute_report));
+            next_progress_time = progress_interval.map(|interval| now + interval);
+        }
+
+        //Check the memory.  Freed memory isn't always given back to the system, so streaming buckets only stop the
+        //run if the memory is still growing.
+        let mut is_over_budget = false;
+        let memory_check = precompute_settings.memory_budget.map(|budget| progress_tracker.check_memory(budget));
+        if let Some(MemoryCheck::OverBudget { is_growing }) = memory_check {
+            //If every formula is kept, stream instead.
+            if normal_formula_run.bucket_mode == BucketMode::Full {
+                println!("The resident memory is over the budget, so the buckets switched to -bucket stream -topk {}",
+                         DEFAULT_MAX_FORMULAS_PER_BUCKET);
+                normal_formula_run.switch_to_stream(&mut formula_buckets, DEFAULT_MAX_FORMULAS_PER_BUCKET);
+            }
+            else {
+                is_over_budget = is_growing;
+            }
+        } //End if the memory is over the budget
+
+        //Save a checkpoint when it's time, or before stopping early.
+        if next_checkpoint_time.is_some_and(|checkpoint_time| now >= checkpoint_time) || is_over_budget {
+            if let Some(checkpoint_settings) = checkpoint_settings {
+                normal_formula_run.write_checkpoint(&checkpoint_settings.filepath, &formula_buckets,
+                                                    &precompute_report, next_work_unit_index)?;
+            }
+            next_checkpoint_time = checkpoint_interval.map(|interval| now + interval);
+        }
+
+        if is_over_budget {
+            println!("{}", progress_tracker.get_progress_text(next_work_unit_index, num_work_units, &formula_buckets,
+                                                              &precompute_report));
+            precompute_report.is_complete = false;
+            precompute_report.normal_formula_duration = phase_start_time.elapsed();
+            return Ok((formula_buckets, precompute_report));
+        }
+    } //End while there are work units left
+
+    precompute_report.normal_formula_duration = phase_start_time.elapsed();
+
+    //Add the formula families after every normal formula, so a family formula is only the minimum formula of a truth
+    //table if it's strictly cheaper than the normal formulas.
+    let phase_start_time = Instant::now();
+    let tt_computer = TruthTableSize5Computer::new(n);
+    for formula_family in FormulaFamily::ALL.iter() {
+        if !precompute_settings.family_settings.is_enabled(*formula_family) {continue;}
+
+        let order = (num_work_units + 1 + formula_family.get_index()) as u32;
+        for formula in formula_family.generate_formulas(n) {
+            let truth_table = tt_computer.compute_truth_table(&formula);
+            let cost = cost_model.compute_cost(&formula, n);
+            formula_buckets[truth_table as usize].add_formula(PackedFormula::from_formula(formula), cost, order);
+            precompute_report.num_formulas_generated = precompute_report.num_formulas_generated + 1;
+        }
+    } //End for each formula family
+    precompute_report.family_duration = phase_start_time.elapsed();
+
+    //Add the formulas of every shape last.  Each level has its own order, so smaller formulas win ties.
+    let phase_start_time = Instant::now();
+    if precompute_settings.max_shape_operators > 0 {
+        let shape_enumerator = FormulaShapeEnumerator::new(n, precompute_settings.max_shape_operators);
+        for num_binary_operators in 1..=precompute_settings.max_shape_operators {
+            let order = (num_work_units + 1 + NUM_FORMULA_FAMILIES) as u32 + num_binary_operators;
+            for (formula, truth_table) in shape_enumerator.get_level(num_binary_operators) {
+                let cost = cost_model.compute_cost(formula, n);
+                formula_buckets[*truth_table as usize].add_formula(PackedFormula::from_formula(formula.clone()), cost,
+                                                                   order);
+                precompute_report.num_formulas_generated = precompute_report.num_formulas_generated + 1;
+            }
+        } //End for each number of binary operators
+    } //End if formulas of every shape are added
+    precompute_report.shape_duration = phase_start_time.elapsed();
+
+    //The run is finished, so its checkpoint can't be resumed from anymore.
+    if let Some(checkpoint_settings) = checkpoint_settings {
+        if checkpoint_settings.filepath.exists() {
+            std::fs::remove_file(&checkpoint_settings.filepath)
+                .map_err(|message| format!("Cannot remove {}: {}", checkpoint_settings.filepath.display(), message))?;
+        }
+    } //End if there are checkpoint settings
+
+    Ok((formula_buckets, precompute_report))
+} //End generate_truth_tables_with_up_to_n_variables
 
 //PRIVATE//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+const BYTES_PER_MEGABYTE : u64 = 1024 * 1024;
+#[allow(dead_code)]
 const BOOLEAN_NAME_ARRAY : [&str;5] = ["p1", "p2", "p3", "p4", "p5"];
 const NONE_TEXT : &str = "NONE";
 
-///This struct generates Vecs that have all the assorted combinations of positive or negative flags.
-///The results are Vec<SimpleLogicNode>.
-struct AssignFlagsIterator {
-    boolean_indexes_vec : Vec<u32>,
-    configuration : usize,
-    num_configurations : usize
-} //End struct AssignFlagsIterator
-
-impl AssignFlagsIterator {
-    ///Creates a new AssignSignsIterator.
-    ///boolean_indexes_vec is the vector of boolean indexes to generate positive/negative flags for
-    fn new(boolean_indexes_vec : Vec<u32>) -> AssignFlagsIterator {
-        let num_booleans = (&boolean_indexes_vec).len() as u32;
-        AssignFlagsIterator {
-            boolean_indexes_vec : boolean_indexes_vec,
-            configuration : 0,
-            num_configurations : (compute_two_to_n(num_booleans)) as usize
+const DEFAULT_PRUNING_RULES_TEXT : &str = "tautological,duplicate,opposite,subsumption";
+const PRUNING_RULES_NONE_TEXT : &str = "none";
+
+const FORMULA_FAMILIES_NONE_TEXT : &str = "none";
+const FORMULA_FAMILIES_ALL_TEXT : &str = "all";
+
+const CHECKPOINT_MAGIC : &[u8] = b"RLTCP";
+const CHECKPOINT_VERSION : u8 = 5;
+const CHECKPOINT_TEMPORARY_EXTENSION : &str = "tmp";
+
+const MEMORY_CHECK_INTERVAL : Duration = Duration::from_secs(1);
+
+//Gets every set of at least min_size booleans from 1 to n.  The booleans of each set are in increasing order.
+fn get_boolean_subsets(n : u32, min_size : u32) -> Vec<Vec<u32>> {
+    let mut boolean_subset_vec = Vec::new();
+    for boolean_mask in 1..compute_two_to_n(n) {
+        if boolean_mask.count_ones() < min_size {continue;}
+        boolean_subset_vec.push((1..=n).filter(|boolean_index| boolean_mask & (1 << (boolean_index - 1)) != 0).collect());
+    } //End for each set of booleans
+
+    boolean_subset_vec
+} //End get_boolean_subsets
+
+//Creates an empty bucket for each truth table.
+fn create_formula_buckets(num_truth_tables : usize, bucket_mode : BucketMode) -> Vec<LogicFormulaBucket> {
+    let mut formula_buckets = Vec::with_capacity(num_truth_tables);
+    for _i in 0..num_truth_tables {
+        formula_buckets.push(LogicFormulaBucket::new(bucket_mode));
+    } //End for each bucket to add
+
+    formula_buckets
+} //End create_formula_buckets
+
+fn create_literal_nodes(literal_vec : &Vec<u32>) -> Vec<SimpleLogicNode> {
+    literal_vec.iter().map(|literal| SimpleLogicNode::Literal(*literal)).collect()
+} //End create_literal_nodes
+
+//This struct stores everything about a run of the NormalFormulaGenerators except for the buckets, so the run can be
+//split between threads and checkpoints.
+struct NormalFormulaRun<'a> {
+    literal_configurations : &'a Vec<ClauseMask>,
+    work_unit_vec : Vec<NormalFormulaWorkUnit>,
+    n : u32,
+    cost_model : FormulaCostModel,
+    bucket_mode : BucketMode,
+    pruning_settings : PruningSettings,
+    verify_duality : bool,
+    num_threads : usize,
+    shard_vec : Vec<Vec<LogicFormulaBucket>>  //The buckets of each thread, which are empty between calls
+} //End struct NormalFormulaRun
+
+impl<'a> NormalFormulaRun<'a> {
+    //Creates a run of every work unit over the clauses in literal_configurations.
+    fn new(literal_configurations : &'a Vec<ClauseMask>, n : u32, precompute_settings : &PrecomputeSettings)
+        -> NormalFormulaRun<'a>
+    {
+        NormalFormulaRun {
+            literal_configurations : literal_configurations,
+            work_unit_vec : NormalFormulaGenerator::get_work_units(literal_configurations.len()),
+            n : n,
+            cost_model : precompute_settings.cost_model,
+            bucket_mode : precompute_settings.bucket_mode,
+            pruning_settings : precompute_settings.pruning_settings,
+            verify_duality : precompute_settings.verify_duality,
+            num_threads : precompute_settings.num_threads.max(1),
+            shard_vec : Vec::new()
         }
     } //End new
-} //End impl AssignFlagsIterator
 
-impl Iterator for AssignFlagsIterator {
-    type Item = Vec<u32>;
+    //Generates work units on every thread, starting from first_work_unit_index, until there are none left or the
+    //deadline passes.  The threads take turns claiming work units, and each one fills its own shard of buckets.  The
+    //shards are merged into formula_buckets, and the counts are added to precompute_report.  The shards are emptied
+    //by the merge and kept for the next call, so they're only allocated once.
+    //Return value: the index of the first work unit that isn't done
+    fn generate_work_units(&mut self, formula_buckets : &mut Vec<LogicFormulaBucket>,
+                           precompute_report : &mut PrecomputeReport, first_work_unit_index : usize,
+                           deadline : Option<Instant>) -> usize
+    {
+        if self.shard_vec.is_empty() {
+            self.shard_vec = (0..self.num_threads)
+                .map(|_| create_formula_buckets(formula_buckets.len(), self.bucket_mode)).collect();
+        }
+        let shard_vec = std::mem::take(&mut self.shard_vec);
 
-    ///AssignFlagsIterator.next to implement Iterator.next
-    fn next(&mut self) -> Option<Self::Item> {
-        //If the AssignSignsIterator is out of configurations
-        if self.configuration >= self.num_configurations {
-            return None;
-        } //End if the AssignSignsIterator is out of configurations
+        let next_work_unit_index = AtomicUsize::new(first_work_unit_index);
+        let run = &*self;
+        let generated_shard_vec : Vec<(Vec<LogicFormulaBucket>, PrecomputeReport)> = thread::scope(|scope| {
+            let mut handle_vec = Vec::with_capacity(run.num_threads);
+            for shard_buckets in shard_vec {
+                let next_work_unit_index = &next_work_unit_index;
+                handle_vec.push(scope.spawn(move || {
+                    let mut nf_generator = NormalFormulaGenerator::new(shard_buckets,
+                                                                       run.literal_configurations.clone(), run.n,
+                                                                       run.cost_model, run.pruning_settings,
+                                                                       run.verify_duality);
+                    nf_generator.generate_work_units(&run.work_unit_vec, next_work_unit_index, deadline);
+                    (nf_generator.formula_buckets, nf_generator.precompute_report)
+                }));
+            } //End for each thread
 
-        //FIX ALL OF THIS
-        //Create a Vec for the configuration of literals.
-        let mut boolean_indexes_with_signs_vec :Vec<u32> = Vec::with_capacity(self.boolean_indexes_vec.len());
+            handle_vec.into_iter().map(|handle| handle.join().expect("a formula generating thread panicked")).collect()
+        });
 
-        //The bitmask is used to check whether a specific boolean index must be positive or negative.
-        //0 = negative, 1 = positive
-        let mut bitmask :usize = 1;
-        for index_index in 0..self.boolean_indexes_vec.len() {
-            //Figure out the literal.
-            let literal =
-                if self.configuration & bitmask == 0 {self.boolean_indexes_vec[index_index] | NEGATIVITY_FLAG}
-                else {self.boolean_indexes_vec[index_index]};
+        //Merge the shards.  Every formula remembers which work unit generated it, so the result doesn't depend on the
+        //number of threads or on which thread did which unit.
+        for (mut shard_buckets, shard_report) in generated_shard_vec {
+            for (formula_bucket, shard_bucket) in formula_buckets.iter_mut().zip(shard_buckets.iter_mut()) {
+                formula_bucket.merge_from(std::mem::replace(shard_bucket, LogicFormulaBucket::new(self.bucket_mode)));
+            } //End for each bucket
+            precompute_report.add(&shard_report);
+            self.shard_vec.push(shard_buckets);
+        } //End for each shard
 
-            //Add the literal to the vec (as a SimpleLogicNode).
-            boolean_indexes_with_signs_vec.push(literal);
+        next_work_unit_index.load(Ordering::Relaxed).min(self.work_unit_vec.len())
+    } //End generate_work_units
 
-            //Update the bitmask
-            bitmask = bitmask << 1;
-        } //End for each boolean index
+    //Switches the buckets and the empty shards from keeping every formula to streaming.
+    fn switch_to_stream(&mut self, formula_buckets : &mut Vec<LogicFormulaBucket>, max_formulas : usize) {
+        self.bucket_mode = BucketMode::Stream(max_formulas);
+        for formula_bucket in formula_buckets.iter_mut().chain(self.shard_vec.iter_mut().flatten()) {
+            formula_bucket.switch_to_stream(max_formulas, self.cost_model, self.n);
+        }
+    } //End switch_to_stream
 
-        //Increment
-        self.configuration = self.configuration + 1;
+    //Saves the buckets, the report and the index of the first work unit that isn't done.  The checkpoint is written
+    //to a temporary file first, so an interrupted write never replaces the last good checkpoint.
+    fn write_checkpoint(&self, filepath : &PathBuf, formula_buckets : &Vec<LogicFormulaBucket>,
+                        precompute_report : &PrecomputeReport, next_work_unit_index : usize) -> Result<(), String>
+    {
+        let mut byte_vec = self.get_checkpoint_header();
+        byte_vec.extend_from_slice(&(next_work_unit_index as u64).to_le_bytes());
+        for num_cuts in &precompute_report.num_cuts_array {
+            byte_vec.extend_from_slice(&num_cuts.to_le_bytes());
+        }
+        byte_vec.extend_from_slice(&precompute_report.num_duality_checks.to_le_bytes());
+        byte_vec.extend_from_slice(&precompute_report.num_duality_failures.to_le_bytes());
+        byte_vec.extend_from_slice(&precompute_report.num_formulas_generated.to_le_bytes());
+        for formula_bucket in formula_buckets {
+            formula_bucket.add_checkpoint_bytes(&mut byte_vec);
+        }
+
+        let temporary_filepath = filepath.with_extension(CHECKPOINT_TEMPORARY_EXTENSION);
+        std::fs::write(&temporary_filepath, &byte_vec)
+            .map_err(|message| format!("Cannot write {}: {}", temporary_filepath.display(), message))?;
+        std::fs::rename(&temporary_filepath, filepath)
+            .map_err(|message| format!("Cannot write {}: {}", filepath.display(), message))
+    } //End write_checkpoint
 
-        Some(boolean_indexes_with_signs_vec)
-    } //End next
-} //End impl Iterator for AssignFlagsIterator
+    //Loads the buckets, the report and the index of the first work unit that isn't done from a checkpoint of this run.
+    //Return value: the buckets, the report and the index, or an error message if the checkpoint can't be read or is
+    //from a run with different settings
+    fn read_checkpoint(&self, filepath : &PathBuf)
+        -> Result<(Vec<LogicFormulaBucket>, PrecomputeReport, usize), String>
+    {
+        let byte_vec = std::fs::read(filepath)
+            .map_err(|message| format!("Cannot resume from {}: {}", filepath.display(), message))?;
+
+        let header = self.get_checkpoint_header();
+        if byte_vec.len() < header.len() || byte_vec[..header.len()] != header[..] {
+            return Err(format!("{} isn't a checkpoint of a run with these settings", filepath.display()));
+        }
+
+        let mut reader = FormulaByteReader::new(&byte_vec, header.len());
+        let next_work_unit_index = reader.read_u64()? as usize;
+        if next_work_unit_index > self.work_unit_vec.len() {
+            return Err(format!("Invalid work unit {} in checkpoint", next_work_unit_index));
+        }
+
+        let mut precompute_report = PrecomputeReport::new();
+        for num_cuts in precompute_report.num_cuts_array.iter_mut() {
+            *num_cuts = reader.read_u64()?;
+        }
+        precompute_report.num_duality_checks = reader.read_u64()?;
+        precompute_report.num_duality_failures = reader.read_u64()?;
+        precompute_report.num_formulas_generated = reader.read_u64()?;
+
+        let num_truth_tables = compute_two_to_two_to_n(self.n) as usize;
+        let mut formula_buckets = Vec::with_capacity(num_truth_tables);
+        for _i in 0..num_truth_tables {
+            formula_buckets.push(LogicFormulaBucket::read_checkpoint_bytes(&mut reader, self.bucket_mode, self.n)?);
+        }
+
+        Ok((formula_buckets, precompute_report, next_work_unit_index))
+    } //End read_checkpoint
+
+    //Gets the start of a checkpoint, which identifies the settings of the run.  A checkpoint can only be resumed by
+    //a run with the same settings.  The number of threads doesn't matter.
+    fn get_checkpoint_header(&self) -> Vec<u8> {
+        let mut byte_vec = Vec::new();
+        byte_vec.extend_from_slice(CHECKPOINT_MAGIC);
+        byte_vec.push(CHECKPOINT_VERSION);
+        byte_vec.push(self.n as u8);
+        byte_vec.push(get_cost_model_code(self.cost_model));
+        let max_formulas_per_bucket =
+            match self.bucket_mode {
+                BucketMode::Full => 0,
+                BucketMode::Stream(max_formulas) => max_formulas as u64 + 1
+            };
+        byte_vec.extend_from_slice(&max_formulas_per_bucket.to_le_bytes());
+        byte_vec.extend_from_slice(&(self.work_unit_vec.len() as u64).to_le_bytes());
+        for pruning_rule in PruningRule::ALL.iter() {
+            byte_vec.push(self.pruning_settings.is_enabled(*pruning_rule) as u8);
+        }
+        byte_vec.push(self.verify_duality as u8);
+        byte_vec
+    } //End get_checkpoint_header
+} //End impl NormalFormulaRun
+
+//The result of checking the resident memory against the budget.
+enum MemoryCheck {
+    UnderBudget,
+    OverBudget {is_growing : bool}  //is_growing is true if the memory grew since the last check
+} //End enum MemoryCheck
+
+//This struct keeps track of a run's progress to estimate the time left and to see whether the memory is growing.
+struct ProgressTracker {
+    start_time : Instant,
+    first_work_unit_index : usize,        //The first work unit of this run, which is after the checkpoint if resuming
+    last_resident_memory : Option<u64>    //The resident memory at the last check
+} //End struct ProgressTracker
+
+impl ProgressTracker {
+    fn new(first_work_unit_index : usize) -> ProgressTracker {
+        ProgressTracker {
+            start_time : Instant::now(),
+            first_work_unit_index : first_work_unit_index,
+            last_resident_memory : None
+        }
+    } //End new
+
+    //Gets a line with the number of work units done, the number of formulas generated, the number of truth tables with
+    //a formula, the estimated time left and the resident memory.  Each work unit is a prefix of clauses.
+    fn get_progress_text(&self, next_work_unit_index : usize, num_work_units : usize,
+                         formula_buckets : &Vec<LogicFormulaBucket>, precompute_report : &PrecomputeReport) -> String
+    {
+        let num_filled_buckets =
+            formula_buckets.iter().filter(|formula_bucket| formula_bucket.get_minimum_cost().is_some()).count();
+
+        //Assume the rest of the work units take as long as the ones done so far.
+        let num_done_this_run = next_work_unit_index - self.first_work_unit_index;
+        let eta_text =
+            if num_done_this_run == 0 {"unknown".to_string()}
+            else {
+                let seconds_per_work_unit = self.start_time.elapsed().as_secs_f64() / num_done_this_run as f64;
+                let seconds_left = (seconds_per_work_unit * (num_work_units - next_work_unit_index) as f64) as u64;
+                format!("{}:{:02}:{:02}", seconds_left / 3600, seconds_left / 60 % 60, seconds_left % 60)
+            };
+        let memory_text =
+            match get_resident_memory() {
+                Some(resident_memory) => format!("{} MB", resident_memory / BYTES_PER_MEGABYTE),
+                None => "unknown".to_string()
+            };
+
+        format!("Progress: {} of {} prefixes ({:.1}%), {} formulas, {} of {} truth tables found, ETA {}, memory {}",
+                next_work_unit_index, num_work_units, 100.0 * next_work_unit_index as f64 / num_work_units as f64,
+                precompute_report.num_formulas_generated, num_filled_buckets, formula_buckets.len(), eta_text,
+                memory_text)
+    } //End get_progress_text
+
+    //Checks the resident memory against a budget in bytes.  If the memory can't be read, it's under the budget.
+    fn check_memory(&mut self, memory_budget : u64) -> MemoryCheck {
+        let resident_memory =
+            match get_resident_memory() {
+                Some(resident_memory) => resident_memory,
+                None => return MemoryCheck::UnderBudget
+            };
+        let is_growing = self.last_resident_memory.is_none_or(|last_memory| resident_memory > last_memory);
+        self.last_resident_memory = Some(resident_memory);
+
+        if resident_memory <= memory_budget {MemoryCheck::UnderBudget}
+        else {MemoryCheck::OverBudget {is_growing : is_growing}}
+    } //End check_memory
+} //End impl ProgressTracker
+
+//Gets the resident memory of this process in bytes, or None if it can't be read.  It's read from /proc, so it only
+//works on Linux.
+fn get_resident_memory() -> Option<u64> {
+    let status_text = std::fs::read_to_string("/proc/self/status").ok()?;
+    let resident_line = status_text.lines().find(|line| line.starts_with("VmRSS:"))?;
+
+    //The line looks like "VmRSS:     1234 kB".
+    let kilobytes = resident_line.split_whitespace().nth(1)?.parse::<u64>().ok()?;
+    Some(kilobytes * 1024)
+} //End get_resident_memory
+
+//The formulas are generated in work units so they can be split across threads and checkpoints.  A work unit with
+//fewer than WORK_UNIT_DEPTH clauses is the formula with exactly those clauses.  A work unit with WORK_UNIT_DEPTH
+//clauses is every formula that starts with them.
+#[derive(Clone)]
+struct NormalFormulaWorkUnit {
+    clause_index_vec : Vec<usize>  //Indexes into literal_configurations
+} //End struct NormalFormulaWorkUnit
+
+//The subtrees of the recursion are very uneven, so the work units go a few clauses deep to keep them small.
+const WORK_UNIT_DEPTH : usize = 3;
+
+//Some clauses, along with the truth table of their DNF formula.  The truth table is extended one clause at a time, so
+//no formula's truth table is computed from scratch.  The CNF formula with the same clauses is the dual of the DNF
+//formula, so its truth table is derived from the DNF formula's.
+#[derive(Clone)]
+struct ClausePrefix {
+    clause_vec : Vec<ClauseMask>,
+    dnf_truth_table : u32  //The disjunction of the clauses' conjunction truth tables
+} //End struct ClausePrefix
+
+impl ClausePrefix {
+    fn new() -> ClausePrefix {
+        ClausePrefix {
+            clause_vec : Vec::new(),
+            dnf_truth_table : 0
+        }
+    } //End new
+} //End impl ClausePrefix
 
 struct NormalFormulaGenerator {
     formula_buckets : Vec<LogicFormulaBucket>,  //Stores the final results
-    literal_configurations: Vec<Vec<u32>>,      //The different possible configurations of literals
-    tt_computer : TruthTableSize5Computer,      //Computes the truth tables for the formula buckets
+    literal_configurations: Vec<ClauseMask>,    //The different possible configurations of literals
+    conjunction_truth_tables : Vec<u32>,        //The truth table of each configuration's literals joined by &
+    tt_computer : TruthTableSize5Computer,      //Checks the derived CNF truth tables
+    cost_model : FormulaCostModel,              //Chooses the minimum formula of each bucket
+    n : u32,
+    current_order : u32,                        //The order of the formulas being generated, from the work unit
+    pruning_settings : PruningSettings,         //Which clauses are skipped
+    verify_duality : bool,                      //Whether the derived CNF truth tables are checked
+    precompute_report : PrecomputeReport        //How many clauses each pruning rule cut
 } //End struct NormalFormulaGenerator
 
 impl NormalFormulaGenerator {
-    fn new(formula_buckets : Vec<LogicFormulaBucket>, literal_configurations : Vec<Vec<u32>>, n : u32)
+    fn new(formula_buckets : Vec<LogicFormulaBucket>, literal_configurations : Vec<ClauseMask>, n : u32,
+           cost_model : FormulaCostModel, pruning_settings : PruningSettings, verify_duality : bool)
         -> NormalFormulaGenerator
     {
+        //Compute the truth tables of the clauses once, up front.
         let tt_computer = TruthTableSize5Computer::new(n);
+        let mut conjunction_truth_tables = Vec::with_capacity(literal_configurations.len());
+        for clause_mask in &literal_configurations {
+            let mut conjunction_truth_table = u32::MAX;
+            for literal in get_clause_literals(*clause_mask) {
+                conjunction_truth_table = conjunction_truth_table & tt_computer.compute_literal_truth_table(literal);
+            }
+            conjunction_truth_tables.push(conjunction_truth_table);
+        } //End for each literal configuration
+
         NormalFormulaGenerator {
             formula_buckets : formula_buckets,
             literal_configurations: literal_configurations,
+            conjunction_truth_tables : conjunction_truth_tables,
             tt_computer : tt_computer,
+            cost_model : cost_model,
+            n : n,
+            current_order : 0,
+            pruning_settings : pruning_settings,
+            verify_duality : verify_duality,
+            precompute_report : PrecomputeReport::new()
         }
     } //End new
 
-    fn add_formula_to_buckets(&mut self, formula : SimpleLogicNode) {
-        let truth_table = self.tt_computer.compute_truth_table(&formula);
-        let mut formula_bucket = &mut self.formula_buckets[truth_table as usize];
+    //truth_table is the truth table of the formula, which the caller already knows
+    fn add_formula_to_buckets(&mut self, formula : PackedFormula, truth_table : u32) {
+        let cost = formula.compute_cost(self.cost_model, self.n);
+        let formula_bucket = &mut self.formula_buckets[truth_table as usize];
 
-        formula_bucket.add_formula(formula);
+        formula_bucket.add_formula(formula, cost, self.current_order);
+        self.precompute_report.num_formulas_generated = self.precompute_report.num_formulas_generated + 1;
     } //End add_formula_to_buckets
 
-    fn generate_all_normal_formulas(&mut self) {
-        //Actually generate the formulas.
-        let num_literal_configurations = self.literal_configurations.len();
-        for i in 0..num_literal_configurations {
-            let mut clause_builder = Vec::new();
-            clause_builder.push(self.literal_configurations[i].clone());
-            self.generate_all_normal_formulas_with_prefix(&mut Vec::new(), i);
-        } //End for each literal configuration
-    } //End generate_all_normal_formulas
+    //Splits the formulas into work units.  Generating the work units in order generates every formula in the same
+    //order as one recursion over all the literal configurations.
+    fn get_work_units(num_literal_configurations : usize) -> Vec<NormalFormulaWorkUnit> {
+        let mut work_unit_vec = Vec::new();
+        NormalFormulaGenerator::add_work_units(&mut work_unit_vec, &mut Vec::new(), num_literal_configurations);
+        work_unit_vec
+    } //End get_work_units
 
-    fn generate_all_normal_formulas_with_prefix(&mut self, prefix_clauses : &mut Vec<Vec<u32>>,
-                                                clause_to_add_index : usize)
+    //Adds the work units that start with some clauses, in the order the recursion generates them.
+    fn add_work_units(work_unit_vec : &mut Vec<NormalFormulaWorkUnit>, clause_index_vec : &mut Vec<usize>,
+                      num_literal_configurations : usize)
     {
-        let new_clause = self.literal_configurations[clause_to_add_index].clone();
-        let mut current_clauses = prefix_clauses.clone();
+        let first_clause_index = clause_index_vec.last().map_or(0, |clause_index| clause_index + 1);
+        for clause_index in first_clause_index..num_literal_configurations {
+            clause_index_vec.push(clause_index);
+            work_unit_vec.push(NormalFormulaWorkUnit {clause_index_vec : clause_index_vec.clone()});
+            if clause_index_vec.len() < WORK_UNIT_DEPTH {
+                NormalFormulaGenerator::add_work_units(work_unit_vec, clause_index_vec, num_literal_configurations);
+            }
+            clause_index_vec.pop();
+        } //End for each next clause
+    } //End add_work_units
 
-        //Check for unit clauses that are the opposite of other unit clauses.
-        //Note: If the new clause is a unit, then the current clause will also be a unit because the arrays are
-        //created in order of increasing size.
-        if new_clause.len() == 1 {
-            for current_clause in &current_clauses {
-                //If the new clause is the opposite of another clause, don't generate any formulas with this prefix.
-                //It would be a tautology or contradiction anyways.
-                if current_clause[0] ^ new_clause[0] == NEGATIVITY_FLAG {return; }
-            } //End for each prefix clause
-        } //End if the new clause is a unit clause and the opposite of the current clause
-        //Check whether the new clause is subsumed by one of the existing clauses.
-        else {
-            for current_clause in &current_clauses {
-                //If the new clause is subsumed, don't generate any formulas with this prefix.
-                if NormalFormulaGenerator::is_subarray_of(current_clause, &new_clause) { return; }
-            } //End for each prefix clause
-        } //End else the new clause isn't a unit clause
+    //Generates work units until there are none left or the deadline passes.  Other threads can be generating work
+    //units at the same time.  A work unit that's started is always finished, so when every thread stops, exactly the
+    //work units before next_work_unit_index are done.
+    //next_work_unit_index is the index of the next work unit that no thread has claimed
+    fn generate_work_units(&mut self, work_unit_vec : &Vec<NormalFormulaWorkUnit>,
+                           next_work_unit_index : &AtomicUsize, deadline : Option<Instant>)
+    {
+        loop {
+            if deadline.is_some_and(|deadline| Instant::now() >= deadline) {break;}
+            let work_unit_index = next_work_unit_index.fetch_add(1, Ordering::Relaxed);
+            if work_unit_index >= work_unit_vec.len() {break;}
 
-        //Add the clause to add.
-        current_clauses.push(new_clause);
-
-        //Turn all the clauses into conjunctions.
-        let mut current_conjunctions: Vec<SimpleLogicNode> = Vec::with_capacity(current_clauses.len());
-        for clause_integers_vec in &current_clauses {
-            let mut clause_literals_vec = Vec::with_capacity(clause_integers_vec.len());
-            for integer in clause_integers_vec {
-                clause_literals_vec.push(SimpleLogicNode::Literal(*integer));
-            } //End for each integer in the clause
-
-            //If there is only 1 literal in clause, don't even wrap it in a conjunction
-            if clause_literals_vec.len() == 1 {
-                current_conjunctions.push(clause_literals_vec[0].clone());
-            } //End if there is only 1 literal in clause, don't even wrap in a conjunction
-            //Else there's multiple literals, so wrap the clause in a conjunction
-            else {
-                current_conjunctions.push(SimpleLogicNode::Conjunction(clause_literals_vec));
-            } //End else there's multiple literals
-        } //End for each current clause
+            //Order 0 is used for the constants.
+            self.current_order = (work_unit_index + 1) as u32;
+            self.generate_work_unit(&work_unit_vec[work_unit_index]);
+        } //End loop over the work units
+    } //End generate_work_units
 
-        //If there's only 1 clause
-        if current_conjunctions.len() == 1 {
-            //Get the formula.
-            let single_conjunction = current_conjunctions.pop().unwrap();
+    fn generate_work_unit(&mut self, work_unit : &NormalFormulaWorkUnit) {
+        let (last_clause_index, prefix_clause_indexes) =
+            work_unit.clause_index_vec.split_last().expect("a work unit has at least one clause");
 
-            //Only add the formula to the buckets if it's a literal.
-            //Other single clause conjunctions will be added as the CNF version of a DNF.
-            match single_conjunction {
-                SimpleLogicNode::Literal(_) => {
-                    self.add_formula_to_buckets(single_conjunction);
-                },
-                _ => ()
-            };
-        } //End if there's only 1 clause
-        //Else there's more than 1 clause
+        //Add the clauses before the last one, unless one of them is cut.  Those cuts are counted by the shorter work
+        //units, so they aren't counted again.
+        let mut prefix_clauses = ClausePrefix::new();
+        for prefix_clause_index in prefix_clause_indexes {
+            prefix_clauses =
+                match self.add_clause_to_prefix(&prefix_clauses, *prefix_clause_index) {
+                    Ok(current_clauses) => current_clauses,
+                    Err(_) => return
+                };
+        } //End for each clause before the last one
+
+        if work_unit.clause_index_vec.len() == WORK_UNIT_DEPTH {
+            self.generate_all_normal_formulas_with_prefix(&mut prefix_clauses, *last_clause_index);
+        }
         else {
-            //Create the disjunction.
-            let dnf_formula = SimpleLogicNode::Disjunction(current_conjunctions);
+            match self.add_clause_to_prefix(&prefix_clauses, *last_clause_index) {
+                Ok(current_clauses) => self.add_formulas_for_clauses(&current_clauses),
+                Err(pruning_rule) => self.count_cut(pruning_rule)
+            };
+        }
+    } //End generate_work_unit
 
-            //Add CNF and DNF formulas.
-            self.add_formula_to_buckets(NormalFormulaGenerator::generate_cnf_from_dnf(&dnf_formula));
-            self.add_formula_to_buckets(dnf_formula);
-        } //End else there's more than 1 clause
+    fn generate_all_normal_formulas_with_prefix(&mut self, prefix_clauses : &mut ClausePrefix,
+                                                clause_to_add_index : usize)
+    {
+        let mut current_clauses = match self.add_clause_to_prefix(prefix_clauses, clause_to_add_index) {
+            Ok(current_clauses) => current_clauses,
+            Err(pruning_rule) => {
+                self.count_cut(pruning_rule);
+                return;
+            }
+        };
+        self.add_formulas_for_clauses(&current_clauses);
 
         //Add subsequent clauses.
         let num_literal_configurations = self.literal_configurations.len();
@@ -360,73 +1375,59 @@ impl NormalFormulaGenerator {
         } //End for each possible next clause
     } //End generate_all_normal_formulas_with_prefix
 
-    //Generates a CNF formula with the same literals as a DNF formula.  They are not in any way equivalent.
-    //dnf_formula is the DNF formula to generate the CNF formula from
-    //Returns the CNF formula generated.
-    fn generate_cnf_from_dnf(dnf_formula : &SimpleLogicNode) -> SimpleLogicNode {
-        match dnf_formula {
-            SimpleLogicNode::False => {
-                panic!("NormalFormulaGenerator.generate_cnf_from_dnf should never see SimpleLogicNode::False")
-            },
-            SimpleLogicNode::True => {
-                panic!("NormalFormulaGenerator.generate_cnf_from_dnf should never see SimpleLogicNode::True")
-            },
-            SimpleLogicNode::Literal(_) => dnf_formula.to_owned(),
-            SimpleLogicNode::Conjunction(operands) => {
-                let mut flipped_operands = Vec::with_capacity(operands.len());
-                for operand in operands {
-                    flipped_operands.push(NormalFormulaGenerator::generate_cnf_from_dnf(operand));
-                }
-                SimpleLogicNode::Disjunction(flipped_operands)
-            },
-            SimpleLogicNode::Disjunction(operands) => {
-                let mut flipped_operands = Vec::with_capacity(operands.len());
-                for operand in operands {
-                    flipped_operands.push(NormalFormulaGenerator::generate_cnf_from_dnf(operand));
+    //Adds a clause to a prefix of clauses, unless a pruning rule cuts the new clause.  The truth table of the prefix is
+    //extended with the cached truth table of the new clause.
+    //Returns the new clauses, or the rule that cut the new clause if no formulas should be generated with them.
+    fn add_clause_to_prefix(&self, prefix_clauses : &ClausePrefix, clause_to_add_index : usize)
+        -> Result<ClausePrefix, PruningRule>
+    {
+        let new_clause = self.literal_configurations[clause_to_add_index];
+        if let Some(pruning_rule) = self.pruning_settings.find_cutting_rule(&prefix_clauses.clause_vec, new_clause) {
+            return Err(pruning_rule);
+        }
+
+        //Add the clause to add.
+        let mut current_clauses = prefix_clauses.clone();
+        current_clauses.clause_vec.push(new_clause);
+        current_clauses.dnf_truth_table =
+            current_clauses.dnf_truth_table | self.conjunction_truth_tables[clause_to_add_index];
+        Ok(current_clauses)
+    } //End add_clause_to_prefix
+
+    fn count_cut(&mut self, pruning_rule : PruningRule) {
+        let rule_index = pruning_rule.get_index();
+        self.precompute_report.num_cuts_array[rule_index] = self.precompute_report.num_cuts_array[rule_index] + 1;
+    } //End count_cut
+
+    //Adds the DNF formula of some clauses and its CNF version to the buckets.
+    fn add_formulas_for_clauses(&mut self, current_clauses : &ClausePrefix) {
+        let clause_vec = &current_clauses.clause_vec;
+
+        //If there's only 1 clause
+        if clause_vec.len() == 1 {
+            //Only add the formula to the buckets if it's a literal.
+            //Other single clause conjunctions will be added as the CNF version of a DNF.
+            if clause_vec[0].count_ones() == 1 {
+                self.add_formula_to_buckets(PackedFormula::new_dnf(clause_vec), current_clauses.dnf_truth_table);
+            }
+        } //End if there's only 1 clause
+        //Else there's more than 1 clause
+        else {
+            //Add CNF and DNF formulas.  They have the same literals, but they are not in any way equivalent.  The CNF
+            //formula is the dual of the DNF formula, so its truth table doesn't have to be computed.
+            let cnf_formula = PackedFormula::new_cnf(clause_vec);
+            let cnf_truth_table = compute_dual_truth_table(current_clauses.dnf_truth_table, self.n);
+            if self.verify_duality {
+                self.precompute_report.num_duality_checks = self.precompute_report.num_duality_checks + 1;
+                if cnf_formula.compute_truth_table(&self.tt_computer) != cnf_truth_table {
+                    self.precompute_report.num_duality_failures = self.precompute_report.num_duality_failures + 1;
                 }
-                SimpleLogicNode::Conjunction(flipped_operands)
-            },
-        } //End match dnf_formula
-    } //End generate_cnf_from_dnf
-
-    //This method determines whether the old array is a subarray of the new array.  However, it doesn't work on
-    //arbitrary arrays.  The arrays must be sorted in increasing order.
-    //old_array is the array that might be a subarray
-    //new_array is the array that might be a superarray
-    //It checks whether old_array is actually a subarray of new_array.
-    fn is_subarray_of(old_array: &Vec<u32>, new_array: &Vec<u32>) -> bool {
-        let old_array_length = old_array.len();
-        let new_array_length = new_array.len();
-
-        //If the arrays are the same size, it won't be a subarray (because all the arrays are different).
-        if old_array_length == new_array_length {return false;}
-
-        //The arrays are different sizes, so old_array might be a subarray of new_array.
-
-        //Compare elements.
-        //i is the index of old_array
-        //j is the index of new_array
-        let mut j: usize = 0;
-        for i in 0..old_array_length {
-            let without_flag = old_array[i] & VARIABLE_INDEX_MASK;
-            while without_flag > new_array[j] & VARIABLE_INDEX_MASK {
-                j = j + 1;
-
-                //If we're at the end of superarray, it's not subsumed
-                if j == new_array_length {return false}
-            } //End while the current boolean of vec1 is more than the current boolean of vec2
-
-            //if the booleans aren't identical
-            if old_array[i] != new_array[j] {
-                //In this case, without_flag < than the current boolean in vec2.
-                //This means that subarray is not a subarray of superarray because without_flag isn't in it.
-                return false;
-            } //End else if it's a completely different boolean
-        } //End for each Vector that might be subsumed
-
-        //The old array must be a subarray of the new array if the code is here.
-        return true;
-    } //End is_subarray_of
+            } //End if the duality is verified
+
+            self.add_formula_to_buckets(cnf_formula, cnf_truth_table);
+            self.add_formula_to_buckets(PackedFormula::new_dnf(clause_vec), current_clauses.dnf_truth_table);
+        } //End else there's more than 1 clause
+    } //End add_formulas_for_clauses
 } //End impl NormalFormulaGenerator
 
 //Computes 2 to the power of n
@@ -434,5 +1435,170 @@ impl NormalFormulaGenerator {
 fn compute_two_to_n(n : u32) -> u32 {1 << n}
 
 //Computes 2 to the power of 2 to the n.  This is 2^(2^n), not (2^2)^n.
-//n is the power of 2 to raise 2 to
+//n is the power of 2 to raise 2 to.  It must be at most 4, or the result doesn't fit.
 fn compute_two_to_two_to_n(n : u32) -> u32 {1 << (1 << n)}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //Creates the default settings, with num_threads threads and the given bucket mode.
+    fn create_settings(bucket_mode : BucketMode, num_threads : usize) -> PrecomputeSettings {
+        PrecomputeSettings {
+            cost_model : FormulaCostModel::BinaryOperators,
+            bucket_mode : bucket_mode,
+            num_threads : num_threads,
+            pruning_settings : PruningSettings::new(),
+            family_settings : FormulaFamilySettings::new(),
+            max_shape_operators : 0,
+            verify_duality : false,
+            progress_interval : Duration::ZERO,
+            memory_budget : None
+        }
+    } //End create_settings
+
+    //Asserts that two runs kept the same formulas in every bucket and counted the same things.  The durations aren't
+    //compared.
+    fn assert_same_run(expected : &(Vec<LogicFormulaBucket>, PrecomputeReport),
+                       actual : &(Vec<LogicFormulaBucket>, PrecomputeReport)) {
+        assert_eq!(expected.0.len(), actual.0.len());
+        for (truth_table, (expected_bucket, actual_bucket)) in expected.0.iter().zip(actual.0.iter()).enumerate() {
+            assert_eq!(expected_bucket.get_num_formulas(), actual_bucket.get_num_formulas(), "tt {}", truth_table);
+            assert_eq!(expected_bucket.get_minimum_cost(), actual_bucket.get_minimum_cost(), "tt {}", truth_table);
+            assert!(expected_bucket.get_minimum_formula() == actual_bucket.get_minimum_formula(), "tt {}",
+                    truth_table);
+            assert!(expected_bucket.get_formula_vector() == actual_bucket.get_formula_vector(), "tt {}", truth_table);
+        } //End for each bucket
+
+        assert_eq!(expected.1.num_cuts_array, actual.1.num_cuts_array);
+        assert_eq!(expected.1.num_duality_checks, actual.1.num_duality_checks);
+        assert_eq!(expected.1.num_duality_failures, actual.1.num_duality_failures);
+        assert_eq!(expected.1.num_formulas_generated, actual.1.num_formulas_generated);
+        assert_eq!(expected.1.is_complete, actual.1.is_complete);
+    } //End assert_same_run
+
+    #[test]
+    fn thread_count_does_not_change_the_buckets() {
+        for bucket_mode in [BucketMode::Full, BucketMode::Stream(DEFAULT_MAX_FORMULAS_PER_BUCKET)] {
+            let single_thread_run = generate_truth_tables_with_up_to_n_variables(3, &create_settings(bucket_mode, 1),
+                                                                                 None).unwrap();
+            assert!(single_thread_run.1.num_formulas_generated > 0);
+            for num_threads in [2, 3, 8] {
+                let multi_thread_run = generate_truth_tables_with_up_to_n_variables(3,
+                                                                                    &create_settings(bucket_mode,
+                                                                                                     num_threads),
+                                                                                    None).unwrap();
+                assert_same_run(&single_thread_run, &multi_thread_run);
+            } //End for each number of threads
+        } //End for each bucket mode
+    } //End thread_count_does_not_change_the_buckets
+
+    //Runs the first half of the work units for n = 3, like a run that was interrupted halfway.
+    //Return value: the buckets, the report and the index of the first work unit that isn't done
+    fn run_first_half(clause_mask_vec : &Vec<ClauseMask>, precompute_settings : &PrecomputeSettings)
+        -> (Vec<LogicFormulaBucket>, PrecomputeReport, usize)
+    {
+        let mut formula_buckets = create_formula_buckets(256, precompute_settings.bucket_mode);
+        formula_buckets[0].add_formula(PackedFormula::from_formula(SimpleLogicNode::False), 0, 0);
+        formula_buckets[255].add_formula(PackedFormula::from_formula(SimpleLogicNode::True), 0, 0);
+        let mut precompute_report = PrecomputeReport::new();
+
+        //A run without the second half of the work units stops exactly halfway.
+        let mut normal_formula_run = NormalFormulaRun::new(clause_mask_vec, 3, precompute_settings);
+        let num_work_units = normal_formula_run.work_unit_vec.len();
+        normal_formula_run.work_unit_vec.truncate(num_work_units / 2);
+        let next_work_unit_index =
+            normal_formula_run.generate_work_units(&mut formula_buckets, &mut precompute_report, 0, None);
+        assert_eq!(next_work_unit_index, num_work_units / 2);
+
+        (formula_buckets, precompute_report, next_work_unit_index)
+    } //End run_first_half
+
+    //Gets a path in the temporary directory for a checkpoint that only this test uses.
+    fn get_checkpoint_filepath(test_name : &str) -> PathBuf {
+        std::env::temp_dir().join(format!("rust_logic_tools_{}_{}.checkpoint", test_name, std::process::id()))
+    } //End get_checkpoint_filepath
+
+    #[test]
+    fn checkpoint_round_trips() {
+        let clause_mask_vec : Vec<ClauseMask> =
+            ClauseIterator::new(3, 3).map(|literal_subarray| get_clause_mask(&literal_subarray)).collect();
+        let filepath = get_checkpoint_filepath("round_trip");
+        for bucket_mode in [BucketMode::Full, BucketMode::Stream(DEFAULT_MAX_FORMULAS_PER_BUCKET)] {
+            let precompute_settings = create_settings(bucket_mode, 2);
+            let (formula_buckets, precompute_report, next_work_unit_index) =
+                run_first_half(&clause_mask_vec, &precompute_settings);
+
+            let normal_formula_run = NormalFormulaRun::new(&clause_mask_vec, 3, &precompute_settings);
+            normal_formula_run.write_checkpoint(&filepath, &formula_buckets, &precompute_report, next_work_unit_index)
+                              .unwrap();
+            let (read_buckets, read_report, read_work_unit_index) =
+                normal_formula_run.read_checkpoint(&filepath).unwrap();
+            assert_eq!(read_work_unit_index, next_work_unit_index);
+            assert_same_run(&(formula_buckets, precompute_report), &(read_buckets, read_report));
+
+            //A run with other settings can't resume from the checkpoint.
+            let other_settings = create_settings(BucketMode::Stream(1), 2);
+            assert!(NormalFormulaRun::new(&clause_mask_vec, 3, &other_settings).read_checkpoint(&filepath).is_err());
+        } //End for each bucket mode
+
+        std::fs::remove_file(&filepath).unwrap();
+    } //End checkpoint_round_trips
+
+    #[test]
+    fn resumed_run_matches_an_uninterrupted_run() {
+        let clause_mask_vec : Vec<ClauseMask> =
+            ClauseIterator::new(3, 3).map(|literal_subarray| get_clause_mask(&literal_subarray)).collect();
+        let filepath = get_checkpoint_filepath("resume");
+        for bucket_mode in [BucketMode::Full, BucketMode::Stream(DEFAULT_MAX_FORMULAS_PER_BUCKET)] {
+            let precompute_settings = create_settings(bucket_mode, 2);
+            let uninterrupted_run = generate_truth_tables_with_up_to_n_variables(3, &precompute_settings, None)
+                                        .unwrap();
+
+            let (formula_buckets, precompute_report, next_work_unit_index) =
+                run_first_half(&clause_mask_vec, &precompute_settings);
+            NormalFormulaRun::new(&clause_mask_vec, 3, &precompute_settings)
+                .write_checkpoint(&filepath, &formula_buckets, &precompute_report, next_work_unit_index).unwrap();
+            let checkpoint_settings = CheckpointSettings {
+                filepath : filepath.clone(),
+                interval : Duration::ZERO,
+                resume : true
+            };
+            let resumed_run = generate_truth_tables_with_up_to_n_variables(3, &precompute_settings,
+                                                                           Some(&checkpoint_settings)).unwrap();
+            assert_same_run(&uninterrupted_run, &resumed_run);
+            assert_eq!(uninterrupted_run.1.get_as_text(&precompute_settings.pruning_settings),
+                       resumed_run.1.get_as_text(&precompute_settings.pruning_settings));
+
+            //The finished run removes its checkpoint.
+            assert!(!filepath.exists());
+        } //End for each bucket mode
+    } //End resumed_run_matches_an_uninterrupted_run
+
+    #[test]
+    fn tautological_and_duplicate_rules_are_toggled_and_reported() {
+        let default_settings = create_settings(BucketMode::Full, 1);
+        let mut settings_without_them = create_settings(BucketMode::Full, 1);
+        settings_without_them.pruning_settings = PruningSettings::parse("opposite,subsumption").unwrap();
+        let default_run = generate_truth_tables_with_up_to_n_variables(3, &default_settings, None).unwrap();
+        let run_without_them = generate_truth_tables_with_up_to_n_variables(3, &settings_without_them, None).unwrap();
+
+        //Neither rule cuts a clause the clause generator makes, so turning them off doesn't change anything.
+        assert_same_run(&default_run, &run_without_them);
+        for pruning_rule in [PruningRule::TautologicalClauses, PruningRule::DuplicateVariableClauses] {
+            assert!(default_settings.pruning_settings.is_enabled(pruning_rule));
+            assert!(!settings_without_them.pruning_settings.is_enabled(pruning_rule));
+            assert_eq!(default_run.1.get_num_cuts(pruning_rule), 0);
+
+            let name = pruning_rule.get_name();
+            assert!(default_run.1.get_as_text(&default_settings.pruning_settings)
+                                 .contains(&format!("Pruning rule {} cut 0 clauses", name)));
+            assert!(run_without_them.1.get_as_text(&settings_without_them.pruning_settings)
+                                      .contains(&format!("Pruning rule {} is off", name)));
+        } //End for each rule that never cuts
+
+        //The rules still cut the clauses they describe.
+        assert!(PruningRule::TautologicalClauses.cuts_clause(&Vec::new(), get_clause_mask(&[1, 1 | NEGATIVITY_FLAG])));
+        assert!(!PruningRule::TautologicalClauses.cuts_clause(&Vec::new(), get_clause_mask(&[1, 2 | NEGATIVITY_FLAG])));
+    } //End tautological_and_duplicate_rules_are_toggled_and_reported
+} //End mod tests
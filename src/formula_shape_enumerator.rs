@@ -0,0 +1,134 @@
+/** This file enumerates formulas of every shape, not just the CNF and DNF formulas of the normal formula generator.
+    Author: Steven Fletcher
+    Created: 10/18/2026
+    Last Updated: 10/18/2026
+*/
+use crate::logic::*;
+use crate::truth_table_size_5::*;
+use std::collections::{HashMap, HashSet};
+
+///This struct enumerates the formula trees made of & and | over literals, like SimpleLogicNode, with up to a number
+///of binary operators.  Negation is pushed down to the literals, so a tree can have any depth.
+///
+///Level s is the formulas with s binary operators.  A formula with s operators is an & or | of formulas with a and
+///s - 1 - a operators, so level s is built by combining every pair of lower levels.  Two kinds of pruning keep the
+///levels small:
+///Canonical forms: nested operators of the same kind are flattened and their operands are sorted, so formulas that
+///only differ by commutativity or associativity are generated once.
+///Dominance: a formula whose truth table is in a lower level is dropped.  Any formula that used it as a subformula
+///could use the smaller formula instead, so no minimum formula is lost.  Every formula that's kept is minimal for its
+///truth table, and every minimal formula with up to the maximum number of operators is kept.
+pub struct FormulaShapeEnumerator {
+    level_vec : Vec<Vec<(SimpleLogicNode, u32)>>  //level_vec[s] is the formulas with s operators and their truth tables
+} //End struct FormulaShapeEnumerator
+
+impl FormulaShapeEnumerator {
+    ///Enumerates every minimal formula over num_booleans booleans with up to max_binary_operators binary operators.
+    pub fn new(num_booleans : u32, max_binary_operators : u32) -> FormulaShapeEnumerator {
+        let tt_computer = TruthTableSize5Computer::new(num_booleans);
+        let mut minimum_level_map : HashMap<u32, usize> = HashMap::new();  //The lowest level of each truth table
+
+        //Level 0: the literals.  The constants are minimal with no operators too, but an operator with a constant
+        //operand is never minimal, so they're only used for dominance.
+        minimum_level_map.insert(tt_computer.compute_truth_table(&SimpleLogicNode::False), 0);
+        minimum_level_map.insert(tt_computer.compute_truth_table(&SimpleLogicNode::True), 0);
+        let mut literal_level = Vec::with_capacity(2 * num_booleans as usize);
+        for boolean_index in 1..=num_booleans {
+            for literal in [boolean_index, boolean_index | NEGATIVITY_FLAG] {
+                let formula = SimpleLogicNode::Literal(literal);
+                let truth_table = tt_computer.compute_truth_table(&formula);
+                minimum_level_map.insert(truth_table, 0);
+                literal_level.push((formula, truth_table));
+            }
+        } //End for each boolean
+
+        let mut level_vec = vec![literal_level];
+        for level in 1..=max_binary_operators as usize {
+            let mut formula_set : HashSet<SimpleLogicNode> = HashSet::new();
+            let mut formula_vec = Vec::new();
+
+            for left_level in 0..=(level - 1) / 2 {
+                let right_level = level - 1 - left_level;
+                for (left_index, (left_formula, left_truth_table)) in level_vec[left_level].iter().enumerate() {
+                    //When both levels are the same, each pair only needs to be tried once.  A formula paired with
+                    //itself has its own truth table, so it's always dominated.
+                    let right_start = if left_level == right_level {left_index + 1} else {0};
+                    for (right_formula, right_truth_table) in &level_vec[right_level][right_start..] {
+                        for is_conjunction in [true, false] {
+                            let truth_table = if is_conjunction {left_truth_table & right_truth_table}
+                                              else {left_truth_table | right_truth_table};
+                            if minimum_level_map.get(&truth_table).is_some_and(|minimum_level| *minimum_level < level) {
+                                continue;
+                            }
+
+                            let formula = create_canonical_operator(is_conjunction, left_formula, right_formula);
+                            if formula_set.contains(&formula) {continue;}
+                            formula_set.insert(formula.clone());
+                            minimum_level_map.entry(truth_table).or_insert(level);
+                            formula_vec.push((formula, truth_table));
+                        } //End for each operator
+                    } //End for each right operand
+                } //End for each left operand
+            } //End for each split of the operators
+
+            level_vec.push(formula_vec);
+        } //End for each level
+
+        FormulaShapeEnumerator {
+            level_vec : level_vec
+        }
+    } //End new
+
+    ///Gets every formula with a number of binary operators, along with its truth table.
+    pub fn get_level(&self, num_binary_operators : u32) -> &Vec<(SimpleLogicNode, u32)> {
+        &self.level_vec[num_binary_operators as usize]
+    } //End get_level
+} //End impl FormulaShapeEnumerator
+
+//PRIVATE//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+//Creates the conjunction or disjunction of two formulas in canonical form: operands of the same kind are flattened
+//into it, and its operands are sorted.  The operands must already be in canonical form.
+fn create_canonical_operator(is_conjunction : bool, left_formula : &SimpleLogicNode, right_formula : &SimpleLogicNode)
+    -> SimpleLogicNode
+{
+    let operands = vec![left_formula.clone(), right_formula.clone()];
+    let mut formula = if is_conjunction {create_conjunction(operands)} else {create_disjunction(operands)};
+    if let SimpleLogicNode::Conjunction(operands) | SimpleLogicNode::Disjunction(operands) = &mut formula {
+        operands.sort();
+    }
+    formula
+} //End create_canonical_operator
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::minimum_formula_synthesis::*;
+
+    #[test]
+    fn minimum_levels_match_exact_synthesis() {
+        for num_booleans in 1..=3 {
+            let synthesizer = MinimumFormulaSynthesizer::new(num_booleans).unwrap();
+            let shape_enumerator = FormulaShapeEnumerator::new(num_booleans, synthesizer.get_max_cost());
+            let tt_computer = TruthTableSize5Computer::new(num_booleans);
+            let mask = get_truth_table_mask(num_booleans);
+
+            //The constants are never in a level.
+            let mut minimum_level_vec : Vec<Option<u32>> = vec![None; mask as usize + 1];
+            minimum_level_vec[0] = Some(0);
+            minimum_level_vec[mask as usize] = Some(0);
+            for level in 0..=synthesizer.get_max_cost() {
+                for (formula, truth_table) in shape_enumerator.get_level(level) {
+                    assert_eq!(tt_computer.compute_truth_table(formula) & mask, *truth_table);
+                    assert_eq!(synthesizer.get_cost(*truth_table), level,
+                               "{} over {} booleans", truth_table, num_booleans);
+                    minimum_level_vec[*truth_table as usize].get_or_insert(level);
+                }
+            } //End for each level
+
+            for truth_table in 0..=mask {
+                assert_eq!(minimum_level_vec[truth_table as usize], Some(synthesizer.get_cost(truth_table)),
+                           "{} over {} booleans", truth_table, num_booleans);
+            }
+        } //End for each number of booleans
+    }
+} //End mod tests
@@ -0,0 +1,668 @@
+/** This file computes functional decompositions of truth tables.
+    Author: Steven Fletcher
+    Created: 10/18/2026
+    Last Updated: 10/18/2026
+*/
+use crate::html_text::*;
+use crate::logic::*;
+use crate::truth_table_size_5::*;
+
+///A DecompositionNode is a node in the disjoint-support decomposition (DSD) tree of a truth table.  The operands of
+///every node depend on disjoint sets of booleans.
+///Constant and Literal are the leaves of the tree.
+///Conjunction, Disjunction and ExclusiveDisjunction can have any number of operands.
+///Prime is a function that can't be decomposed any further.  It's stored as a truth table.
+#[derive(Clone)]
+pub enum DecompositionNode {
+    Constant(bool),
+    Literal(u32),
+    Conjunction(Vec<DecompositionNode>),
+    Disjunction(Vec<DecompositionNode>),
+    ExclusiveDisjunction(Vec<DecompositionNode>),
+    Prime(PrimeDecompositionNode)
+} //End enum DecompositionNode
+
+///This struct stores a prime node of a decomposition tree.
+///truth_table is a truth table over num_booleans booleans, but it only depends on input_booleans.  The value of each
+///input boolean is computed by the input node at the same index.
+#[derive(Clone)]
+pub struct PrimeDecompositionNode {
+    truth_table : u32,
+    num_booleans : u32,
+    input_booleans : Vec<u32>,
+    inputs : Vec<DecompositionNode>
+} //End struct PrimeDecompositionNode
+
+impl DecompositionNode {
+    ///Negates this DecompositionNode.  The negation is pushed down the tree so that no negation node is needed.
+    pub fn negate(&self) -> DecompositionNode {
+        match self {
+            DecompositionNode::Constant(value) => DecompositionNode::Constant(!value),
+            DecompositionNode::Literal(literal) => DecompositionNode::Literal(literal ^ NEGATIVITY_FLAG),
+            DecompositionNode::Conjunction(operands) => {
+                DecompositionNode::Disjunction(operands.iter().map(|operand| operand.negate()).collect())
+            },
+            DecompositionNode::Disjunction(operands) => {
+                DecompositionNode::Conjunction(operands.iter().map(|operand| operand.negate()).collect())
+            },
+            DecompositionNode::ExclusiveDisjunction(operands) => {
+                //Negating any one operand negates the whole exclusive disjunction.
+                let mut negated_operands = operands.clone();
+                negated_operands[0] = negated_operands[0].negate();
+                DecompositionNode::ExclusiveDisjunction(negated_operands)
+            },
+            DecompositionNode::Prime(prime_node) => {
+                let mut negated_prime_node = prime_node.clone();
+                negated_prime_node.truth_table = !prime_node.truth_table & get_truth_table_mask(prime_node.num_booleans);
+                DecompositionNode::Prime(negated_prime_node)
+            }
+        } //End match self
+    } //End negate
+
+    ///Gets a text representation of this DecompositionNode.  Prime nodes are displayed as PRIMEx(a, b, ...), where x is
+    ///the truth table of the prime function over its own inputs.
+    ///boolean_name_list is a list of the names of the booleans
+    pub fn get_as_text(&self, boolean_name_list : &Vec<String>) -> String {
+        let mut text = "".to_string();
+        self.get_as_text_helper(&mut text, false, boolean_name_list);
+        text
+    } //End get_as_text
+
+    ///Converts this decomposition into an equivalent SimpleLogicNode.  Prime nodes don't exist in SimpleLogicNode, so
+    ///they are expanded into conjunctions and disjunctions of the operands.
+    pub fn to_simple_logic_node(&self) -> SimpleLogicNode {
+        match self {
+            DecompositionNode::Constant(value) => {
+                if *value {SimpleLogicNode::True}
+                else {SimpleLogicNode::False}
+            },
+            DecompositionNode::Literal(literal) => SimpleLogicNode::Literal(*literal),
+            DecompositionNode::Conjunction(operands) => {
+                create_conjunction(operands.iter().map(|operand| operand.to_simple_logic_node()).collect())
+            },
+            DecompositionNode::Disjunction(operands) => {
+                create_disjunction(operands.iter().map(|operand| operand.to_simple_logic_node()).collect())
+            },
+            DecompositionNode::ExclusiveDisjunction(operands) => {
+                create_exclusive_disjunction(operands.iter().map(|operand| operand.to_simple_logic_node()).collect())
+            },
+            DecompositionNode::Prime(prime_node) => prime_node.to_simple_logic_node(prime_node.truth_table, 0)
+        } //End match self
+    } //End to_simple_logic_node
+
+    //Replaces every literal of boolean_index in this tree with replacement (or its negation).
+    fn substitute(&self, boolean_index : u32, replacement : &DecompositionNode) -> DecompositionNode {
+        match self {
+            DecompositionNode::Constant(_) => self.clone(),
+            DecompositionNode::Literal(literal) => {
+                if get_variable_index(*literal) != boolean_index {self.clone()}
+                else if is_positive_literal(*literal) {replacement.clone()}
+                else {replacement.negate()}
+            },
+            DecompositionNode::Conjunction(operands) => DecompositionNode::Conjunction(
+                operands.iter().map(|operand| operand.substitute(boolean_index, replacement)).collect()),
+            DecompositionNode::Disjunction(operands) => DecompositionNode::Disjunction(
+                operands.iter().map(|operand| operand.substitute(boolean_index, replacement)).collect()),
+            DecompositionNode::ExclusiveDisjunction(operands) => DecompositionNode::ExclusiveDisjunction(
+                operands.iter().map(|operand| operand.substitute(boolean_index, replacement)).collect()),
+            DecompositionNode::Prime(prime_node) => {
+                let mut new_prime_node = prime_node.clone();
+                new_prime_node.inputs = prime_node.inputs.iter()
+                    .map(|input| input.substitute(boolean_index, replacement)).collect();
+                DecompositionNode::Prime(new_prime_node)
+            }
+        } //End match self
+    } //End substitute
+
+    //Does the work for get_as_text.
+    //should_parenthesize - whether this node should be parenthesized if it has multiple operands
+    fn get_as_text_helper(&self, text : &mut String, should_parenthesize : bool, boolean_name_list : &Vec<String>) {
+        let (operands, symbol_text) =
+            match self {
+                DecompositionNode::Constant(value) => {
+                    text.push_str(if *value {TRUE_TEXT} else {FALSE_TEXT});
+                    return;
+                },
+                DecompositionNode::Literal(literal) => {
+                    if !is_positive_literal(*literal) {text.push_str(NEGATION_SYMBOL);}
+                    text.push_str(&boolean_name_list[(get_variable_index(*literal) - 1) as usize]);
+                    return;
+                },
+                DecompositionNode::Prime(prime_node) => {
+                    text.push_str(&format!("{}{}(", PRIME_FUNCTION_TEXT, prime_node.get_input_truth_table()));
+                    for (input_index, input) in prime_node.inputs.iter().enumerate() {
+                        if input_index > 0 {text.push_str(", ");}
+                        input.get_as_text_helper(text, false, boolean_name_list);
+                    } //End for each input
+                    text.push(')');
+                    return;
+                },
+                DecompositionNode::Conjunction(operands) => (operands, CONJUNCTION_SYMBOL),
+                DecompositionNode::Disjunction(operands) => (operands, DISJUNCTION_SYMBOL),
+                DecompositionNode::ExclusiveDisjunction(operands) => (operands, EXCLUSIVE_DISJUNCTION_SYMBOL)
+            }; //End match self
+
+        if should_parenthesize {text.push('(');}
+        for (operand_index, operand) in operands.iter().enumerate() {
+            if operand_index > 0 {
+                text.push(' ');
+                text.push_str(symbol_text);
+                text.push(' ');
+            }
+            operand.get_as_text_helper(text, true, boolean_name_list);
+        } //End for each operand
+        if should_parenthesize {text.push(')');}
+    } //End get_as_text_helper
+} //End impl DecompositionNode
+
+impl PrimeDecompositionNode {
+    ///Gets the truth table of this prime function over its own inputs, in the order they're listed.
+    pub fn get_input_truth_table(&self) -> u32 {
+        let num_inputs = self.input_booleans.len() as u32;
+        let mut input_truth_table : u32 = 0;
+        for input_row in 0..get_num_truth_table_rows(num_inputs) {
+            //Find a row of the full truth table where the inputs have the values in input_row.
+            let mut row : u32 = 0;
+            for (input_index, boolean_index) in self.input_booleans.iter().enumerate() {
+                if is_boolean_true_in_row(input_row, input_index as u32 + 1, num_inputs) {
+                    row = row | (1 << (self.num_booleans - boolean_index));
+                }
+            } //End for each input
+
+            if is_truth_table_true_in_row(self.truth_table, row) {
+                input_truth_table = input_truth_table | (1 << input_row);
+            }
+        } //End for each row of the input truth table
+
+        input_truth_table
+    } //End get_input_truth_table
+
+    //Converts the part of this prime function starting at input_index into a SimpleLogicNode using Shannon
+    //expansion on each input in turn.
+    fn to_simple_logic_node(&self, truth_table : u32, input_index : usize) -> SimpleLogicNode {
+        let mask = get_truth_table_mask(self.num_booleans);
+        if truth_table == 0 {return SimpleLogicNode::False;}
+        if truth_table == mask {return SimpleLogicNode::True;}
+
+        let boolean_index = self.input_booleans[input_index];
+        let true_cofactor = compute_cofactor(truth_table, self.num_booleans, boolean_index, true);
+        let false_cofactor = compute_cofactor(truth_table, self.num_booleans, boolean_index, false);
+        if true_cofactor == false_cofactor {
+            return self.to_simple_logic_node(truth_table, input_index + 1);
+        }
+
+        let input_formula = self.inputs[input_index].to_simple_logic_node();
+        if true_cofactor == mask && false_cofactor == 0 {return input_formula;}
+        if true_cofactor == 0 && false_cofactor == mask {return input_formula.negate();}
+
+        let true_formula = self.to_simple_logic_node(true_cofactor, input_index + 1);
+        let false_formula = self.to_simple_logic_node(false_cofactor, input_index + 1);
+
+        //Use the smallest form the cofactors allow.
+        if false_cofactor == 0 {create_conjunction(vec![input_formula, true_formula])}
+        else if true_cofactor == 0 {create_conjunction(vec![input_formula.negate(), false_formula])}
+        else if true_cofactor == mask {create_disjunction(vec![input_formula, false_formula])}
+        else if false_cofactor == mask {create_disjunction(vec![input_formula.negate(), true_formula])}
+        else {
+            create_disjunction(vec![
+                create_conjunction(vec![input_formula.clone(), true_formula]),
+                create_conjunction(vec![input_formula.negate(), false_formula])
+            ])
+        }
+    } //End to_simple_logic_node
+} //End impl PrimeDecompositionNode
+
+///Computes the full disjoint-support decomposition tree of a truth table.
+///truth_table is a truth table over num_booleans booleans (see truth_table_size_5.rs for the format)
+///Return value: the root of the decomposition tree
+pub fn compute_disjoint_support_decomposition(truth_table : u32, num_booleans : u32) -> DecompositionNode {
+    let truth_table = truth_table & get_truth_table_mask(num_booleans);
+    let support = compute_support(truth_table, num_booleans);
+
+    //Constants and literals are the leaves.
+    if support.is_empty() {
+        return DecompositionNode::Constant(truth_table != 0);
+    }
+    if support.len() == 1 {
+        let boolean_index = support[0];
+        if truth_table == get_boolean_truth_table(boolean_index, num_booleans) {
+            return DecompositionNode::Literal(boolean_index);
+        }
+        return DecompositionNode::Literal(boolean_index | NEGATIVITY_FLAG);
+    } //End if there's only 1 boolean in the support
+
+    //Try to split the truth table into a conjunction, disjunction or exclusive disjunction.
+    if let Some(node) = decompose_associatively(truth_table, num_booleans, &support) {
+        return node;
+    }
+
+    //Try an Ashenhurst decomposition f(X, Y) = h(g(X), Y) with the largest possible bound set X.
+    for bound_set_size in (2..support.len()).rev() {
+        for bound_set in get_subsets_of_size(&support, bound_set_size) {
+            let chart = DecompositionChart::new(truth_table, num_booleans, bound_set);
+            if chart.get_column_multiplicity() != 2 {continue;}
+
+            //g is True for the bound set assignments in the second column pattern.
+            //h uses the first boolean of the bound set as the placeholder for g.
+            let (inner_truth_table, outer_truth_table) = chart.compute_ashenhurst_functions();
+            let placeholder_boolean = chart.bound_set[0];
+            let inner_node = compute_disjoint_support_decomposition(inner_truth_table, num_booleans);
+            let outer_node = compute_disjoint_support_decomposition(outer_truth_table, num_booleans);
+            return outer_node.substitute(placeholder_boolean, &inner_node);
+        } //End for each bound set
+    } //End for each bound set size
+
+    //Nothing worked, so the function is prime.
+    DecompositionNode::Prime(PrimeDecompositionNode {
+        truth_table : truth_table,
+        num_booleans : num_booleans,
+        inputs : support.iter().map(|boolean_index| DecompositionNode::Literal(*boolean_index)).collect(),
+        input_booleans : support
+    })
+} //End compute_disjoint_support_decomposition
+
+///An Ashenhurst-Curtis decomposition chart.  The booleans of a truth table are split into a bound set and a free
+///set.  Each column of the chart is an assignment to the bound set, and each row is an assignment to the free set.
+///The truth table can be written as h(g1(bound set), ..., gk(bound set), free set) where k is the ceiling of the
+///log base 2 of the column multiplicity.  If the column multiplicity is at most 2, it is a simple disjoint
+///(Ashenhurst) decomposition.  Otherwise it is a Curtis decomposition, which only simplifies the truth table if k is
+///less than the size of the bound set.
+pub struct DecompositionChart {
+    num_booleans : u32,
+    bound_set : Vec<u32>,
+    free_set : Vec<u32>,
+    entries : Vec<Vec<bool>> //entries[row][column]
+} //End struct DecompositionChart
+
+impl DecompositionChart {
+    ///Creates the decomposition chart of a truth table for a bound set.  All other booleans are in the free set.
+    ///bound_set is the indexes of the booleans in the bound set (1 to n)
+    pub fn new(truth_table : u32, num_booleans : u32, bound_set : Vec<u32>) -> DecompositionChart {
+        let free_set : Vec<u32> = (1..=num_booleans).filter(|index| !bound_set.contains(index)).collect();
+        let num_columns = get_num_truth_table_rows(bound_set.len() as u32);
+        let num_rows = get_num_truth_table_rows(free_set.len() as u32);
+
+        let mut entries = Vec::with_capacity(num_rows as usize);
+        for free_assignment in 0..num_rows {
+            let mut chart_row = Vec::with_capacity(num_columns as usize);
+            for bound_assignment in 0..num_columns {
+                let row = compose_row(num_booleans, &bound_set, bound_assignment, &free_set, free_assignment);
+                chart_row.push(is_truth_table_true_in_row(truth_table, row));
+            } //End for each column
+            entries.push(chart_row);
+        } //End for each row
+
+        DecompositionChart {
+            num_booleans : num_booleans,
+            bound_set : bound_set,
+            free_set : free_set,
+            entries : entries
+        }
+    } //End new
+
+    ///Gets the column multiplicity, the number of distinct columns in the chart.
+    pub fn get_column_multiplicity(&self) -> u32 {
+        self.get_distinct_column_patterns().len() as u32
+    } //End get_column_multiplicity
+
+    ///Gets the number of functions of the bound set that a Curtis decomposition needs.
+    pub fn get_num_curtis_functions(&self) -> u32 {
+        let column_multiplicity = self.get_column_multiplicity();
+        let mut num_functions = 0;
+        while (1 << num_functions) < column_multiplicity {
+            num_functions = num_functions + 1;
+        }
+        num_functions
+    } //End get_num_curtis_functions
+
+    ///Determines whether the chart has a simple disjoint (Ashenhurst) decomposition.
+    pub fn is_ashenhurst_decomposable(&self) -> bool {
+        self.get_column_multiplicity() <= 2
+    } //End is_ashenhurst_decomposable
+
+    ///Determines whether the chart has a Curtis decomposition that needs fewer functions of the bound set than there
+    ///are booleans in it.  Every Ashenhurst decomposition is one.
+    pub fn is_curtis_decomposable(&self) -> bool {
+        self.get_num_curtis_functions() < self.bound_set.len() as u32
+    } //End is_curtis_decomposable
+
+    //Computes g and h for an Ashenhurst decomposition.  The column multiplicity must be 2.
+    //h uses the first boolean of the bound set as the placeholder for the value of g.
+    //Return value: (g, h) as truth tables over num_booleans booleans
+    fn compute_ashenhurst_functions(&self) -> (u32, u32) {
+        let column_patterns = self.get_distinct_column_patterns();
+        let placeholder_boolean = self.bound_set[0];
+
+        let mut inner_truth_table : u32 = 0;
+        let mut outer_truth_table : u32 = 0;
+        for row in 0..get_num_truth_table_rows(self.num_booleans) {
+            //Split the row into its bound and free assignments.
+            let bound_assignment = extract_assignment(row, self.num_booleans, &self.bound_set);
+            let free_assignment = extract_assignment(row, self.num_booleans, &self.free_set);
+
+            //g
+            let column_pattern = self.get_column_pattern(bound_assignment);
+            if column_pattern == column_patterns[1] {
+                inner_truth_table = inner_truth_table | (1 << row);
+            }
+
+            //h
+            let pattern_index = if is_boolean_true_in_row(row, placeholder_boolean, self.num_booleans) {1} else {0};
+            if self.entries[free_assignment as usize][self.find_column(&column_patterns[pattern_index]) as usize] {
+                outer_truth_table = outer_truth_table | (1 << row);
+            }
+        } //End for each row
+
+        (inner_truth_table, outer_truth_table)
+    } //End compute_ashenhurst_functions
+
+    //Gets the distinct column patterns in the order they first appear.
+    fn get_distinct_column_patterns(&self) -> Vec<Vec<bool>> {
+        let mut column_patterns : Vec<Vec<bool>> = Vec::new();
+        for column in 0..get_num_truth_table_rows(self.bound_set.len() as u32) {
+            let column_pattern = self.get_column_pattern(column);
+            if !column_patterns.contains(&column_pattern) {
+                column_patterns.push(column_pattern);
+            }
+        } //End for each column
+
+        column_patterns
+    } //End get_distinct_column_patterns
+
+    fn get_column_pattern(&self, column : u32) -> Vec<bool> {
+        self.entries.iter().map(|chart_row| chart_row[column as usize]).collect()
+    } //End get_column_pattern
+
+    fn find_column(&self, column_pattern : &Vec<bool>) -> u32 {
+        for column in 0..get_num_truth_table_rows(self.bound_set.len() as u32) {
+            if self.get_column_pattern(column) == *column_pattern {return column;}
+        }
+        panic!("DecompositionChart.find_column was given a column pattern that isn't in the chart")
+    } //End find_column
+} //End impl DecompositionChart
+
+///Computes the decomposition charts for every bound set with at least 2 booleans that leaves at least 1 boolean of
+///the support in the free set.
+pub fn compute_all_decomposition_charts(truth_table : u32, num_booleans : u32) -> Vec<DecompositionChart> {
+    let support = compute_support(truth_table, num_booleans);
+    let mut charts = Vec::new();
+    for bound_set_size in 2..support.len() {
+        for bound_set in get_subsets_of_size(&support, bound_set_size) {
+            charts.push(DecompositionChart::new(truth_table, num_booleans, bound_set));
+        }
+    } //End for each bound set size
+
+    charts
+} //End compute_all_decomposition_charts
+
+///Adds the html for the disjoint-support decomposition of a truth table, followed by the decomposition charts of
+///every bound set that has an Ashenhurst or Curtis decomposition.
+pub fn add_html_for_decomposition(html_generator : &mut HtmlGenerator, truth_table : u32,
+                                  boolean_name_list : &Vec<String>)
+{
+    let num_booleans = boolean_name_list.len() as u32;
+    let decomposition = compute_disjoint_support_decomposition(truth_table, num_booleans);
+    html_generator.add_paragraph(&format!("Disjoint-Support Decomposition: {}",
+                                          decomposition.get_as_text(boolean_name_list)));
+    html_generator.add_paragraph(&format!("Decomposition As A Formula: {}",
+                                          decomposition.to_simple_logic_node().get_as_text(boolean_name_list)));
+
+    for chart in compute_all_decomposition_charts(truth_table, num_booleans) {
+        if chart.is_curtis_decomposable() {
+            add_html_for_decomposition_chart(html_generator, &chart, boolean_name_list);
+        }
+    } //End for each chart
+} //End add_html_for_decomposition
+
+///Adds the html for a decomposition chart.  The columns are the bound set assignments, and the rows are the free set
+///assignments.
+pub fn add_html_for_decomposition_chart(html_generator : &mut HtmlGenerator, chart : &DecompositionChart,
+                                        boolean_name_list : &Vec<String>)
+{
+    let bound_set_names = get_boolean_names(&chart.bound_set, boolean_name_list);
+    let free_set_names = get_boolean_names(&chart.free_set, boolean_name_list);
+    let decomposition_text = if chart.is_ashenhurst_decomposable() {ASHENHURST_TEXT} else {CURTIS_TEXT};
+    html_generator.add_paragraph(&format!("Bound Set: {{{}}}, Free Set: {{{}}}, Column Multiplicity: {}, {} \
+                                           Decomposition With {} Function(s) Of The Bound Set", bound_set_names,
+                                          free_set_names, chart.get_column_multiplicity(), decomposition_text,
+                                          chart.get_num_curtis_functions()));
+
+    html_generator.table_create_with_border(CHART_BORDER_THICKNESS);
+
+    //Header row.
+    html_generator.table_add_row(TAG_EMPTY_PROPERTIES);
+    html_generator.table_add_header(TAG_EMPTY_PROPERTIES, &format!("{} \\ {}", free_set_names, bound_set_names));
+    for column in 0..get_num_truth_table_rows(chart.bound_set.len() as u32) {
+        html_generator.table_add_header(TAG_EMPTY_PROPERTIES,
+                                        &get_assignment_as_text(column, chart.bound_set.len() as u32));
+    } //End for each column
+
+    //Data rows.
+    for (free_assignment, chart_row) in chart.entries.iter().enumerate() {
+        html_generator.table_add_row(TAG_EMPTY_PROPERTIES);
+        html_generator.table_add_header(TAG_EMPTY_PROPERTIES,
+                                        &get_assignment_as_text(free_assignment as u32, chart.free_set.len() as u32));
+        for entry in chart_row {
+            html_generator.table_add_data(TAG_EMPTY_PROPERTIES, if *entry {T_TEXT} else {F_TEXT});
+        }
+    } //End for each row
+
+    html_generator.table_end();
+} //End add_html_for_decomposition_chart
+
+//PRIVATE//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+const PRIME_FUNCTION_TEXT : &str = "PRIME";
+const ASHENHURST_TEXT : &str = "Ashenhurst";
+const CURTIS_TEXT : &str = "Curtis";
+const CHART_BORDER_THICKNESS : u8 = 1;
+const TAG_EMPTY_PROPERTIES : &str = "";
+const T_TEXT : &str = "T";
+const F_TEXT : &str = "F";
+
+///The associative operators that a truth table might be split on.
+#[derive(Clone, Copy, PartialEq)]
+enum AssociativeOperator {
+    Conjunction,
+    Disjunction,
+    ExclusiveDisjunction
+} //End enum AssociativeOperator
+
+//Tries to write a truth table as a conjunction, disjunction or exclusive disjunction of functions with disjoint
+//supports.  The partition of the support with the most blocks is used, so the operands can't be split the same way
+//again.
+//Return value: the decomposition, or None if there isn't one
+fn decompose_associatively(truth_table : u32, num_booleans : u32, support : &Vec<u32>) -> Option<DecompositionNode> {
+    let mut best_decomposition : Option<(AssociativeOperator, Vec<u32>)> = None;
+    let mut best_num_blocks = 1;
+
+    for partition in get_set_partitions(support) {
+        if partition.len() <= best_num_blocks {continue;}
+
+        for operator in [AssociativeOperator::Conjunction, AssociativeOperator::Disjunction,
+                         AssociativeOperator::ExclusiveDisjunction].iter()
+        {
+            if let Some(operand_truth_tables) =
+                compute_associative_operands(truth_table, num_booleans, support, &partition, *operator)
+            {
+                best_num_blocks = partition.len();
+                best_decomposition = Some((*operator, operand_truth_tables));
+                break;
+            }
+        } //End for each operator
+    } //End for each partition
+
+    let (operator, operand_truth_tables) = best_decomposition?;
+    let operands : Vec<DecompositionNode> = operand_truth_tables.iter()
+        .map(|operand_truth_table| compute_disjoint_support_decomposition(*operand_truth_table, num_booleans))
+        .collect();
+
+    Some(match operator {
+        AssociativeOperator::Conjunction => DecompositionNode::Conjunction(operands),
+        AssociativeOperator::Disjunction => DecompositionNode::Disjunction(operands),
+        AssociativeOperator::ExclusiveDisjunction => DecompositionNode::ExclusiveDisjunction(operands)
+    })
+} //End decompose_associatively
+
+//Computes the operands of an associative decomposition for a specific partition of the support.
+//Return value: the truth table of each operand, or None if the truth table doesn't decompose this way
+fn compute_associative_operands(truth_table : u32, num_booleans : u32, support : &Vec<u32>,
+                                partition : &Vec<Vec<u32>>, operator : AssociativeOperator) -> Option<Vec<u32>>
+{
+    let mut operand_truth_tables = Vec::with_capacity(partition.len());
+    for block in partition {
+        let other_booleans : Vec<u32> = support.iter().filter(|index| !block.contains(index)).cloned().collect();
+
+        //Quantify away every boolean outside of the block.
+        let mut operand_truth_table = truth_table;
+        for boolean_index in other_booleans {
+            let true_cofactor = compute_cofactor(operand_truth_table, num_booleans, boolean_index, true);
+            let false_cofactor = compute_cofactor(operand_truth_table, num_booleans, boolean_index, false);
+            operand_truth_table =
+                match operator {
+                    AssociativeOperator::Conjunction => true_cofactor | false_cofactor,
+                    AssociativeOperator::Disjunction => true_cofactor & false_cofactor,
+                    AssociativeOperator::ExclusiveDisjunction => false_cofactor
+                };
+        } //End for each boolean outside of the block
+
+        operand_truth_tables.push(operand_truth_table);
+    } //End for each block
+
+    //For exclusive disjunctions, f(0) is counted once per operand, so it has to be removed from all but one of them.
+    if operator == AssociativeOperator::ExclusiveDisjunction && is_truth_table_true_in_row(truth_table, 0) {
+        let mask = get_truth_table_mask(num_booleans);
+        for operand_truth_table in operand_truth_tables.iter_mut().skip(1) {
+            *operand_truth_table = !*operand_truth_table & mask;
+        }
+    } //End if the exclusive disjunction needs to be adjusted
+
+    //Check whether the operands actually combine to the truth table.
+    let mut combined_truth_table =
+        match operator {
+            AssociativeOperator::Conjunction => get_truth_table_mask(num_booleans),
+            _ => 0
+        };
+    for operand_truth_table in &operand_truth_tables {
+        combined_truth_table =
+            match operator {
+                AssociativeOperator::Conjunction => combined_truth_table & operand_truth_table,
+                AssociativeOperator::Disjunction => combined_truth_table | operand_truth_table,
+                AssociativeOperator::ExclusiveDisjunction => combined_truth_table ^ operand_truth_table
+            };
+    } //End for each operand
+
+    if combined_truth_table == truth_table {Some(operand_truth_tables)}
+    else {None}
+} //End compute_associative_operands
+
+//Gets every partition of a set into non-empty blocks.
+fn get_set_partitions(set : &Vec<u32>) -> Vec<Vec<Vec<u32>>> {
+    let mut partitions : Vec<Vec<Vec<u32>>> = vec![Vec::new()];
+    for element in set {
+        let mut new_partitions = Vec::new();
+        for partition in &partitions {
+            //Put the element in each existing block.
+            for block_index in 0..partition.len() {
+                let mut new_partition = partition.clone();
+                new_partition[block_index].push(*element);
+                new_partitions.push(new_partition);
+            }
+
+            //Put the element in a block by itself.
+            let mut new_partition = partition.clone();
+            new_partition.push(vec![*element]);
+            new_partitions.push(new_partition);
+        } //End for each partition
+        partitions = new_partitions;
+    } //End for each element
+
+    partitions
+} //End get_set_partitions
+
+//Gets every subset of a set with a specific size.  The elements of each subset stay in the same order as the set.
+fn get_subsets_of_size(set : &Vec<u32>, subset_size : usize) -> Vec<Vec<u32>> {
+    let mut subsets = Vec::new();
+    for bitmask in 0..(1u32 << set.len()) {
+        if bitmask.count_ones() as usize != subset_size {continue;}
+        subsets.push((0..set.len()).filter(|index| bitmask & (1 << index) != 0).map(|index| set[index]).collect());
+    } //End for each bitmask
+
+    subsets
+} //End get_subsets_of_size
+
+//Builds a row of a truth table from assignments to two disjoint sets of booleans.  Assignments are numbered like
+//truth table rows: the first boolean in the set is the most significant bit.
+fn compose_row(num_booleans : u32, first_set : &Vec<u32>, first_assignment : u32, second_set : &Vec<u32>,
+               second_assignment : u32) -> u32
+{
+    let mut row : u32 = 0;
+    for (set, assignment) in [(first_set, first_assignment), (second_set, second_assignment)].iter() {
+        let set_size = set.len() as u32;
+        for (position, boolean_index) in set.iter().enumerate() {
+            if is_boolean_true_in_row(*assignment, position as u32 + 1, set_size) {
+                row = row | (1 << (num_booleans - boolean_index));
+            }
+        } //End for each boolean in the set
+    } //End for each set
+
+    row
+} //End compose_row
+
+//Extracts the assignment to a set of booleans from a row of a truth table.  This is the inverse of compose_row.
+fn extract_assignment(row : u32, num_booleans : u32, set : &Vec<u32>) -> u32 {
+    let mut assignment : u32 = 0;
+    for boolean_index in set {
+        assignment = assignment << 1;
+        if is_boolean_true_in_row(row, *boolean_index, num_booleans) {
+            assignment = assignment | 1;
+        }
+    } //End for each boolean in the set
+
+    assignment
+} //End extract_assignment
+
+fn get_boolean_names(set : &Vec<u32>, boolean_name_list : &Vec<String>) -> String {
+    set.iter().map(|index| boolean_name_list[(index - 1) as usize].clone()).collect::<Vec<String>>().join(", ")
+} //End get_boolean_names
+
+//Gets an assignment as a string of Ts and Fs.
+fn get_assignment_as_text(assignment : u32, set_size : u32) -> String {
+    let mut text = "".to_string();
+    for position in 1..=set_size {
+        text.push_str(if is_boolean_true_in_row(assignment, position, set_size) {T_TEXT} else {F_TEXT});
+    }
+    text
+} //End get_assignment_as_text
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decomposition_trees_compute_their_truth_tables() {
+        for n in 1..=3 {
+            let tt_computer = TruthTableSize5Computer::new(n);
+            for truth_table in 0..=get_truth_table_mask(n) {
+                let decomposition = compute_disjoint_support_decomposition(truth_table, n);
+                let formula = decomposition.to_simple_logic_node();
+                assert_eq!(tt_computer.compute_truth_table(&formula) & get_truth_table_mask(n), truth_table,
+                           "n = {}, truth table {}", n, truth_table);
+            } //End for each truth table
+        } //End for each number of booleans
+    } //End decomposition_trees_compute_their_truth_tables
+
+    #[test]
+    fn curtis_function_count_covers_the_column_multiplicity() {
+        for n in 3..=4 {
+            for truth_table in 0..=get_truth_table_mask(n) {
+                for chart in compute_all_decomposition_charts(truth_table, n) {
+                    let num_functions = chart.get_num_curtis_functions();
+                    let column_multiplicity = chart.get_column_multiplicity();
+                    assert!(column_multiplicity <= 1 << num_functions);
+                    assert!(num_functions == 0 || column_multiplicity > 1 << (num_functions - 1));
+                    assert_eq!(chart.is_ashenhurst_decomposable(), num_functions <= 1);
+                }
+            } //End for each truth table
+        } //End for each number of booleans
+    } //End curtis_function_count_covers_the_column_multiplicity
+} //End mod tests
@@ -1,7 +1,7 @@
 /** This file generates the text of an Html page.
     Author: Steven Fletcher
     Created: 01/17/2021
-    Last Updated: 03/29/2021
+    Last Updated: 10/18/2026
 */
 use std::fmt;
 
@@ -43,7 +43,7 @@ impl HtmlGenerator {
         let header_number_text = &header_number.to_string();
         self.html_body.push_str("<h");
         self.html_body += header_number_text;
-        self.html_body.push_str(">");
+        self.html_body.push('>');
         self.html_body.push_str(header_text);
         self.html_body.push_str("</h");
         self.html_body += header_number_text;
@@ -62,7 +62,7 @@ impl HtmlGenerator {
     ///Adds a row to the current list.
     ///Parameter row_properties is used to set the internals of the row tag
     pub fn list_add_row(&mut self, row_properties : &str, data : &str) {
-        self.current_list_text.push_str(&"<li ".to_owned());
+        self.current_list_text.push_str("<li ");
         self.current_list_text.push_str(row_properties);
         self.current_list_text.push('>');
         self.current_list_text.push_str(data);
@@ -73,6 +73,7 @@ impl HtmlGenerator {
     ///1. Call list_create
     ///2. For each row, call list_add_row
     ///3. When done, call list_end
+    ///
     ///Only one list can exist at a time.  If you create a new list while the old one hasn't been ended, the old
     ///list will be lost.  If you don't end a list, it will never be added to the text.
     ///
@@ -113,7 +114,7 @@ impl HtmlGenerator {
     ///Adds a row to the current table.
     ///Parameter row_properties is used to set the internals of the row tag
     pub fn table_add_row(&mut self, row_properties : &str) {
-        self.current_table_text.push_str(&"<tr ".to_owned());
+        self.current_table_text.push_str("<tr ");
         self.current_table_text.push_str(row_properties);
         self.current_table_text.push_str(">\n");
     } //End table_add_row
@@ -123,6 +124,7 @@ impl HtmlGenerator {
     ///2. For each row, call table_add_row
     ///3. For each data cell in each row, call table_add_data or table_add_header
     ///4. When done, call table_end
+    ///
     ///Only one table can exist at a time.  If you create a new table while the old one hasn't been ended, the old
     ///table will be lost.  If you don't end a table, it will never be added to the text.
     ///
@@ -170,7 +172,7 @@ impl HtmlGenerator {
 impl fmt::Display for HtmlGenerator {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut text = "<html>\n".to_owned();
-        if self.html_header.len() > 0 {
+        if !self.html_header.is_empty() {
             text.push_str("<header>\n");
             text.push_str(&self.html_header);
             text.push_str("\n</header>\n");
@@ -1,7 +1,7 @@
 ///This file stores various structs for propositional logic.
 ///Author: Steven Fletcher
 ///Created: 2020
-///Last Updated: 05/05/2021
+///Last Updated: 10/18/2026
 ///Please note that the booleans are numbered from 1 to n.  There is no 0 boolean.  This works fine.
 use std::collections::HashMap;
 //use std::fmt;
@@ -25,12 +25,50 @@ pub const VARIABLE_INDEX_MASK : u32 = !NEGATIVITY_FLAG;
 pub const CONJUNCTION_SYMBOL : &str = "&";
 pub const DISJUNCTION_SYMBOL : &str = "|";
 pub const NEGATION_SYMBOL : &str = "~";
+pub const EXCLUSIVE_DISJUNCTION_SYMBOL : &str = "^";
+pub const EQUIVALENCE_SYMBOL : &str = "<=>";
 
 pub const FALSE_TEXT : &str = "FALSE";
 pub const TRUE_TEXT : &str  = "TRUE";
 
 pub const NULL_TEXT : &str = "null";
 
+///Creates a conjunction of the operands.  Operands that are conjunctions themselves are flattened into the new
+///conjunction, and a single operand isn't wrapped at all.
+pub fn create_conjunction(operands : Vec<SimpleLogicNode>) -> SimpleLogicNode {
+    create_flattened_operator(operands, SimpleLogicNode::Conjunction, |operand| match operand {
+        SimpleLogicNode::Conjunction(sub_operands) => Ok(sub_operands),
+        _ => Err(operand)
+    })
+} //End create_conjunction
+
+///Creates a disjunction of the operands.  Operands that are disjunctions themselves are flattened into the new
+///disjunction, and a single operand isn't wrapped at all.
+pub fn create_disjunction(operands : Vec<SimpleLogicNode>) -> SimpleLogicNode {
+    create_flattened_operator(operands, SimpleLogicNode::Disjunction, |operand| match operand {
+        SimpleLogicNode::Disjunction(sub_operands) => Ok(sub_operands),
+        _ => Err(operand)
+    })
+} //End create_disjunction
+
+///Creates an equivalence of the operands.  Equivalence is associative, so operands that are equivalences themselves
+///are flattened into the new equivalence, and a single operand isn't wrapped at all.
+pub fn create_equivalence(operands : Vec<SimpleLogicNode>) -> SimpleLogicNode {
+    create_flattened_operator(operands, SimpleLogicNode::Equivalence, |operand| match operand {
+        SimpleLogicNode::Equivalence(sub_operands) => Ok(sub_operands),
+        _ => Err(operand)
+    })
+} //End create_equivalence
+
+///Creates an exclusive or of the operands.  Exclusive or is associative, so operands that are exclusive ors themselves
+///are flattened into the new exclusive or, and a single operand isn't wrapped at all.
+pub fn create_exclusive_disjunction(operands : Vec<SimpleLogicNode>) -> SimpleLogicNode {
+    create_flattened_operator(operands, SimpleLogicNode::ExclusiveDisjunction, |operand| match operand {
+        SimpleLogicNode::ExclusiveDisjunction(sub_operands) => Ok(sub_operands),
+        _ => Err(operand)
+    })
+} //End create_exclusive_disjunction
+
 pub fn get_variable_index(literal : u32) -> u32 {
     literal & VARIABLE_INDEX_MASK
 } //End get_variable_index
@@ -45,45 +83,98 @@ pub trait LogicFormula {
     fn evaluate(&self, truth_values : &HashMap<u32,bool>) -> TruthValue;
 } //End trait LogicFormula
 
-///SimpleLogicNode can be used to entirely populate a multi-branching syntax tree.  It does not include secondary
-///operators.
+///SimpleLogicNode can be used to entirely populate a multi-branching syntax tree.  Its only secondary operators are
+///equivalence and exclusive or, which are associative, so they can have any number of operands like conjunctions.
 ///Literal stores an integer representing the literal (sign and variable index)
 ///LiteralConjunction and LiteralDisjunction store a Vec containing multiple literals
 ///NodeConjunction and NodeDisjunction store a Vec containing multiple SimpleLogicNodes.
+///Equivalence is p <=> q <=> r..., which is true when an even number of its operands are false.
+///ExclusiveDisjunction is p ^ q ^ r..., which is true when an odd number of its operands are true.
 ///The Literal value is used inside of NodeConjunctions/NodeDisjunctions that include both literals and SimpleLogicNodes
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum SimpleLogicNode {
     False,
     True,
     Literal(u32),
     Conjunction(Vec<SimpleLogicNode>),
-    Disjunction(Vec<SimpleLogicNode>)
+    Disjunction(Vec<SimpleLogicNode>),
+    Equivalence(Vec<SimpleLogicNode>),
+    ExclusiveDisjunction(Vec<SimpleLogicNode>)
 } //End enum SimpleLogicNode
 
 impl SimpleLogicNode {
-    ///Counts the number of binary operators in this SimpleLogicNode and its descendants.
+    ///Counts the number of binary operators in this SimpleLogicNode and its descendants.  Each conjunction,
+    ///disjunction, equivalence or exclusive or counts as 1, however many operands it has.  This is the cost that
+    ///-cost operators uses.
     ///Returns the number of binary operators in this SimpleLogicNode and its descendants.
     pub fn count_binary_operators(&self) -> u32 {
         match self {
             SimpleLogicNode::False => 0,
             SimpleLogicNode::True => 0,
             SimpleLogicNode::Literal(_) => 0,
-            SimpleLogicNode::Conjunction(operands) => {
+            SimpleLogicNode::Conjunction(operands) | SimpleLogicNode::Disjunction(operands) |
+            SimpleLogicNode::Equivalence(operands) | SimpleLogicNode::ExclusiveDisjunction(operands) => {
                 let mut count : u32 = 1;
                 for operand in operands {
                     count = count + operand.count_binary_operators();
                 }
                 count
-            }, //End Conjunction
-            SimpleLogicNode::Disjunction(operands) => {
-                let mut count : u32 = 1;
+            } //End operator
+        }
+    } //End count_binary_operators
+
+    ///Counts the number of two-input operators it takes to build this SimpleLogicNode and its descendants.  An
+    ///operator with k operands counts as k - 1.  This is the cost that -cost twoinput uses.
+    pub fn count_two_input_operators(&self) -> u32 {
+        match self {
+            SimpleLogicNode::False => 0,
+            SimpleLogicNode::True => 0,
+            SimpleLogicNode::Literal(_) => 0,
+            SimpleLogicNode::Conjunction(operands) | SimpleLogicNode::Disjunction(operands) |
+            SimpleLogicNode::Equivalence(operands) | SimpleLogicNode::ExclusiveDisjunction(operands) => {
+                let mut count : u32 = (operands.len() as u32).saturating_sub(1);
                 for operand in operands {
-                    count = count + operand.count_binary_operators();
+                    count = count + operand.count_two_input_operators();
                 }
                 count
-            } //End Disjunction
+            } //End operator
         }
-    } //End count_binary_operators
+    } //End count_two_input_operators
+
+    ///Gets the largest variable index of the literals in this SimpleLogicNode, or 0 if it has no literals.  A formula
+    ///over p1 to pn has a truth table over n booleans.
+    pub fn get_max_variable_index(&self) -> u32 {
+        match self {
+            SimpleLogicNode::False | SimpleLogicNode::True => 0,
+            SimpleLogicNode::Literal(literal) => get_variable_index(*literal),
+            SimpleLogicNode::Conjunction(operands) | SimpleLogicNode::Disjunction(operands) |
+            SimpleLogicNode::Equivalence(operands) | SimpleLogicNode::ExclusiveDisjunction(operands) => {
+                operands.iter().map(|operand| operand.get_max_variable_index()).max().unwrap_or(0)
+            }
+        }
+    } //End get_max_variable_index
+
+    ///Negates this SimpleLogicNode.  There's no negation node, so the negation is pushed down to the literals using
+    ///De Morgan's laws.  Negating one operand of an equivalence or exclusive or negates the whole thing.  The result
+    ///has exactly as many binary operators as the original.
+    ///Return value: a SimpleLogicNode that is True exactly when this one is False
+    pub fn negate(&self) -> SimpleLogicNode {
+        match self {
+            SimpleLogicNode::False => SimpleLogicNode::True,
+            SimpleLogicNode::True => SimpleLogicNode::False,
+            SimpleLogicNode::Literal(literal) => SimpleLogicNode::Literal(literal ^ NEGATIVITY_FLAG),
+            SimpleLogicNode::Conjunction(operands) => {
+                SimpleLogicNode::Disjunction(operands.iter().map(|operand| operand.negate()).collect())
+            },
+            SimpleLogicNode::Disjunction(operands) => {
+                SimpleLogicNode::Conjunction(operands.iter().map(|operand| operand.negate()).collect())
+            },
+            SimpleLogicNode::Equivalence(operands) => SimpleLogicNode::Equivalence(negate_first_operand(operands)),
+            SimpleLogicNode::ExclusiveDisjunction(operands) => {
+                SimpleLogicNode::ExclusiveDisjunction(negate_first_operand(operands))
+            }
+        } //End match self
+    } //End negate
 
     ///Gets a text representation of this SimpleLogicNode.
     ///boolean_name_list a list of the names of the booleans in this formula might have
@@ -112,26 +203,16 @@ impl SimpleLogicNode {
                 //Exit the function.
                 return;
             },
-            SimpleLogicNode::Conjunction(node_vec) => {
-                //If there's no nodes, output null
-                if node_vec.len() == 0 {
-                    text.push_str(NULL_TEXT);
-                    return;
-                } //End if there's no nodes
-
-                if should_parenthesize {text.push('(');}
-                self.get_as_text_helper2(text, node_vec, CONJUNCTION_SYMBOL, boolean_name_list);
-                if should_parenthesize {text.push(')');}
-            },
-            SimpleLogicNode::Disjunction(node_vec) => {
+            SimpleLogicNode::Conjunction(node_vec) | SimpleLogicNode::Disjunction(node_vec) |
+            SimpleLogicNode::Equivalence(node_vec) | SimpleLogicNode::ExclusiveDisjunction(node_vec) => {
                 //If there's no nodes, output null
-                if node_vec.len() == 0 {
+                if node_vec.is_empty() {
                     text.push_str(NULL_TEXT);
                     return;
                 } //End if there's no nodes
 
                 if should_parenthesize {text.push('(');}
-                self.get_as_text_helper2(text, node_vec, DISJUNCTION_SYMBOL, boolean_name_list);
+                self.get_as_text_helper2(text, node_vec, self.get_operator_symbol(), boolean_name_list);
                 if should_parenthesize {text.push(')');}
             },
         }; //End match self
@@ -155,6 +236,17 @@ impl SimpleLogicNode {
         let num_symbols_to_delete = 2 + symbol_text.len();
         text.truncate(text.len() - num_symbols_to_delete);
     } //End get_as_text_helper
+
+    ///Gets the symbol of the operator at the root of this SimpleLogicNode, or an empty string if it isn't an operator.
+    pub fn get_operator_symbol(&self) -> &'static str {
+        match self {
+            SimpleLogicNode::Conjunction(_) => CONJUNCTION_SYMBOL,
+            SimpleLogicNode::Disjunction(_) => DISJUNCTION_SYMBOL,
+            SimpleLogicNode::Equivalence(_) => EQUIVALENCE_SYMBOL,
+            SimpleLogicNode::ExclusiveDisjunction(_) => EXCLUSIVE_DISJUNCTION_SYMBOL,
+            SimpleLogicNode::False | SimpleLogicNode::True | SimpleLogicNode::Literal(_) => ""
+        }
+    } //End get_operator_symbol
 } //End impl SimpleLogicNode
 
 impl LogicFormula for SimpleLogicNode {
@@ -171,7 +263,7 @@ impl LogicFormula for SimpleLogicNode {
                 for conjunct in conjunct_vec.iter() {
                     match conjunct.evaluate(truth_values) {
                         TruthValue::MustBeTrue      => (),
-                        TruthValue::MustBeFalse     => (return TruthValue::MustBeFalse),
+                        TruthValue::MustBeFalse     => return TruthValue::MustBeFalse,
                         TruthValue::Unrestricted    => {contains_unknown_children = true;},
                     } //End match evaluate
                 } //End for each conjunct
@@ -185,7 +277,7 @@ impl LogicFormula for SimpleLogicNode {
                 //Check each disjunct
                 for disjunct in disjunct_vec.iter() {
                     match disjunct.evaluate(truth_values) {
-                        TruthValue::MustBeTrue      => (return TruthValue::MustBeTrue),
+                        TruthValue::MustBeTrue      => return TruthValue::MustBeTrue,
                         TruthValue::MustBeFalse     => (),
                         TruthValue::Unrestricted    => {contains_unknown_children = true;},
                     } //End match evaluate
@@ -196,12 +288,56 @@ impl LogicFormula for SimpleLogicNode {
                 //Each disjunct is false, so the whole thing is false.
                 else {TruthValue::MustBeFalse}
             },
+            SimpleLogicNode::Equivalence(operand_vec) | SimpleLogicNode::ExclusiveDisjunction(operand_vec) => {
+                //Every operand has to be known, and then only the number of true operands matters.
+                let mut num_true_operands = 0;
+                for operand in operand_vec.iter() {
+                    match operand.evaluate(truth_values) {
+                        TruthValue::MustBeTrue      => {num_true_operands = num_true_operands + 1;},
+                        TruthValue::MustBeFalse     => (),
+                        TruthValue::Unrestricted    => return TruthValue::Unrestricted,
+                    } //End match evaluate
+                } //End for each operand
+
+                //An equivalence is true when an even number of operands are false.
+                let num_false_operands = operand_vec.len() - num_true_operands;
+                let is_true = if let SimpleLogicNode::Equivalence(_) = self {num_false_operands % 2 == 0}
+                              else {num_true_operands % 2 == 1};
+                if is_true {TruthValue::MustBeTrue} else {TruthValue::MustBeFalse}
+            },
         } //End match self
     } //End evaluate
 } //End impl LogicFormula for SimpleLogicNode
 
 //MISCELLANEOUS HELPER FUNCTIONS
 
+//Creates an associative operator.  Operands that are the same operator are flattened into it, and a single operand
+//isn't wrapped at all.
+//create_node wraps the operands in the operator, like SimpleLogicNode::Conjunction
+//take_sub_operands gets the operands of an operand that is the same operator, or gives the operand back if it isn't
+fn create_flattened_operator(operands : Vec<SimpleLogicNode>, create_node : fn(Vec<SimpleLogicNode>) -> SimpleLogicNode,
+                             take_sub_operands : fn(SimpleLogicNode) -> Result<Vec<SimpleLogicNode>, SimpleLogicNode>)
+    -> SimpleLogicNode
+{
+    let mut flattened_operands = Vec::with_capacity(operands.len());
+    for operand in operands {
+        match take_sub_operands(operand) {
+            Ok(sub_operands) => flattened_operands.extend(sub_operands),
+            Err(operand) => flattened_operands.push(operand)
+        };
+    } //End for each operand
+
+    if flattened_operands.len() == 1 {flattened_operands.pop().unwrap()}
+    else {create_node(flattened_operands)}
+} //End create_flattened_operator
+
+//Negates the first operand of an equivalence or exclusive or, which negates the whole formula.
+fn negate_first_operand(operands : &Vec<SimpleLogicNode>) -> Vec<SimpleLogicNode> {
+    let mut negated_operands = operands.clone();
+    if let Some(first_operand) = negated_operands.first_mut() {*first_operand = first_operand.negate();}
+    negated_operands
+} //End negate_first_operand
+
 ///Evaluates a single literal.
 ///literal - the literal (sign bit and variable index)
 ///truth_values - the known values that literals have
@@ -0,0 +1,268 @@
+/** This file finds the minimum formula of every truth table exactly, by building formulas in order of increasing size.
+    Author: Steven Fletcher
+    Created: 10/18/2026
+    Last Updated: 10/18/2026
+*/
+use crate::formula_precomputer::*;
+use crate::logic::*;
+use crate::truth_table_size_5::*;
+
+///Exact synthesis stores a record for every truth table, so it's limited to 2^16 truth tables.
+pub const MAX_BOOLEANS_FOR_EXACT_SYNTHESIS : u32 = 4;
+
+///This struct finds a formula with the fewest binary operators for every truth table over n booleans.  The formulas
+///use & and | over literals, like SimpleLogicNode, and negation is pushed down to the literals.
+///
+///Level s is every truth table whose minimum formula has s binary operators.  Level 0 is the constants and literals.
+///A formula with s operators is an & or | of formulas with a and s - 1 - a operators, so level s is found by combining
+///every pair of lower levels.  Truth tables are only recorded the first time they're found, which is the smallest
+///level they're in, so every recorded formula is minimal.
+///
+///Every level is closed under negation, since De Morgan's laws negate a formula without changing its size.  So only
+///conjunctions are combined, and the negation of each conjunction is recorded as a disjunction of the negated
+///operands.
+pub struct MinimumFormulaSynthesizer {
+    record_vec : Vec<Option<SynthesisRecordEntry>>,  //record_vec[truth_table] is how it was first built
+    level_vec : Vec<Vec<u32>>                        //level_vec[s] is the truth tables whose minimum has s operators
+} //End struct MinimumFormulaSynthesizer
+
+impl MinimumFormulaSynthesizer {
+    ///Finds the minimum formula of every truth table over num_booleans booleans.
+    ///Return value: the synthesizer, or an error message if num_booleans is 0 or too large
+    pub fn new(num_booleans : u32) -> Result<MinimumFormulaSynthesizer, String> {
+        if num_booleans == 0 || num_booleans > MAX_BOOLEANS_FOR_EXACT_SYNTHESIS {
+            return Err(format!("Exact synthesis needs 1 to {} booleans, not {}", MAX_BOOLEANS_FOR_EXACT_SYNTHESIS,
+                               num_booleans));
+        }
+
+        let mask = get_truth_table_mask(num_booleans);
+        let num_truth_tables = (mask as usize) + 1;
+        let mut synthesizer = MinimumFormulaSynthesizer {
+            record_vec : vec![None; num_truth_tables],
+            level_vec : vec![Vec::new()]
+        };
+
+        //Level 0: constants and literals
+        synthesizer.add_record(0, 0, SynthesisRecord::Constant);
+        synthesizer.add_record(mask, 0, SynthesisRecord::Constant);
+        for boolean_index in 1..=num_booleans {
+            let boolean_truth_table = get_boolean_truth_table(boolean_index, num_booleans);
+            synthesizer.add_record(boolean_truth_table, 0, SynthesisRecord::Literal(boolean_index));
+            synthesizer.add_record(!boolean_truth_table & mask, 0,
+                                   SynthesisRecord::Literal(boolean_index | NEGATIVITY_FLAG));
+        } //End for each boolean
+        let mut num_found = synthesizer.level_vec[0].len();
+
+        //Build each level from the lower levels until every truth table is found.
+        while num_found < num_truth_tables {
+            let level = synthesizer.level_vec.len();
+            synthesizer.level_vec.push(Vec::new());
+
+            for left_level in 0..=(level - 1) / 2 {
+                let right_level = level - 1 - left_level;
+                let num_left = synthesizer.level_vec[left_level].len();
+                for left_index in 0..num_left {
+                    let left = synthesizer.level_vec[left_level][left_index];
+                    if left == 0 || left == mask {continue;}  //Constants never help
+
+                    //When both levels are the same, each pair only needs to be tried once.
+                    let right_start = if left_level == right_level {left_index + 1} else {0};
+                    for right_index in right_start..synthesizer.level_vec[right_level].len() {
+                        let right = synthesizer.level_vec[right_level][right_index];
+                        let conjunction = left & right;
+                        if synthesizer.record_vec[conjunction as usize].is_some() {continue;}
+
+                        synthesizer.add_record(conjunction, level, SynthesisRecord::Conjunction(left, right));
+                        synthesizer.add_record(!conjunction & mask, level,
+                                               SynthesisRecord::Disjunction(!left & mask, !right & mask));
+                    } //End for each right operand
+                } //End for each left operand
+            } //End for each split of the operators
+
+            num_found = num_found + synthesizer.level_vec[level].len();
+        } //End while there are truth tables left
+
+        Ok(synthesizer)
+    } //End new
+
+    ///Gets the size of the largest minimum formula.  Every truth table has a formula with at most this many binary
+    ///operators.
+    #[cfg(test)]
+    pub fn get_max_cost(&self) -> u32 {(self.level_vec.len() - 1) as u32}
+
+    ///Gets every truth table whose minimum formula has a number of binary operators.
+    #[cfg(test)]
+    pub fn get_level(&self, cost : u32) -> &Vec<u32> {&self.level_vec[cost as usize]}
+
+    ///Gets the number of binary operators in the minimum formula of a truth table.
+    pub fn get_cost(&self, truth_table : u32) -> u32 {
+        self.record_vec[truth_table as usize].as_ref().expect("every truth table has a record").cost as u32
+    } //End get_cost
+
+    ///Gets a minimum formula of a truth table.  Nested operators of the same kind are flattened, so the formula has
+    ///exactly get_cost(truth_table) two-input operators.
+    pub fn get_formula(&self, truth_table : u32) -> SimpleLogicNode {
+        let record = self.record_vec[truth_table as usize].as_ref().expect("every truth table has a record");
+        match record.kind {
+            SynthesisRecord::Constant => {
+                if truth_table == 0 {SimpleLogicNode::False} else {SimpleLogicNode::True}
+            },
+            SynthesisRecord::Literal(literal) => SimpleLogicNode::Literal(literal),
+            SynthesisRecord::Conjunction(left, right) => {
+                create_conjunction(vec![self.get_formula(left), self.get_formula(right)])
+            },
+            SynthesisRecord::Disjunction(left, right) => {
+                create_disjunction(vec![self.get_formula(left), self.get_formula(right)])
+            }
+        } //End match record.kind
+    } //End get_formula
+
+    //PRIVATE
+    //Records how a truth table was built, unless it's already recorded.
+    fn add_record(&mut self, truth_table : u32, level : usize, kind : SynthesisRecord) {
+        if self.record_vec[truth_table as usize].is_some() {return;}
+        self.record_vec[truth_table as usize] = Some(SynthesisRecordEntry {cost : level as u8, kind : kind});
+        self.level_vec[level].push(truth_table);
+    } //End add_record
+} //End impl MinimumFormulaSynthesizer
+
+///Finds the minimum formula of every truth table with exact synthesis and puts each one in its own bucket.  The
+///buckets are in the same format as generate_truth_tables_with_up_to_n_variables, but each one only holds its minimum
+///formula.
+///cost_model must be FormulaCostModel::TwoInputOperators.  The formulas are minimal in two-input operators, where a
+///conjunction or disjunction of k operands takes k - 1 of them, and they aren't minimal in the other cost models.
+///Return value: the buckets, or an error message if n or the cost model isn't supported
+pub fn generate_minimum_formulas_exactly(n : u32, cost_model : FormulaCostModel)
+    -> Result<Vec<LogicFormulaBucket>, String>
+{
+    if cost_model != FormulaCostModel::TwoInputOperators {
+        return Err(format!("The exact method minimizes -cost {}, not -cost {}",
+                           FormulaCostModel::TwoInputOperators.get_name(), cost_model.get_name()));
+    }
+
+    let synthesizer = MinimumFormulaSynthesizer::new(n)?;
+    let num_truth_tables = get_truth_table_mask(n) as usize + 1;
+
+    let mut formula_buckets = Vec::with_capacity(num_truth_tables);
+    for truth_table in 0..num_truth_tables as u32 {
+        let formula = synthesizer.get_formula(truth_table);
+        formula_buckets.push(LogicFormulaBucket::new_with_formula(formula, synthesizer.get_cost(truth_table)));
+    } //End for each truth table
+
+    Ok(formula_buckets)
+} //End generate_minimum_formulas_exactly
+
+//PRIVATE//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+//How a truth table was first built.  Operands are truth tables.
+#[derive(Clone, Copy)]
+enum SynthesisRecord {
+    Constant,
+    Literal(u32),
+    Conjunction(u32, u32),
+    Disjunction(u32, u32)
+} //End enum SynthesisRecord
+
+#[derive(Clone, Copy)]
+struct SynthesisRecordEntry {
+    cost : u8,
+    kind : SynthesisRecord
+} //End struct SynthesisRecordEntry
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAX_TEST_BOOLEANS : u32 = 3;
+
+    //Finds the fewest binary operators of every truth table by brute force.  is_reachable_vec[s] is every truth table
+    //that some & or | formula over the literals and constants with at most s binary operators computes.  Unlike the
+    //synthesizer, this tries both operators on every pair and doesn't skip constants.
+    fn get_brute_force_costs(num_booleans : u32) -> Vec<u32> {
+        let mask = get_truth_table_mask(num_booleans);
+        let num_truth_tables = mask as usize + 1;
+        let mut cost_vec : Vec<Option<u32>> = vec![None; num_truth_tables];
+        let mut is_reachable_vec : Vec<Vec<bool>> = vec![vec![false; num_truth_tables]];
+
+        is_reachable_vec[0][0] = true;
+        is_reachable_vec[0][mask as usize] = true;
+        for boolean_index in 1..=num_booleans {
+            let boolean_truth_table = get_boolean_truth_table(boolean_index, num_booleans);
+            is_reachable_vec[0][boolean_truth_table as usize] = true;
+            is_reachable_vec[0][(!boolean_truth_table & mask) as usize] = true;
+        }
+
+        let mut num_operators = 0;
+        loop {
+            for truth_table in 0..num_truth_tables {
+                if is_reachable_vec[num_operators][truth_table] && cost_vec[truth_table].is_none() {
+                    cost_vec[truth_table] = Some(num_operators as u32);
+                }
+            }
+            if cost_vec.iter().all(|cost| cost.is_some()) {break;}
+
+            num_operators = num_operators + 1;
+            let mut is_reachable = is_reachable_vec[num_operators - 1].clone();
+            for left_operators in 0..num_operators {
+                let right_operators = num_operators - 1 - left_operators;
+                for left in 0..num_truth_tables {
+                    if !is_reachable_vec[left_operators][left] {continue;}
+                    for right in 0..num_truth_tables {
+                        if !is_reachable_vec[right_operators][right] {continue;}
+                        is_reachable[left & right] = true;
+                        is_reachable[left | right] = true;
+                    }
+                }
+            } //End for each split of the operators
+            is_reachable_vec.push(is_reachable);
+        } //End loop
+
+        cost_vec.into_iter().map(|cost| cost.unwrap()).collect()
+    } //End get_brute_force_costs
+
+    #[test]
+    fn costs_match_brute_force() {
+        for num_booleans in 1..=MAX_TEST_BOOLEANS {
+            let synthesizer = MinimumFormulaSynthesizer::new(num_booleans).unwrap();
+            let brute_force_cost_vec = get_brute_force_costs(num_booleans);
+            for (truth_table, brute_force_cost) in brute_force_cost_vec.iter().enumerate() {
+                assert_eq!(synthesizer.get_cost(truth_table as u32), *brute_force_cost,
+                           "truth table {} over {} booleans", truth_table, num_booleans);
+            }
+            assert_eq!(synthesizer.get_max_cost(), *brute_force_cost_vec.iter().max().unwrap());
+        } //End for each number of booleans
+    }
+
+    #[test]
+    fn formulas_compute_their_truth_tables() {
+        for num_booleans in 1..=MAX_TEST_BOOLEANS {
+            let synthesizer = MinimumFormulaSynthesizer::new(num_booleans).unwrap();
+            let computer = TruthTableSize5Computer::new(num_booleans);
+            let mask = get_truth_table_mask(num_booleans);
+            for truth_table in 0..=mask {
+                let formula = synthesizer.get_formula(truth_table);
+                assert_eq!(computer.compute_truth_table(&formula) & mask, truth_table);
+                assert_eq!(formula.count_two_input_operators(), synthesizer.get_cost(truth_table));
+                assert!(synthesizer.get_level(synthesizer.get_cost(truth_table)).contains(&truth_table));
+            }
+        } //End for each number of booleans
+    }
+
+    #[test]
+    fn rejects_unsupported_sizes() {
+        assert!(MinimumFormulaSynthesizer::new(0).is_err());
+        assert!(MinimumFormulaSynthesizer::new(MAX_BOOLEANS_FOR_EXACT_SYNTHESIS + 1).is_err());
+    }
+
+    #[test]
+    fn buckets_use_the_cost_that_is_minimized() {
+        let formula_buckets = generate_minimum_formulas_exactly(2, FormulaCostModel::TwoInputOperators).unwrap();
+        for (truth_table, formula_bucket) in formula_buckets.iter().enumerate() {
+            let minimum_formula = formula_bucket.get_minimum_formula().unwrap();
+            assert_eq!(formula_bucket.get_minimum_cost(), Some(minimum_formula.count_two_input_operators()),
+                       "truth table {}", truth_table);
+        }
+
+        assert!(generate_minimum_formulas_exactly(2, FormulaCostModel::BinaryOperators).is_err());
+        assert!(generate_minimum_formulas_exactly(2, FormulaCostModel::AigAndGates).is_err());
+    }
+} //End mod tests
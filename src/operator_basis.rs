@@ -0,0 +1,563 @@
+/** This file finds the minimum formula of every truth table over other functionally complete sets of operators, like
+    NAND alone, so the sizes of the minimum formulas can be compared between bases.
+    Author: Steven Fletcher
+    Created: 10/18/2026
+    Last Updated: 10/18/2026
+*/
+use crate::html_text::*;
+use crate::logic::*;
+use crate::minimum_formula_synthesis::*;
+use crate::truth_table_size_5::*;
+
+///This enum enumerates the functionally complete bases that minimum formulas can be found over.  The booleans are
+///always free, but negation isn't, so ~p costs a gate in every basis.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum OperatorBasis {
+    Nand,         //p NAND q
+    Nor,          //p NOR q
+    Implication,  //p -> q and FALSE
+    ReedMuller,   //p & q, p ^ q and TRUE
+    Majority      //MAJ(p, q, r), ~p, FALSE and TRUE
+} //End enum OperatorBasis
+
+///The number of operator bases.
+pub const NUM_OPERATOR_BASES : usize = 5;
+
+impl OperatorBasis {
+    ///Every operator basis, in the order they're compared.
+    pub const ALL : [OperatorBasis; NUM_OPERATOR_BASES] = [
+        OperatorBasis::Nand,
+        OperatorBasis::Nor,
+        OperatorBasis::Implication,
+        OperatorBasis::ReedMuller,
+        OperatorBasis::Majority
+    ];
+
+    ///Parses an operator basis from its command line name.
+    pub fn parse(text : &str) -> Option<OperatorBasis> {
+        OperatorBasis::ALL.iter().copied().find(|operator_basis| operator_basis.get_name() == text)
+    } //End parse
+
+    ///Parses a comma separated list of operator bases, like "nand,nor".  "none" is no bases and "all" is every basis.
+    ///Return value: the bases in the order they're listed, or None if a basis isn't recognized
+    pub fn parse_list(text : &str) -> Option<Vec<OperatorBasis>> {
+        if text == OPERATOR_BASES_NONE_TEXT {return Some(Vec::new());}
+        if text == OPERATOR_BASES_ALL_TEXT {return Some(OperatorBasis::ALL.to_vec());}
+
+        let mut operator_basis_vec = Vec::new();
+        for basis_text in text.split(',') {
+            let operator_basis = OperatorBasis::parse(basis_text.trim())?;
+            if !operator_basis_vec.contains(&operator_basis) {operator_basis_vec.push(operator_basis);}
+        }
+        Some(operator_basis_vec)
+    } //End parse_list
+
+    ///Gets the command line name of this basis.
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            OperatorBasis::Nand => "nand",
+            OperatorBasis::Nor => "nor",
+            OperatorBasis::Implication => "implication",
+            OperatorBasis::ReedMuller => "reedmuller",
+            OperatorBasis::Majority => "majority"
+        }
+    } //End get_name
+
+    ///Gets the operators of this basis as they're written in the formulas.
+    pub fn get_operator_text(&self) -> &'static str {
+        match self {
+            OperatorBasis::Nand => "{NAND}",
+            OperatorBasis::Nor => "{NOR}",
+            OperatorBasis::Implication => "{->, FALSE}",
+            OperatorBasis::ReedMuller => "{&, ^, TRUE}",
+            OperatorBasis::Majority => "{MAJ, ~, FALSE, TRUE}"
+        }
+    } //End get_operator_text
+
+    //PRIVATE
+    //The constants that are free, like the booleans.
+    fn get_constants(&self) -> &'static [bool] {
+        match self {
+            OperatorBasis::Nand | OperatorBasis::Nor => &[],
+            OperatorBasis::Implication => &[false],
+            OperatorBasis::ReedMuller => &[true],
+            OperatorBasis::Majority => &[false, true]
+        }
+    } //End get_constants
+
+    //The binary operators of this basis.
+    fn get_binary_operators(&self) -> &'static [BinaryBasisOperator] {
+        match self {
+            OperatorBasis::Nand => &[BinaryBasisOperator::Nand],
+            OperatorBasis::Nor => &[BinaryBasisOperator::Nor],
+            OperatorBasis::Implication => &[BinaryBasisOperator::Implication],
+            OperatorBasis::ReedMuller => &[BinaryBasisOperator::Conjunction, BinaryBasisOperator::ExclusiveDisjunction],
+            OperatorBasis::Majority => &[]
+        }
+    } //End get_binary_operators
+} //End impl OperatorBasis
+
+///This enum is a formula over one of the operator bases.  Unlike SimpleLogicNode, negation is a node of its own, since
+///it isn't free in these bases.
+#[derive(Clone, PartialEq, Debug)]
+pub enum BasisFormula {
+    False,
+    True,
+    Boolean(u32),
+    Negation(Box<BasisFormula>),
+    Nand(Box<BasisFormula>, Box<BasisFormula>),
+    Nor(Box<BasisFormula>, Box<BasisFormula>),
+    Implication(Box<BasisFormula>, Box<BasisFormula>),
+    Conjunction(Box<BasisFormula>, Box<BasisFormula>),
+    ExclusiveDisjunction(Box<BasisFormula>, Box<BasisFormula>),
+    Majority(Box<BasisFormula>, Box<BasisFormula>, Box<BasisFormula>)
+} //End enum BasisFormula
+
+impl BasisFormula {
+    ///Counts the gates in this formula, which is every node except the booleans and the constants.
+    #[cfg(test)]
+    pub fn count_gates(&self) -> u32 {
+        match self {
+            BasisFormula::False | BasisFormula::True | BasisFormula::Boolean(_) => 0,
+            BasisFormula::Negation(operand) => 1 + operand.count_gates(),
+            BasisFormula::Nand(left, right) | BasisFormula::Nor(left, right) |
+            BasisFormula::Implication(left, right) | BasisFormula::Conjunction(left, right) |
+            BasisFormula::ExclusiveDisjunction(left, right) => 1 + left.count_gates() + right.count_gates(),
+            BasisFormula::Majority(first, second, third) => {
+                1 + first.count_gates() + second.count_gates() + third.count_gates()
+            }
+        } //End match self
+    } //End count_gates
+
+    ///Computes the truth table of this formula over the booleans 1 to num_booleans.
+    #[cfg(test)]
+    pub fn compute_truth_table(&self, num_booleans : u32) -> u32 {
+        let mask = get_truth_table_mask(num_booleans);
+        match self {
+            BasisFormula::False => 0,
+            BasisFormula::True => mask,
+            BasisFormula::Boolean(boolean_index) => get_boolean_truth_table(*boolean_index, num_booleans),
+            BasisFormula::Negation(operand) => !operand.compute_truth_table(num_booleans) & mask,
+            BasisFormula::Nand(left, right) => {
+                BinaryBasisOperator::Nand.apply(left.compute_truth_table(num_booleans),
+                                                right.compute_truth_table(num_booleans)) & mask
+            },
+            BasisFormula::Nor(left, right) => {
+                BinaryBasisOperator::Nor.apply(left.compute_truth_table(num_booleans),
+                                               right.compute_truth_table(num_booleans)) & mask
+            },
+            BasisFormula::Implication(left, right) => {
+                BinaryBasisOperator::Implication.apply(left.compute_truth_table(num_booleans),
+                                                       right.compute_truth_table(num_booleans)) & mask
+            },
+            BasisFormula::Conjunction(left, right) => {
+                left.compute_truth_table(num_booleans) & right.compute_truth_table(num_booleans)
+            },
+            BasisFormula::ExclusiveDisjunction(left, right) => {
+                left.compute_truth_table(num_booleans) ^ right.compute_truth_table(num_booleans)
+            },
+            BasisFormula::Majority(first, second, third) => {
+                compute_majority(first.compute_truth_table(num_booleans), second.compute_truth_table(num_booleans),
+                                 third.compute_truth_table(num_booleans))
+            }
+        } //End match self
+    } //End compute_truth_table
+
+    ///Gets a text representation of this formula, like (p1 NAND p2) NAND p3.  Operands that are binary operators are
+    ///parenthesized, except for chains of & or ^, which are associative.
+    pub fn get_as_text(&self, boolean_name_list : &Vec<String>) -> String {
+        let mut text = String::new();
+        self.add_text(&mut text, None, boolean_name_list);
+        text
+    } //End get_as_text
+
+    //PRIVATE
+    //Adds the text of this formula.  parent_symbol is the symbol of the binary operator this formula is an operand of.
+    fn add_text(&self, text : &mut String, parent_symbol : Option<&str>, boolean_name_list : &Vec<String>) {
+        let (left, right, symbol) =
+            match self {
+                BasisFormula::False => {text.push_str(FALSE_TEXT); return;},
+                BasisFormula::True => {text.push_str(TRUE_TEXT); return;},
+                BasisFormula::Boolean(boolean_index) => {
+                    text.push_str(&boolean_name_list[(boolean_index - 1) as usize]);
+                    return;
+                },
+                BasisFormula::Negation(operand) => {
+                    text.push_str(NEGATION_SYMBOL);
+                    operand.add_text(text, Some(NEGATION_SYMBOL), boolean_name_list);
+                    return;
+                },
+                BasisFormula::Majority(first, second, third) => {
+                    text.push_str(MAJORITY_TEXT);
+                    text.push('(');
+                    first.add_text(text, None, boolean_name_list);
+                    text.push_str(", ");
+                    second.add_text(text, None, boolean_name_list);
+                    text.push_str(", ");
+                    third.add_text(text, None, boolean_name_list);
+                    text.push(')');
+                    return;
+                },
+                BasisFormula::Nand(left, right) => (left, right, NAND_TEXT),
+                BasisFormula::Nor(left, right) => (left, right, NOR_TEXT),
+                BasisFormula::Implication(left, right) => (left, right, IMPLICATION_SYMBOL),
+                BasisFormula::Conjunction(left, right) => (left, right, CONJUNCTION_SYMBOL),
+                BasisFormula::ExclusiveDisjunction(left, right) => (left, right, EXCLUSIVE_DISJUNCTION_SYMBOL)
+            }; //End match self
+
+        let is_associative = symbol == CONJUNCTION_SYMBOL || symbol == EXCLUSIVE_DISJUNCTION_SYMBOL;
+        let should_parenthesize = match parent_symbol {
+            Some(parent_symbol) => !(is_associative && parent_symbol == symbol),
+            None => false
+        };
+
+        if should_parenthesize {text.push('(');}
+        left.add_text(text, Some(symbol), boolean_name_list);
+        text.push(' ');
+        text.push_str(symbol);
+        text.push(' ');
+        right.add_text(text, Some(symbol), boolean_name_list);
+        if should_parenthesize {text.push(')');}
+    } //End add_text
+} //End impl BasisFormula
+
+///This struct finds a formula with the fewest gates for every truth table over n booleans, using only the operators of
+///one basis.  It works like MinimumFormulaSynthesizer: level s is every truth table whose minimum formula has s gates,
+///and it's found by applying each operator to formulas from lower levels whose gates add up to s - 1.  Truth tables
+///are only recorded the first time they're found, so every recorded formula is minimal.
+///
+///Unlike MinimumFormulaSynthesizer, the levels aren't closed under negation, so every operator is applied to every
+///combination of operands, including an operand with itself, like p NAND p for ~p.
+pub struct BasisFormulaSynthesizer {
+    num_booleans : u32,
+    record_vec : Vec<Option<BasisRecordEntry>>,  //record_vec[truth_table] is how it was first built
+    level_vec : Vec<Vec<u32>>,                   //level_vec[s] is the truth tables whose minimum has s gates
+    num_found : usize                            //The number of truth tables recorded so far
+} //End struct BasisFormulaSynthesizer
+
+impl BasisFormulaSynthesizer {
+    ///Finds the minimum formula over a basis of every truth table over num_booleans booleans.
+    ///Return value: the synthesizer, or an error message if num_booleans is 0 or too large
+    pub fn new(num_booleans : u32, operator_basis : OperatorBasis) -> Result<BasisFormulaSynthesizer, String> {
+        if num_booleans == 0 || num_booleans > MAX_BOOLEANS_FOR_EXACT_SYNTHESIS {
+            return Err(format!("Synthesis over the {} basis needs 1 to {} booleans, not {}", operator_basis.get_name(),
+                               MAX_BOOLEANS_FOR_EXACT_SYNTHESIS, num_booleans));
+        }
+
+        let mask = get_truth_table_mask(num_booleans);
+        let num_truth_tables = (mask as usize) + 1;
+        let mut synthesizer = BasisFormulaSynthesizer {
+            num_booleans : num_booleans,
+            record_vec : vec![None; num_truth_tables],
+            level_vec : vec![Vec::new()],
+            num_found : 0
+        };
+
+        //Level 0: the booleans and the free constants
+        for constant in operator_basis.get_constants() {
+            synthesizer.add_record(if *constant {mask} else {0}, 0, BasisRecord::Constant(*constant));
+        }
+        for boolean_index in 1..=num_booleans {
+            synthesizer.add_record(get_boolean_truth_table(boolean_index, num_booleans), 0,
+                                   BasisRecord::Boolean(boolean_index));
+        }
+
+        //Build each level from the lower levels until every truth table is found.  Every basis is functionally
+        //complete, so this ends.
+        while synthesizer.num_found < num_truth_tables {
+            let level = synthesizer.level_vec.len();
+            synthesizer.level_vec.push(Vec::new());
+
+            if operator_basis == OperatorBasis::Majority {
+                synthesizer.add_negations(level);
+                synthesizer.add_majorities(level);
+            }
+            for binary_operator in operator_basis.get_binary_operators() {
+                synthesizer.add_binary_operations(level, *binary_operator);
+            }
+        } //End while there are truth tables left
+
+        Ok(synthesizer)
+    } //End new
+
+    ///Gets the number of gates in the minimum formula of a truth table.
+    pub fn get_cost(&self, truth_table : u32) -> u32 {
+        self.record_vec[truth_table as usize].as_ref().expect("every truth table has a record").cost as u32
+    } //End get_cost
+
+    ///Gets a minimum formula of a truth table.  It has exactly get_cost(truth_table) gates.
+    pub fn get_formula(&self, truth_table : u32) -> BasisFormula {
+        let record = self.record_vec[truth_table as usize].as_ref().expect("every truth table has a record");
+        match record.kind {
+            BasisRecord::Constant(constant) => if constant {BasisFormula::True} else {BasisFormula::False},
+            BasisRecord::Boolean(boolean_index) => BasisFormula::Boolean(boolean_index),
+            BasisRecord::Negation(operand) => BasisFormula::Negation(Box::new(self.get_formula(operand))),
+            BasisRecord::BinaryOperation(binary_operator, left, right) => {
+                let left = Box::new(self.get_formula(left));
+                let right = Box::new(self.get_formula(right));
+                match binary_operator {
+                    BinaryBasisOperator::Nand => BasisFormula::Nand(left, right),
+                    BinaryBasisOperator::Nor => BasisFormula::Nor(left, right),
+                    BinaryBasisOperator::Implication => BasisFormula::Implication(left, right),
+                    BinaryBasisOperator::Conjunction => BasisFormula::Conjunction(left, right),
+                    BinaryBasisOperator::ExclusiveDisjunction => BasisFormula::ExclusiveDisjunction(left, right)
+                }
+            },
+            BasisRecord::Majority(first, second, third) => {
+                BasisFormula::Majority(Box::new(self.get_formula(first)), Box::new(self.get_formula(second)),
+                                       Box::new(self.get_formula(third)))
+            }
+        } //End match record.kind
+    } //End get_formula
+
+    //PRIVATE
+    //Records how a truth table was built, unless it's already recorded.
+    fn add_record(&mut self, truth_table : u32, level : usize, kind : BasisRecord) {
+        if self.record_vec[truth_table as usize].is_some() {return;}
+        self.record_vec[truth_table as usize] = Some(BasisRecordEntry {cost : level as u16, kind : kind});
+        self.level_vec[level].push(truth_table);
+        self.num_found = self.num_found + 1;
+    } //End add_record
+
+    fn is_complete(&self) -> bool {self.num_found == self.record_vec.len()}
+
+    //Adds the negation of every truth table on the level below.
+    fn add_negations(&mut self, level : usize) {
+        let mask = get_truth_table_mask(self.num_booleans);
+        for operand_index in 0..self.level_vec[level - 1].len() {
+            let operand = self.level_vec[level - 1][operand_index];
+            self.add_record(!operand & mask, level, BasisRecord::Negation(operand));
+        }
+    } //End add_negations
+
+    //Applies a binary operator to every pair of operands whose gates add up to level - 1.
+    fn add_binary_operations(&mut self, level : usize, binary_operator : BinaryBasisOperator) {
+        let mask = get_truth_table_mask(self.num_booleans);
+        let is_commutative = binary_operator.is_commutative();
+        for left_level in 0..level {
+            let right_level = level - 1 - left_level;
+            if is_commutative && left_level > right_level {break;}
+
+            for left_index in 0..self.level_vec[left_level].len() {
+                let left = self.level_vec[left_level][left_index];
+
+                //When the operator is commutative and both levels are the same, each pair only needs to be tried once.
+                let right_start = if is_commutative && left_level == right_level {left_index} else {0};
+                for right_index in right_start..self.level_vec[right_level].len() {
+                    let right = self.level_vec[right_level][right_index];
+                    self.add_record(binary_operator.apply(left, right) & mask, level,
+                                    BasisRecord::BinaryOperation(binary_operator, left, right));
+                    if self.is_complete() {return;}
+                } //End for each right operand
+            } //End for each left operand
+        } //End for each split of the gates
+    } //End add_binary_operations
+
+    //Applies the majority operator to every triple of different operands whose gates add up to level - 1.  The
+    //majority is symmetric, so the operands are taken in order of level and then of index, and a repeated operand
+    //never helps, since MAJ(p, p, q) is p.
+    fn add_majorities(&mut self, level : usize) {
+        for first_level in 0..level {
+            for second_level in first_level..level {
+                if first_level + second_level > level - 1 {break;}
+                let third_level = level - 1 - first_level - second_level;
+                if third_level < second_level {break;}
+
+                for first_index in 0..self.level_vec[first_level].len() {
+                    let second_start = if first_level == second_level {first_index + 1} else {0};
+                    for second_index in second_start..self.level_vec[second_level].len() {
+                        let third_start = if second_level == third_level {second_index + 1} else {0};
+                        for third_index in third_start..self.level_vec[third_level].len() {
+                            let first = self.level_vec[first_level][first_index];
+                            let second = self.level_vec[second_level][second_index];
+                            let third = self.level_vec[third_level][third_index];
+                            self.add_record(compute_majority(first, second, third), level,
+                                            BasisRecord::Majority(first, second, third));
+                            if self.is_complete() {return;}
+                        } //End for each third operand
+                    } //End for each second operand
+                } //End for each first operand
+            } //End for each level of the second operand
+        } //End for each level of the first operand
+    } //End add_majorities
+} //End impl BasisFormulaSynthesizer
+
+///This struct holds the minimum formula of one truth table over an operator basis, like LogicFormulaBucket does for
+///formulas with & and |.
+pub struct BasisFormulaBucket {
+    formula : BasisFormula,
+    cost : u32  //The number of gates in the formula
+} //End struct BasisFormulaBucket
+
+impl BasisFormulaBucket {
+    ///Adds the minimum formula and its number of gates to an Html page.
+    pub fn add_html_for_formula(&self, html_generator : &mut HtmlGenerator, boolean_name_list : &Vec<String>) {
+        html_generator.add_paragraph(&format!("Minimum Formula: {}", self.formula.get_as_text(boolean_name_list)));
+        html_generator.add_paragraph(&format!("Gates: {}", self.cost));
+    } //End add_html_for_formula
+} //End impl BasisFormulaBucket
+
+///Finds the minimum formula over a basis of every truth table and puts each one in its own bucket.  The buckets are
+///indexed by truth table, like the buckets of generate_minimum_formulas_exactly.
+pub fn generate_minimum_basis_formulas(n : u32, operator_basis : OperatorBasis)
+    -> Result<Vec<BasisFormulaBucket>, String>
+{
+    let synthesizer = BasisFormulaSynthesizer::new(n, operator_basis)?;
+    let num_truth_tables = get_truth_table_mask(n) as usize + 1;
+
+    let mut formula_buckets = Vec::with_capacity(num_truth_tables);
+    for truth_table in 0..num_truth_tables as u32 {
+        formula_buckets.push(BasisFormulaBucket {
+            formula : synthesizer.get_formula(truth_table),
+            cost : synthesizer.get_cost(truth_table)
+        });
+    } //End for each truth table
+
+    Ok(formula_buckets)
+} //End generate_minimum_basis_formulas
+
+///Generates an Html page that compares the minimum formulas over several bases.  It has a summary of the costs in each
+///basis, followed by a table with the number of gates of the minimum formula of every truth table in each basis.
+///basis_bucket_vec is each basis with its buckets, which are indexed by internal truth table
+///convention determines how the truth tables are numbered and ordered
+pub fn get_basis_comparison_html(basis_bucket_vec : &Vec<(OperatorBasis, Vec<BasisFormulaBucket>)>, num_booleans : u32,
+                                 convention : TruthTableConvention) -> String
+{
+    let mut html_generator = HtmlGenerator::new();
+    html_generator.add_header(&format!("Minimum Formulas over Operator Bases for n = {}", num_booleans), 1);
+    html_generator.add_paragraph("Each cost is the number of gates in the minimum formula.  The booleans and the \
+                                  constants of a basis are free, and every other operator, including ~, is a gate.");
+
+    //Summary
+    html_generator.add_header("Summary", 2);
+    html_generator.table_create_with_border(TABLE_BORDER_THICKNESS);
+    html_generator.table_add_row(TAG_EMPTY_PROPERTIES);
+    for header_text in ["Basis", "Operators", "Largest Minimum Formula", "Average Minimum Formula"].iter() {
+        html_generator.table_add_header(TAG_EMPTY_PROPERTIES, header_text);
+    }
+    for (operator_basis, bucket_vec) in basis_bucket_vec.iter() {
+        let max_cost = bucket_vec.iter().map(|bucket| bucket.cost).max().unwrap_or(0);
+        let total_cost : u64 = bucket_vec.iter().map(|bucket| bucket.cost as u64).sum();
+        html_generator.table_add_row(TAG_EMPTY_PROPERTIES);
+        html_generator.table_add_header(TAG_EMPTY_PROPERTIES, operator_basis.get_name());
+        html_generator.table_add_data(TAG_EMPTY_PROPERTIES, operator_basis.get_operator_text());
+        html_generator.table_add_data(TAG_EMPTY_PROPERTIES, &max_cost.to_string());
+        html_generator.table_add_data(TAG_EMPTY_PROPERTIES,
+                                      &format!("{:.2}", total_cost as f64 / bucket_vec.len().max(1) as f64));
+    } //End for each basis
+    html_generator.table_end();
+
+    //Cost of every truth table in each basis
+    html_generator.add_header("Cost of Each Truth Table", 2);
+    html_generator.table_create_with_border(TABLE_BORDER_THICKNESS);
+    html_generator.table_add_row(TAG_EMPTY_PROPERTIES);
+    html_generator.table_add_header(TAG_EMPTY_PROPERTIES, "Truth Table");
+    for (operator_basis, _) in basis_bucket_vec.iter() {
+        html_generator.table_add_header(TAG_EMPTY_PROPERTIES, operator_basis.get_name());
+    }
+    for truth_table in 0..=get_truth_table_mask(num_booleans) {
+        let internal_truth_table = convert_truth_table_to_internal(truth_table, num_booleans, convention);
+        html_generator.table_add_row(TAG_EMPTY_PROPERTIES);
+        html_generator.table_add_header(TAG_EMPTY_PROPERTIES, &truth_table.to_string());
+        for (_, bucket_vec) in basis_bucket_vec.iter() {
+            html_generator.table_add_data(TAG_EMPTY_PROPERTIES, &bucket_vec[internal_truth_table as usize].cost
+                                                                                                        .to_string());
+        }
+    } //End for each truth table
+    html_generator.table_end();
+
+    format!("{}", html_generator)
+} //End get_basis_comparison_html
+
+//PRIVATE//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+const OPERATOR_BASES_NONE_TEXT : &str = "none";
+const OPERATOR_BASES_ALL_TEXT : &str = "all";
+const NAND_TEXT : &str = "NAND";
+const NOR_TEXT : &str = "NOR";
+const IMPLICATION_SYMBOL : &str = "->";
+const MAJORITY_TEXT : &str = "MAJ";
+const TABLE_BORDER_THICKNESS : u8 = 1;
+const TAG_EMPTY_PROPERTIES : &str = "";
+
+//The binary operators of the bases.
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum BinaryBasisOperator {
+    Nand,
+    Nor,
+    Implication,
+    Conjunction,
+    ExclusiveDisjunction
+} //End enum BinaryBasisOperator
+
+impl BinaryBasisOperator {
+    //Applies this operator to two truth tables.  The bits above the truth table mask may be set.
+    fn apply(&self, left : u32, right : u32) -> u32 {
+        match self {
+            BinaryBasisOperator::Nand => !(left & right),
+            BinaryBasisOperator::Nor => !(left | right),
+            BinaryBasisOperator::Implication => !left | right,
+            BinaryBasisOperator::Conjunction => left & right,
+            BinaryBasisOperator::ExclusiveDisjunction => left ^ right
+        }
+    } //End apply
+
+    fn is_commutative(&self) -> bool {*self != BinaryBasisOperator::Implication}
+} //End impl BinaryBasisOperator
+
+//How a truth table was first built.  Operands are truth tables.
+#[derive(Clone, Copy)]
+enum BasisRecord {
+    Constant(bool),
+    Boolean(u32),
+    Negation(u32),
+    BinaryOperation(BinaryBasisOperator, u32, u32),
+    Majority(u32, u32, u32)
+} //End enum BasisRecord
+
+#[derive(Clone, Copy)]
+struct BasisRecordEntry {
+    cost : u16,
+    kind : BasisRecord
+} //End struct BasisRecordEntry
+
+//The majority of three truth tables: each row is true if at least two of them are.
+fn compute_majority(first : u32, second : u32, third : u32) -> u32 {
+    (first & second) | (first & third) | (second & third)
+} //End compute_majority
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAX_TEST_BOOLEANS : u32 = 3;
+
+    #[test]
+    fn formulas_compute_their_truth_tables_at_their_cost() {
+        for operator_basis in OperatorBasis::ALL {
+            for num_booleans in 1..=MAX_TEST_BOOLEANS {
+                let synthesizer = BasisFormulaSynthesizer::new(num_booleans, operator_basis).unwrap();
+                for truth_table in 0..=get_truth_table_mask(num_booleans) {
+                    let formula = synthesizer.get_formula(truth_table);
+                    assert_eq!(formula.compute_truth_table(num_booleans), truth_table,
+                               "{} basis, truth table {} over {} booleans", operator_basis.get_name(), truth_table,
+                               num_booleans);
+                    assert_eq!(formula.count_gates(), synthesizer.get_cost(truth_table),
+                               "{} basis, truth table {} over {} booleans", operator_basis.get_name(), truth_table,
+                               num_booleans);
+                } //End for each truth table
+            } //End for each number of booleans
+        } //End for each basis
+    }
+
+    #[test]
+    fn buckets_match_the_synthesizer() {
+        for operator_basis in OperatorBasis::ALL {
+            let synthesizer = BasisFormulaSynthesizer::new(MAX_TEST_BOOLEANS, operator_basis).unwrap();
+            let formula_buckets = generate_minimum_basis_formulas(MAX_TEST_BOOLEANS, operator_basis).unwrap();
+            for (truth_table, formula_bucket) in formula_buckets.iter().enumerate() {
+                assert_eq!(formula_bucket.cost, synthesizer.get_cost(truth_table as u32));
+                assert_eq!(formula_bucket.formula.compute_truth_table(MAX_TEST_BOOLEANS), truth_table as u32);
+            }
+        } //End for each basis
+    }
+} //End mod tests
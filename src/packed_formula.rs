@@ -0,0 +1,318 @@
+/** This file stores CNF and DNF formulas compactly as arrays of clause bitmasks.
+    Author: Steven Fletcher
+    Created: 10/18/2026
+    Last Updated: 10/18/2026
+*/
+use crate::and_inverter_graph::*;
+use crate::formula_precomputer::*;
+use crate::logic::*;
+use crate::truth_table_size_5::*;
+
+///A clause packed into a bitmask.  Bit 2(i - 1) stands for the literal pi and bit 2(i - 1) + 1 stands for ~pi, so a
+///clause over 5 booleans fits in 10 bits.  Whether the literals are joined by & or | depends on the formula.
+pub type ClauseMask = u16;
+
+///The most booleans a ClauseMask can hold.
+pub const MAX_BOOLEANS_IN_CLAUSE_MASK : u32 = 8;
+
+///Gets the clause mask of some literals.  Every boolean index must be at most MAX_BOOLEANS_IN_CLAUSE_MASK.
+pub fn get_clause_mask(literal_slice : &[u32]) -> ClauseMask {
+    let mut clause_mask = 0;
+    for literal in literal_slice {
+        clause_mask = clause_mask | get_literal_mask(*literal);
+    }
+    clause_mask
+} //End get_clause_mask
+
+///Gets the literals of a clause mask in order of increasing boolean index.  If a clause has both literals of a
+///boolean, the positive one comes first.
+pub fn get_clause_literals(clause_mask : ClauseMask) -> Vec<u32> {
+    let mut literal_vec = Vec::with_capacity(clause_mask.count_ones() as usize);
+    for bit_index in 0..2 * MAX_BOOLEANS_IN_CLAUSE_MASK {
+        if clause_mask & (1 << bit_index) == 0 {continue;}
+
+        let boolean_index = bit_index / 2 + 1;  //(+1 because booleans range from 1 to n)
+        literal_vec.push(if bit_index % 2 == 0 {boolean_index} else {boolean_index | NEGATIVITY_FLAG});
+    } //End for each bit
+    literal_vec
+} //End get_clause_literals
+
+///Gets the set of booleans in a clause mask, with the bit of each boolean's positive literal set.
+pub fn get_clause_booleans(clause_mask : ClauseMask) -> ClauseMask {
+    (clause_mask | (clause_mask >> 1)) & POSITIVE_LITERAL_BITS
+} //End get_clause_booleans
+
+///Determines whether a clause mask has a literal and its negation.
+pub fn is_tautological_clause(clause_mask : ClauseMask) -> bool {
+    clause_mask & (clause_mask >> 1) & POSITIVE_LITERAL_BITS != 0
+} //End is_tautological_clause
+
+///A formula stored as compactly as possible.  The normal formula generator only makes CNF and DNF formulas, which are
+///stored as their clause masks, so building a formula doesn't allocate a tree.  Other formulas are stored as trees.
+///
+///Every formula has exactly one PackedFormula, so two PackedFormulas are equal exactly when their formulas are.  A
+///single literal is always a Dnf with one clause, and a CNF or DNF formula that to_formula wouldn't give back exactly,
+///like one with its literals out of order, is stored as a Tree.
+#[derive(Clone, PartialEq, Eq)]
+pub enum PackedFormula {
+    Dnf(Box<[ClauseMask]>),      //A disjunction of conjunctions
+    Cnf(Box<[ClauseMask]>),      //A conjunction of disjunctions
+    Tree(Box<SimpleLogicNode>)   //Any other formula
+} //End enum PackedFormula
+
+impl PackedFormula {
+    ///Creates the DNF formula with some clauses.
+    pub fn new_dnf(clause_slice : &[ClauseMask]) -> PackedFormula {PackedFormula::Dnf(clause_slice.into())}
+
+    ///Creates the CNF formula with some clauses.  A single literal is stored as a DNF formula.
+    pub fn new_cnf(clause_slice : &[ClauseMask]) -> PackedFormula {
+        if clause_slice.len() == 1 && clause_slice[0].count_ones() == 1 {PackedFormula::new_dnf(clause_slice)}
+        else {PackedFormula::Cnf(clause_slice.into())}
+    } //End new_cnf
+
+    ///Packs a formula, or stores it as a tree if it isn't a CNF or DNF formula that can be packed.
+    pub fn from_formula(formula : SimpleLogicNode) -> PackedFormula {
+        let packed_formula =
+            match &formula {
+                SimpleLogicNode::Literal(literal) => {
+                    if get_variable_index(*literal) <= MAX_BOOLEANS_IN_CLAUSE_MASK {
+                        Some(PackedFormula::new_dnf(&[get_literal_mask(*literal)]))
+                    }
+                    else {None}
+                },
+                SimpleLogicNode::Disjunction(operands) => {
+                    pack_clauses(operands, true).map(|clause_vec| PackedFormula::new_dnf(&clause_vec))
+                },
+                SimpleLogicNode::Conjunction(operands) => {
+                    pack_clauses(operands, false).map(|clause_vec| PackedFormula::new_cnf(&clause_vec))
+                },
+                _ => None
+            };
+
+        match packed_formula {
+            Some(packed_formula) if packed_formula.to_formula() == formula => packed_formula,
+            _ => PackedFormula::Tree(Box::new(formula))
+        }
+    } //End from_formula
+
+    ///Builds the SimpleLogicNode of this formula.  A clause with one literal is just the literal, and a formula with one
+    ///clause of one literal is just the literal.
+    pub fn to_formula(&self) -> SimpleLogicNode {
+        let (clause_slice, is_dnf) =
+            match self {
+                PackedFormula::Dnf(clause_slice) => (clause_slice, true),
+                PackedFormula::Cnf(clause_slice) => (clause_slice, false),
+                PackedFormula::Tree(formula) => return (**formula).clone()
+            };
+
+        let mut clause_node_vec : Vec<SimpleLogicNode> = Vec::with_capacity(clause_slice.len());
+        for clause_mask in clause_slice.iter() {
+            let mut literal_node_vec : Vec<SimpleLogicNode> =
+                get_clause_literals(*clause_mask).into_iter().map(SimpleLogicNode::Literal).collect();
+
+            //If there is only 1 literal in the clause, don't even wrap it.
+            clause_node_vec.push(
+                if literal_node_vec.len() == 1 {literal_node_vec.pop().unwrap()}
+                else if is_dnf {SimpleLogicNode::Conjunction(literal_node_vec)}
+                else {SimpleLogicNode::Disjunction(literal_node_vec)}
+            );
+        } //End for each clause
+
+        if clause_node_vec.len() == 1 {
+            if let SimpleLogicNode::Literal(_) = clause_node_vec[0] {return clause_node_vec.pop().unwrap();}
+        }
+        if is_dnf {SimpleLogicNode::Disjunction(clause_node_vec)} else {SimpleLogicNode::Conjunction(clause_node_vec)}
+    } //End to_formula
+
+    ///Computes the truth table of this formula.
+    pub fn compute_truth_table(&self, tt_computer : &TruthTableSize5Computer) -> u32 {
+        match self {
+            PackedFormula::Dnf(clause_slice) => {
+                let mut truth_table = 0;
+                for clause_mask in clause_slice.iter() {
+                    let mut clause_truth_table = u32::MAX;
+                    for literal in get_clause_literals(*clause_mask) {
+                        clause_truth_table = clause_truth_table & tt_computer.compute_literal_truth_table(literal);
+                    }
+                    truth_table = truth_table | clause_truth_table;
+                } //End for each clause
+                truth_table
+            },
+            PackedFormula::Cnf(clause_slice) => {
+                let mut truth_table = u32::MAX;
+                for clause_mask in clause_slice.iter() {
+                    let mut clause_truth_table = 0;
+                    for literal in get_clause_literals(*clause_mask) {
+                        clause_truth_table = clause_truth_table | tt_computer.compute_literal_truth_table(literal);
+                    }
+                    truth_table = truth_table & clause_truth_table;
+                } //End for each clause
+                truth_table
+            },
+            PackedFormula::Tree(formula) => tt_computer.compute_truth_table(formula)
+        } //End match self
+    } //End compute_truth_table
+
+    ///Computes the cost of this formula over the booleans 1 to n.  The cost is the same as the cost of to_formula, but
+    ///CNF and DNF formulas don't have to be built.
+    pub fn compute_cost(&self, cost_model : FormulaCostModel, n : u32) -> u32 {
+        let (clause_slice, is_dnf) =
+            match self {
+                PackedFormula::Dnf(clause_slice) => (clause_slice, true),
+                PackedFormula::Cnf(clause_slice) => (clause_slice, false),
+                PackedFormula::Tree(formula) => return cost_model.compute_cost(formula, n)
+            };
+
+        match cost_model {
+            //Every clause with more than one literal is one operator, and joining the clauses is one more, unless
+            //the whole formula is a single literal.
+            FormulaCostModel::BinaryOperators => {
+                if clause_slice.len() == 1 && clause_slice[0].count_ones() == 1 {return 0;}
+                let num_clause_operators =
+                    clause_slice.iter().filter(|clause_mask| clause_mask.count_ones() > 1).count() as u32;
+                1 + num_clause_operators
+            },
+            //A clause with k literals takes k - 1 operators, and joining c clauses takes c - 1 more.
+            FormulaCostModel::TwoInputOperators => {
+                let num_clause_operators : u32 =
+                    clause_slice.iter().map(|clause_mask| clause_mask.count_ones().saturating_sub(1)).sum();
+                num_clause_operators + (clause_slice.len() as u32).saturating_sub(1)
+            },
+            //Build the AIG in the same order as AndInverterGraph::build_from_formula.
+            FormulaCostModel::AigAndGates => {
+                let mut aig = AndInverterGraph::new(n);
+                let mut result = if is_dnf {AIG_FALSE} else {AIG_TRUE};
+                for clause_mask in clause_slice.iter() {
+                    let mut clause_result = if is_dnf {AIG_TRUE} else {AIG_FALSE};
+                    for literal in get_clause_literals(*clause_mask) {
+                        let input_literal = aig.get_input_literal(get_variable_index(literal));
+                        let literal_result =
+                            if is_positive_literal(literal) {input_literal}
+                            else {AndInverterGraph::negate(input_literal)};
+                        clause_result = if is_dnf {aig.create_and(clause_result, literal_result)}
+                                        else {aig.create_or(clause_result, literal_result)};
+                    } //End for each literal
+
+                    result = if is_dnf {aig.create_or(result, clause_result)} else {aig.create_and(result, clause_result)};
+                } //End for each clause
+                aig.count_reachable_and_gates(&[result])
+            }
+        } //End match cost_model
+    } //End compute_cost
+} //End impl PackedFormula
+
+//PRIVATE//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+const POSITIVE_LITERAL_BITS : ClauseMask = 0x5555;
+
+fn get_literal_mask(literal : u32) -> ClauseMask {
+    let bit_index = 2 * (get_variable_index(literal) - 1) + if is_positive_literal(literal) {0} else {1};
+    1 << bit_index
+} //End get_literal_mask
+
+//Packs the operands of a CNF or DNF formula into clause masks.
+//is_dnf determines whether the clauses are conjunctions or disjunctions
+//Return value: the clause masks, or None if an operand isn't a literal or a clause of the right kind
+fn pack_clauses(operands : &Vec<SimpleLogicNode>, is_dnf : bool) -> Option<Vec<ClauseMask>> {
+    let mut clause_vec = Vec::with_capacity(operands.len());
+    for operand in operands {
+        let literal_node_vec =
+            match operand {
+                SimpleLogicNode::Literal(_) => std::slice::from_ref(operand),
+                SimpleLogicNode::Conjunction(literal_node_vec) if is_dnf => literal_node_vec.as_slice(),
+                SimpleLogicNode::Disjunction(literal_node_vec) if !is_dnf => literal_node_vec.as_slice(),
+                _ => return None
+            };
+
+        let mut clause_mask = 0;
+        for literal_node in literal_node_vec {
+            match literal_node {
+                SimpleLogicNode::Literal(literal) if get_variable_index(*literal) <= MAX_BOOLEANS_IN_CLAUSE_MASK => {
+                    clause_mask = clause_mask | get_literal_mask(*literal);
+                },
+                _ => return None
+            };
+        } //End for each literal
+        clause_vec.push(clause_mask);
+    } //End for each operand
+
+    Some(clause_vec)
+} //End pack_clauses
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clause_iterator::*;
+
+    const MAX_TEST_BOOLEANS : u32 = 3;
+    const COST_MODEL_LIST : [FormulaCostModel; 3] = [FormulaCostModel::BinaryOperators,
+                                                     FormulaCostModel::TwoInputOperators, FormulaCostModel::AigAndGates];
+
+    //Gets the CNF and DNF formulas of every clause and every pair of clauses over n booleans.
+    fn get_normal_formulas(n : u32) -> Vec<PackedFormula> {
+        let clause_mask_vec : Vec<ClauseMask> =
+            ClauseIterator::new(n, n).map(|literal_vec| get_clause_mask(&literal_vec)).collect();
+
+        let mut packed_formula_vec = Vec::new();
+        for first_index in 0..clause_mask_vec.len() {
+            for second_index in first_index..clause_mask_vec.len() {
+                let clause_vec = if first_index == second_index {vec![clause_mask_vec[first_index]]}
+                                 else {vec![clause_mask_vec[first_index], clause_mask_vec[second_index]]};
+                packed_formula_vec.push(PackedFormula::new_dnf(&clause_vec));
+                packed_formula_vec.push(PackedFormula::new_cnf(&clause_vec));
+            } //End for each second clause
+        } //End for each first clause
+        packed_formula_vec
+    } //End get_normal_formulas
+
+    //Gets formulas that can't be packed, over the booleans 1 to 3.
+    fn get_tree_formulas() -> Vec<SimpleLogicNode> {
+        let p = SimpleLogicNode::Literal(1);
+        let q = SimpleLogicNode::Literal(2);
+        let not_r = SimpleLogicNode::Literal(3 | NEGATIVITY_FLAG);
+        vec![
+            SimpleLogicNode::True,
+            SimpleLogicNode::Equivalence(vec![p.clone(), q.clone()]),
+            SimpleLogicNode::Conjunction(vec![p.clone(), SimpleLogicNode::Disjunction(vec![q.clone(),
+                SimpleLogicNode::Conjunction(vec![p.clone(), not_r.clone()])])]),
+            SimpleLogicNode::Disjunction(vec![SimpleLogicNode::Conjunction(vec![q.clone(), p.clone()]), not_r.clone()])
+        ]
+    } //End get_tree_formulas
+
+    #[test]
+    fn formulas_round_trip() {
+        for n in 1..=MAX_TEST_BOOLEANS {
+            for packed_formula in get_normal_formulas(n) {
+                let unpacked_formula = PackedFormula::from_formula(packed_formula.to_formula());
+                assert!(!matches!(unpacked_formula, PackedFormula::Tree(_)));
+                assert!(unpacked_formula == packed_formula);
+            }
+        } //End for each number of booleans
+
+        //The last formula is a DNF formula with its literals out of order.
+        for formula in get_tree_formulas() {
+            let packed_formula = PackedFormula::from_formula(formula.clone());
+            assert!(matches!(packed_formula, PackedFormula::Tree(_)));
+            assert!(packed_formula.to_formula() == formula);
+        }
+    }
+
+    #[test]
+    fn costs_and_truth_tables_match_the_formula() {
+        for n in 1..=MAX_TEST_BOOLEANS {
+            let tt_computer = TruthTableSize5Computer::new(n);
+            let mut packed_formula_vec = get_normal_formulas(n);
+            if n == MAX_TEST_BOOLEANS {
+                packed_formula_vec.extend(get_tree_formulas().into_iter().map(PackedFormula::from_formula));
+            }
+
+            for packed_formula in packed_formula_vec {
+                let formula = packed_formula.to_formula();
+                for cost_model in COST_MODEL_LIST.iter() {
+                    assert_eq!(packed_formula.compute_cost(*cost_model, n), cost_model.compute_cost(&formula, n),
+                               "{} cost over {} booleans", cost_model.get_name(), n);
+                }
+                assert_eq!(packed_formula.compute_truth_table(&tt_computer), tt_computer.compute_truth_table(&formula));
+            } //End for each formula
+        } //End for each number of booleans
+    }
+} //End mod tests
@@ -0,0 +1,373 @@
+/** This file summarizes a precompute run as an Html page and as JSON.
+    Author: Steven Fletcher
+    Created: 10/18/2026
+    Last Updated: 10/18/2026
+*/
+use crate::formula_precomputer::*;
+use crate::html_text::*;
+use crate::truth_table_size_5::*;
+use std::time::Duration;
+
+///This struct stores the statistics of a precompute run: the number of formulas of each truth table, the distribution
+///of the minimum formula costs, the truth tables with no formula, the number of formulas generated, the number of
+///clauses each pruning rule cut, and the time each phase took.  The truth tables are numbered in the convention of the
+///run.
+pub struct PrecomputeStatistics {
+    num_booleans : u32,
+    method_name : String,
+    cost_model : FormulaCostModel,
+    num_formulas_vec : Vec<u64>,                  //The number of formulas of each truth table
+    minimum_cost_vec : Vec<Option<u32>>,          //The cost of each truth table's minimum formula, if it has one
+    precompute_report : Option<PrecomputeReport>, //Only -method normal has a report
+    phase_vec : Vec<(String, Duration)>           //The name and duration of each phase, in the order they ran
+} //End struct PrecomputeStatistics
+
+impl PrecomputeStatistics {
+    ///Collects the statistics of the buckets of a run.  The phases of the report are added first.
+    ///method_name is the name of the method that generated the buckets
+    ///precompute_report is the report of the run, or None if the method doesn't make one
+    pub fn new(tt_bucket_vec : &Vec<LogicFormulaBucket>, num_booleans : u32, convention : TruthTableConvention,
+               method_name : &str, cost_model : FormulaCostModel, precompute_report : Option<PrecomputeReport>)
+        -> PrecomputeStatistics
+    {
+        let mut num_formulas_vec = Vec::with_capacity(tt_bucket_vec.len());
+        let mut minimum_cost_vec = Vec::with_capacity(tt_bucket_vec.len());
+        for truth_table in 0..tt_bucket_vec.len() as u32 {
+            let internal_truth_table = convert_truth_table_to_internal(truth_table, num_booleans, convention);
+            let formula_bucket = &tt_bucket_vec[internal_truth_table as usize];
+            num_formulas_vec.push(formula_bucket.get_num_formulas());
+            minimum_cost_vec.push(formula_bucket.get_minimum_cost());
+        } //End for each truth table
+
+        let mut phase_vec = Vec::new();
+        if let Some(precompute_report) = &precompute_report {
+            for (phase_name, duration) in precompute_report.get_phase_durations().iter() {
+                phase_vec.push((phase_name.to_string(), *duration));
+            }
+        }
+
+        PrecomputeStatistics {
+            num_booleans : num_booleans,
+            method_name : method_name.to_string(),
+            cost_model : cost_model,
+            num_formulas_vec : num_formulas_vec,
+            minimum_cost_vec : minimum_cost_vec,
+            precompute_report : precompute_report,
+            phase_vec : phase_vec
+        }
+    } //End new
+
+    ///Adds a phase that took some time.
+    pub fn add_phase(&mut self, phase_name : &str, duration : Duration) {
+        self.phase_vec.push((phase_name.to_string(), duration));
+    } //End add_phase
+
+    ///Gets the truth tables that no formula was found for.
+    pub fn get_truth_tables_without_formula(&self) -> Vec<u32> {
+        (0..self.minimum_cost_vec.len() as u32)
+            .filter(|truth_table| self.minimum_cost_vec[*truth_table as usize].is_none()).collect()
+    } //End get_truth_tables_without_formula
+
+    ///Gets each minimum formula cost with the number of truth tables whose minimum formula has it, from cheapest to
+    ///most expensive.
+    pub fn get_minimum_cost_histogram(&self) -> Vec<(u32, u64)> {
+        let max_cost = self.minimum_cost_vec.iter().flatten().max().copied();
+        let mut histogram = vec![0u64; max_cost.map_or(0, |max_cost| max_cost as usize + 1)];
+        for minimum_cost in self.minimum_cost_vec.iter().flatten() {
+            histogram[*minimum_cost as usize] = histogram[*minimum_cost as usize] + 1;
+        }
+
+        histogram.into_iter().enumerate().filter(|(_, num_truth_tables)| *num_truth_tables > 0)
+                 .map(|(cost, num_truth_tables)| (cost as u32, num_truth_tables)).collect()
+    } //End get_minimum_cost_histogram
+
+    ///Gets the distribution of the number of formulas per truth table.  The counts are grouped into ranges that double
+    ///in size: 0, 1, 2 to 3, 4 to 7 and so on.  Each range is its smallest count, its largest count and the number of
+    ///truth tables with a count in it.  Ranges with no truth tables are left out.
+    pub fn get_formula_count_histogram(&self) -> Vec<(u64, u64, u64)> {
+        let mut histogram = vec![0u64; 65];  //Range 0 is the count 0, and range k is 2^(k - 1) to 2^k - 1
+        for num_formulas in &self.num_formulas_vec {
+            let range_index = (64 - num_formulas.leading_zeros()) as usize;
+            histogram[range_index] = histogram[range_index] + 1;
+        }
+
+        let mut range_vec = Vec::new();
+        for (range_index, num_truth_tables) in histogram.into_iter().enumerate() {
+            if num_truth_tables == 0 {continue;}
+
+            let (smallest_count, largest_count) =
+                if range_index == 0 {(0, 0)}
+                else {(1u64 << (range_index - 1), ((1u128 << range_index) - 1) as u64)};
+            range_vec.push((smallest_count, largest_count, num_truth_tables));
+        } //End for each range
+
+        range_vec
+    } //End get_formula_count_histogram
+
+    ///Gets the statistics as an Html page.  The histograms are drawn as tables of bars.
+    pub fn get_html(&self) -> String {
+        let mut html_generator = HtmlGenerator::new();
+        html_generator.add_header(&format!("Precompute Statistics for n = {}", self.num_booleans), 1);
+        html_generator.add_paragraph(&format!("Method: {}.  Cost: {}.", self.method_name, self.cost_model.get_name()));
+
+        //Formulas
+        html_generator.add_header("Formulas", 2);
+        html_generator.table_create_with_border(TABLE_BORDER_THICKNESS);
+        add_html_row(&mut html_generator, "Truth tables", &self.num_formulas_vec.len().to_string());
+        add_html_row(&mut html_generator, "Formulas in the buckets",
+                     &self.num_formulas_vec.iter().sum::<u64>().to_string());
+        if let Some(precompute_report) = &self.precompute_report {
+            add_html_row(&mut html_generator, "Formulas generated",
+                         &precompute_report.get_num_formulas_generated().to_string());
+            for pruning_rule in PruningRule::ALL.iter() {
+                let label = format!("Clauses pruned by {} (each skips every formula that starts with it)",
+                                    pruning_rule.get_name());
+                add_html_row(&mut html_generator, &label, &precompute_report.get_num_cuts(*pruning_rule).to_string());
+            }
+        } //End if there's a report
+        html_generator.table_end();
+
+        //Histograms
+        html_generator.add_header("Formulas per Truth Table", 2);
+        let formula_count_histogram = self.get_formula_count_histogram();
+        let max_num_truth_tables = formula_count_histogram.iter().map(|range| range.2).max().unwrap_or(0);
+        html_generator.table_create_with_border(TABLE_BORDER_THICKNESS);
+        add_html_bar_header(&mut html_generator, "Formulas");
+        for (smallest_count, largest_count, num_truth_tables) in formula_count_histogram {
+            let range_text =
+                if smallest_count == largest_count {smallest_count.to_string()}
+                else {format!("{} to {}", smallest_count, largest_count)};
+            add_html_bar_row(&mut html_generator, &range_text, num_truth_tables, max_num_truth_tables);
+        } //End for each range
+        html_generator.table_end();
+
+        html_generator.add_header("Minimum Formula Costs", 2);
+        let minimum_cost_histogram = self.get_minimum_cost_histogram();
+        let max_num_truth_tables = minimum_cost_histogram.iter().map(|cost_count| cost_count.1).max().unwrap_or(0);
+        html_generator.table_create_with_border(TABLE_BORDER_THICKNESS);
+        add_html_bar_header(&mut html_generator, "Cost");
+        for (cost, num_truth_tables) in minimum_cost_histogram {
+            add_html_bar_row(&mut html_generator, &cost.to_string(), num_truth_tables, max_num_truth_tables);
+        }
+        html_generator.table_end();
+
+        //Truth tables without a formula
+        html_generator.add_header("Truth Tables Without a Formula", 2);
+        let missing_truth_table_vec = self.get_truth_tables_without_formula();
+        if missing_truth_table_vec.is_empty() {
+            html_generator.add_paragraph("Every truth table has a formula.");
+        }
+        else {
+            let truth_table_text_vec : Vec<String> =
+                missing_truth_table_vec.iter().map(|truth_table| truth_table.to_string()).collect();
+            html_generator.add_paragraph(&format!("{} truth tables: {}", missing_truth_table_vec.len(),
+                                                  truth_table_text_vec.join(", ")));
+        }
+
+        //Phases
+        html_generator.add_header("Time per Phase", 2);
+        html_generator.table_create_with_border(TABLE_BORDER_THICKNESS);
+        for (phase_name, duration) in &self.phase_vec {
+            add_html_row(&mut html_generator, phase_name, &format!("{:.3} s", duration.as_secs_f64()));
+        }
+        html_generator.table_end();
+
+        format!("{}", html_generator)
+    } //End get_html
+
+    ///Gets the statistics as JSON.
+    pub fn get_json(&self) -> String {
+        let mut json_text = String::from("{\n");
+        json_text.push_str(&format!("  \"num_booleans\": {},\n", self.num_booleans));
+        json_text.push_str(&format!("  \"method\": {},\n", get_json_string(&self.method_name)));
+        json_text.push_str(&format!("  \"cost_model\": {},\n", get_json_string(self.cost_model.get_name())));
+
+        let num_formulas_text_vec : Vec<String> = self.num_formulas_vec.iter().map(|count| count.to_string()).collect();
+        json_text.push_str(&format!("  \"formulas_per_truth_table\": [{}],\n", num_formulas_text_vec.join(", ")));
+
+        let minimum_cost_text_vec : Vec<String> =
+            self.get_minimum_cost_histogram().iter()
+                .map(|(cost, num_truth_tables)| {
+                    format!("{{\"cost\": {}, \"truth_tables\": {}}}", cost, num_truth_tables)
+                })
+                .collect();
+        json_text.push_str(&format!("  \"minimum_cost_histogram\": [{}],\n", minimum_cost_text_vec.join(", ")));
+
+        let missing_text_vec : Vec<String> =
+            self.get_truth_tables_without_formula().iter().map(|truth_table| truth_table.to_string()).collect();
+        json_text.push_str(&format!("  \"truth_tables_without_formula\": [{}],\n", missing_text_vec.join(", ")));
+
+        //Only -method normal generates formulas and prunes clauses.
+        match &self.precompute_report {
+            Some(precompute_report) => {
+                json_text.push_str(&format!("  \"formulas_generated\": {},\n",
+                                            precompute_report.get_num_formulas_generated()));
+                let cut_text_vec : Vec<String> =
+                    PruningRule::ALL.iter()
+                        .map(|pruning_rule| format!("{}: {}", get_json_string(pruning_rule.get_name()),
+                                                    precompute_report.get_num_cuts(*pruning_rule)))
+                        .collect();
+                json_text.push_str(&format!("  \"clauses_pruned\": {{{}}},\n", cut_text_vec.join(", ")));
+            },
+            None => {
+                json_text.push_str("  \"formulas_generated\": null,\n");
+                json_text.push_str("  \"clauses_pruned\": null,\n");
+            }
+        } //End match the report
+
+        let phase_text_vec : Vec<String> =
+            self.phase_vec.iter()
+                .map(|(phase_name, duration)| format!("{{\"name\": {}, \"seconds\": {:.6}}}",
+                                                      get_json_string(phase_name), duration.as_secs_f64()))
+                .collect();
+        json_text.push_str(&format!("  \"phases\": [{}]\n", phase_text_vec.join(", ")));
+        json_text.push_str("}\n");
+        json_text
+    } //End get_json
+} //End impl PrecomputeStatistics
+
+//PRIVATE//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+const TABLE_BORDER_THICKNESS : u8 = 1;
+const TAG_EMPTY_PROPERTIES : &str = "";
+const MAX_BAR_WIDTH : u64 = 60;
+const BAR_TEXT : &str = "&#9608;";
+
+fn add_html_row(html_generator : &mut HtmlGenerator, name : &str, value : &str) {
+    html_generator.table_add_row(TAG_EMPTY_PROPERTIES);
+    html_generator.table_add_header(TAG_EMPTY_PROPERTIES, name);
+    html_generator.table_add_data(TAG_EMPTY_PROPERTIES, value);
+} //End add_html_row
+
+fn add_html_bar_header(html_generator : &mut HtmlGenerator, value_name : &str) {
+    html_generator.table_add_row(TAG_EMPTY_PROPERTIES);
+    html_generator.table_add_header(TAG_EMPTY_PROPERTIES, value_name);
+    html_generator.table_add_header(TAG_EMPTY_PROPERTIES, "Truth Tables");
+    html_generator.table_add_header(TAG_EMPTY_PROPERTIES, "");
+} //End add_html_bar_header
+
+//Adds a row with a bar whose length is proportional to the count.  Any count above 0 gets at least one block.
+fn add_html_bar_row(html_generator : &mut HtmlGenerator, value_text : &str, count : u64, max_count : u64) {
+    let bar_width = if count == 0 {0} else {(count * MAX_BAR_WIDTH).div_ceil(max_count)};
+    html_generator.table_add_row(TAG_EMPTY_PROPERTIES);
+    html_generator.table_add_header(TAG_EMPTY_PROPERTIES, value_text);
+    html_generator.table_add_data(TAG_EMPTY_PROPERTIES, &count.to_string());
+    html_generator.table_add_data(TAG_EMPTY_PROPERTIES, &BAR_TEXT.repeat(bar_width as usize));
+} //End add_html_bar_row
+
+//Quotes a string for JSON.
+fn get_json_string(text : &str) -> String {
+    let mut json_string = String::from("\"");
+    for character in text.chars() {
+        match character {
+            '"' => json_string.push_str("\\\""),
+            '\\' => json_string.push_str("\\\\"),
+            '\n' => json_string.push_str("\\n"),
+            _ => json_string.push(character)
+        };
+    } //End for each character
+    json_string.push('"');
+    json_string
+} //End get_json_string
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logic::*;
+
+    //Runs -method normal for n = 2 on one thread with the default settings and collects its statistics.
+    fn get_fixed_run_statistics() -> PrecomputeStatistics {
+        let precompute_settings = PrecomputeSettings {
+            cost_model : FormulaCostModel::BinaryOperators,
+            bucket_mode : BucketMode::Full,
+            num_threads : 1,
+            pruning_settings : PruningSettings::new(),
+            family_settings : FormulaFamilySettings::new(),
+            max_shape_operators : 0,
+            verify_duality : false,
+            progress_interval : Duration::ZERO,
+            memory_budget : None
+        };
+        let (tt_bucket_vec, precompute_report) =
+            generate_truth_tables_with_up_to_n_variables(2, &precompute_settings, None).unwrap();
+        PrecomputeStatistics::new(&tt_bucket_vec, 2, TruthTableConvention::FirstBooleanMostSignificant, "normal",
+                                  FormulaCostModel::BinaryOperators, Some(precompute_report))
+    } //End get_fixed_run_statistics
+
+    //Gets buckets over 2 booleans where only the internal truth table 2, ~p1 & p2, has no formula.
+    fn get_buckets_with_a_missing_truth_table() -> Vec<LogicFormulaBucket> {
+        (0..16).map(|truth_table| {
+            if truth_table == 2 {LogicFormulaBucket::new(BucketMode::Full)}
+            else {LogicFormulaBucket::new_with_formula(SimpleLogicNode::True, truth_table % 3)}
+        }).collect()
+    } //End get_buckets_with_a_missing_truth_table
+
+    #[test]
+    fn histograms_count_every_truth_table() {
+        let precompute_statistics = get_fixed_run_statistics();
+        assert_eq!(precompute_statistics.get_formula_count_histogram(), vec![(2, 3, 6), (4, 7, 8), (8, 15, 2)]);
+        assert_eq!(precompute_statistics.get_minimum_cost_histogram(), vec![(0, 6), (1, 8), (3, 2)]);
+        assert!(precompute_statistics.get_truth_tables_without_formula().is_empty());
+    }
+
+    #[test]
+    fn json_has_the_counts_of_a_fixed_run() {
+        let json_text = get_fixed_run_statistics().get_json();
+        for expected_line in ["{\n",
+                              "  \"num_booleans\": 2,\n",
+                              "  \"method\": \"normal\",\n",
+                              "  \"cost_model\": \"operators\",\n",
+                              "  \"formulas_per_truth_table\": [10, 4, 4, 3, 4, 3, 2, 4, 4, 2, 3, 4, 3, 4, 4, 10],\n",
+                              "  \"minimum_cost_histogram\": [{\"cost\": 0, \"truth_tables\": 6}, \
+                               {\"cost\": 1, \"truth_tables\": 8}, {\"cost\": 3, \"truth_tables\": 2}],\n",
+                              "  \"truth_tables_without_formula\": [],\n",
+                              "  \"formulas_generated\": 66,\n",
+                              "  \"clauses_pruned\": {\"tautological\": 0, \"duplicate\": 0, \"opposite\": 4, \
+                               \"subsumption\": 37, \"mergeable\": 0},\n",
+                              "  \"phases\": [{\"name\": \"Normal formulas\", \"seconds\": "].iter() {
+            assert!(json_text.contains(expected_line), "missing {:?} in {}", expected_line, json_text);
+        }
+        assert!(json_text.ends_with("}]\n}\n"));
+    }
+
+    #[test]
+    fn html_has_the_counts_of_a_fixed_run() {
+        let html_text = get_fixed_run_statistics().get_html();
+        for expected_text in ["<h1>Precompute Statistics for n = 2</h1>",
+                              "<p>Method: normal.  Cost: operators.</p>",
+                              "<th >Truth tables\n<td >16\n",
+                              "<th >Formulas in the buckets\n<td >68\n",
+                              "<th >Formulas generated\n<td >66\n",
+                              "<th >Clauses pruned by subsumption (each skips every formula that starts with it)\n\
+                               <td >37\n",
+                              "<th >2 to 3\n<td >6\n",
+                              "<th >8 to 15\n<td >2\n",
+                              "<th >3\n<td >2\n",
+                              "<p>Every truth table has a formula.</p>",
+                              "<th >Normal formulas\n<td >"].iter() {
+            assert!(html_text.contains(expected_text), "missing {:?}", expected_text);
+        }
+
+        //The longest bar is the full width.
+        assert!(html_text.contains(&format!("<th >1\n<td >8\n<td >{}\n", BAR_TEXT.repeat(MAX_BAR_WIDTH as usize))));
+    }
+
+    #[test]
+    fn truth_tables_without_formula_are_numbered_in_the_convention() {
+        //Internal truth table 2 is truth table 4 when the first boolean is the least significant bit.
+        let precompute_statistics =
+            PrecomputeStatistics::new(&get_buckets_with_a_missing_truth_table(), 2,
+                                      TruthTableConvention::FirstBooleanLeastSignificant, "exact",
+                                      FormulaCostModel::AigAndGates, None);
+        assert_eq!(precompute_statistics.get_truth_tables_without_formula(), vec![4]);
+        assert_eq!(precompute_statistics.get_minimum_cost_histogram(), vec![(0, 6), (1, 5), (2, 4)]);
+
+        let json_text = precompute_statistics.get_json();
+        assert!(json_text.contains("  \"truth_tables_without_formula\": [4],\n"));
+        assert!(json_text.contains("  \"formulas_generated\": null,\n  \"clauses_pruned\": null,\n"));
+        assert!(json_text.contains("  \"phases\": []\n"));
+
+        let html_text = precompute_statistics.get_html();
+        assert!(html_text.contains("<p>1 truth tables: 4</p>"));
+        assert!(!html_text.contains("Formulas generated"));
+    }
+} //End mod tests
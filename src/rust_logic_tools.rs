@@ -1,24 +1,67 @@
-/** This file runs Rust Logic Tools.  This is a command line tool.  Read "USAGE_TEXT" below for details.
+/* This file runs Rust Logic Tools.  This is a command line tool.  Read "USAGE_TEXT" below for details.
     Author: Steven Fletcher
     Created: 2020
-    Last Updated: 05/05/2021
+    Last Updated: 10/18/2026
 */
+//The code base writes "x = x + 1", "field : field", explicit returns, index loops and &Vec parameters on purpose.
+#![allow(clippy::assign_op_pattern, clippy::needless_return, clippy::ptr_arg, clippy::redundant_field_names,
+         clippy::needless_range_loop)]
+
+mod and_inverter_graph;
+mod binary_decision_diagram;
+mod bucket_verification;
+mod clause_iterator;
+mod complexity_measures;
+mod dot_text;
+mod formula_database;
+mod formula_parser;
 mod formula_precomputer;
+mod formula_shape_enumerator;
+mod functional_decomposition;
 mod html_text;
 mod logic;
+mod minimum_formula_synthesis;
+mod operator_basis;
+mod packed_formula;
+mod precompute_statistics;
 mod truth_table_size_5;
+mod zero_suppressed_decision_diagram;
 
+use and_inverter_graph::*;
+use binary_decision_diagram::*;
+use bucket_verification::*;
+use complexity_measures::*;
+use dot_text::*;
+use formula_database::*;
+use formula_parser::*;
 use formula_precomputer::*;
+use functional_decomposition::*;
 use html_text::*;
+use logic::*;
+use minimum_formula_synthesis::*;
+use operator_basis::*;
+use precompute_statistics::*;
 use std::env;
 use std::io::Write;
 use std::path::*;
-use std::time::SystemTime;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
 use truth_table_size_5::*;
+use zero_suppressed_decision_diagram::*;
 use std::fs::create_dir_all;
 
 const USAGE_TEXT : &str =
-"\nUsage: rust_logic_tools [-n {1 | 2 | 3 | 4 | 5}] [-output {html | text}]\n\
+"\nUsage: rust_logic_tools [-n {1 | 2 | 3 | 4}] [-output {html | text | dot | aiger}] [-bitorder {msb | lsb}] [-decompose] [-measures]\n\
+       [-dotoptions {none | labels,shared,highlight}] [-reorder {none | sift | exact}] [-reorderthreshold nodes]\n\
+       [-cost {operators | twoinput | aig}] [-method {normal | exact}] [-bucket {full | stream}] [-topk number]\n\
+       [-threads number] [-checkpoint seconds] [-resume] [-verifyduality] [-verify]\n\
+       [-progress seconds] [-memory megabytes]\n\
+       [-pruning {none | tautological,duplicate,opposite,subsumption,mergeable}]\n\
+       [-families {none | all | disjunction,equivalence,chain,conjunction,xor}] [-shapes number]\n\
+       [-bases {none | all | nand,nor,implication,reedmuller,majority}]\n\
+       rust_logic_tools lookup {-formula text | -truthtable number} [-bitorder {msb | lsb}]\n\
+       rust_logic_tools minimize {-formula text | -file path | -aiger path} [-cost {operators | twoinput | aig}]\n\
+       [-bitorder {msb | lsb}] [-reorderthreshold nodes]\n\
 At present, this program generates a large number of boolean formulas, calculates their truth tables, and determines \
 the smallest formula for each truth table.  If the output is text mode, it just outputs all the formulas generated in \
 a text file formulalist.txt.  This is more for testing purposes.  If the output is html mode, it generates pretty \
@@ -26,24 +69,165 @@ printed html files named truthtablesX.htm, where X is an integer.  In html mode,
 displays the formula with the least binary operators, followed by a list of all the formulas with that truth table.\n\
 The output is stored in the folder [user]\\Documents\\Loot Smuggler\\Rust Logic Tools\\\n\
 The defaults are -n 3 and -output text if you don't enter any parameters.\n\
-At present, the program is intractable for n >= 4.  I'm planning to make this work for n = 5 somehow.\n\n\
+Every run also writes the minimum formula of each truth table to a binary database, minimumformulas.db, for n <= 4.  \
+The lookup command reads it and prints the minimum formula equivalent to a formula or truth table, along with the \
+prime implicants of the truth table and its irredundant covers by prime implicants.  The covers are counted with \
+zero-suppressed decision diagrams, and a few of them are sampled.\n\
+The minimize command prints the minimum formula equivalent to a formula, to each line of a file of formulas or to \
+each output of an AIGER file, with its truth table and its cost before and after.  The minimum formula comes from \
+minimumformulas.db if it was written for enough booleans, and from exact synthesis otherwise, which works for \
+formulas over p1 to p4.  If the formula is already as cheap as the minimum formula, it's kept.  The minimum formula \
+is checked against the formula with binary decision diagrams, and if they differ, an assignment where they differ is \
+printed.\n\
+Every run also writes statistics.htm and statistics.json with the number of formulas of each truth table, the \
+distribution of the minimum formula costs, the truth tables with no formula, the number of formulas generated, the \
+number of clauses each pruning rule cut, and the time each phase took.\n\
+At present, the normal method is intractable for n >= 4.  The exact method handles n <= 4.  I'm planning to make this \
+work for n = 5 somehow.\n\n\
 Parameters:\n\
--n number determines the number of booleans per formula to precompute\n\
+-n number determines the number of booleans per formula to precompute.  It can be 1 to 4, since every truth table \
+gets a bucket and n = 5 would need 2^32 of them\n\
 -output html causes the output to be output as multiple .html files\n\
--output text causes the output to be output as a .txt file";
+-output text causes the output to be output as a .txt file\n\
+-output dot causes the output to be output as one Graphviz .dot file per truth table, named truthtableX.dot, with the \
+syntax tree of every formula, the BDD of the truth table and the AIG of the minimum formula\n\
+-output aiger causes the minimum formulas to be output as one and-inverter graph with an output for each truth table, \
+in both AIGER formats (minimumformulas.aag and minimumformulas.aig)\n\
+-reorder sift reorders each BDD of -output dot by sifting before it's drawn, and -reorder exact reorders it to the \
+order with the fewest nodes.  With labels, the BDD is labeled with its size and order before and after.  The total \
+number of nodes before and after is printed at the end.  The default is none\n\
+-reorderthreshold nodes reorders the BDDs of -output dot and of the minimize check by sifting whenever an operation \
+makes a BDD with more than that many nodes.  The threshold doubles when reordering can't get the BDD under half of \
+it.  By default, BDDs are only reordered by -reorder\n\
+-dotoptions is a comma separated list of the drawing options for -output dot.  labels labels the formulas and the BDD \
+edges, shared draws identical subformulas and BDD terminals once, and highlight draws the minimum formula in red.  \
+The default is labels,highlight\n\
+-cost operators chooses the minimum formula of each truth table by its number of binary operators (the default for \
+-method normal).  A conjunction or disjunction counts as 1, however many operands it has\n\
+-cost twoinput chooses the minimum formula of each truth table by its number of two-input operators, where a \
+conjunction or disjunction of k operands counts as k - 1 (the default for -method exact)\n\
+-cost aig chooses the minimum formula of each truth table by the number of AND gates in its and-inverter graph\n\
+-method normal enumerates CNF and DNF formulas and puts each one in the bucket of its truth table.  -bucket \
+determines how many of them each bucket keeps.  This is the default, since the other options for formulas only apply \
+to it and exact synthesis keeps just one formula per truth table\n\
+-method exact builds formulas in order of increasing size and keeps one formula per truth table with the fewest \
+two-input operators possible.  It works for n <= 4, and it only works with -cost twoinput, since it can't minimize \
+the other costs\n\
+-bucket stream keeps only the minimum formula, the number of formulas and the cheapest distinct formulas of each \
+truth table (the default)\n\
+-bucket full keeps every formula of each truth table.  This runs out of memory for n >= 4\n\
+-topk number determines how many of the cheapest distinct formulas -bucket stream keeps (the default is 10)\n\
+-threads number determines how many threads -method normal uses.  The output is the same for any number of threads.  \
+The default is the number of processors\n\
+-pruning is a comma separated list of the rules that stop -method normal from adding a clause.  tautological skips \
+clauses with a literal and its negation, duplicate skips clauses with the same literal twice, opposite skips a single \
+literal after its negation, subsumption skips clauses that contain an earlier clause, and mergeable skips clauses \
+that only differ from an earlier clause in the sign of one literal.  Turning rules off generates more formulas but \
+takes longer.  The number of clauses each rule cut is printed at the end.  This isn't the number of formulas it cut, \
+since each cut skips every formula that starts with the clauses up to the cut clause.  The clause generator never \
+makes tautological or duplicate clauses, so those two rules always cut 0 clauses for now.  The default is \
+tautological,duplicate,opposite,subsumption\n\
+-families is a comma separated list of the formula families that -method normal adds after the CNF and DNF formulas, \
+for every assignment of booleans and signs.  disjunction adds p <=> a | b | c with 2 or more disjuncts, equivalence \
+adds p <=> q <=> r with 2 or more booleans, chain adds (p <=> q) & (q <=> r) with 3 or more booleans, conjunction adds \
+p & (q <=> r) and xor adds p ^ q ^ r with 2 or more booleans.  all adds every family.  The default is none\n\
+-shapes number makes -method normal also add formulas of every shape with & and | up to that many binary operators, \
+like p1 & (p2 | p3).  Only formulas that are minimal for their truth table are added, and formulas that only differ \
+in the order of their operands are added once.  The default is 0, which doesn't add any\n\
+-bases is a comma separated list of the functionally complete sets of operators to also find the minimum formula of \
+each truth table over, by building formulas in order of increasing size like -method exact.  nand only uses NAND, nor \
+only uses NOR, implication uses -> and FALSE, reedmuller uses &, ^ and TRUE, and majority uses MAJ(p, q, r), ~, FALSE \
+and TRUE.  The booleans and the constants are free, and the cost is the number of other operators, including ~.  \
+Each basis gets its own html files, named truthtables[basis]X.htm, and basiscomparison.htm compares the costs of every \
+truth table in each basis.  It works for n <= 4.  The default is none\n\
+-checkpoint seconds determines how often -method normal saves its progress to precompute.checkpoint in the output \
+folder.  0 turns checkpoints off.  The default is 300\n\
+-resume continues an interrupted run from precompute.checkpoint.  The other parameters must be the same as the \
+interrupted run's, except for -threads, -checkpoint, -progress and -memory.  The output is the same as an \
+uninterrupted run's\n\
+-verifyduality makes -method normal check the truth table of every CNF formula, which is derived from the DNF formula \
+with the same clauses by complementing it and reversing its rows.  The number of wrong truth tables is printed at the \
+end.  This is slow, so it's meant for small n\n\
+-verify checks every formula kept in every bucket by evaluating it in each row of the truth table, independently of \
+the bitmasks that put it there, and checks that the minimum formula of each bucket has the right cost and that no \
+formula kept with it is cheaper.  Every mismatch is printed with the formula and the row, up to 100 of them, and \
+the program exits with status 1 after writing its output.  -bucket stream, the default, only keeps the cheapest \
+formulas of each truth table, so only those are checked.  Use -bucket full to check every formula\n\
+-progress seconds determines how often -method normal prints its progress: the clause prefixes done, the formulas \
+generated, the truth tables found, the estimated time left and the resident memory.  0 turns progress off.  The \
+default is 10\n\
+-memory megabytes is the most resident memory -method normal should use.  If -bucket full goes over it, the buckets \
+switch to -bucket stream -topk 10.  If streaming buckets go over it and the memory keeps growing, the run saves a \
+checkpoint and stops without writing any output, and it can be resumed with -bucket stream -topk 10 and a bigger \
+-memory.  The resident memory is only known on Linux.  The default is 0, which has no limit\n\
+-bitorder msb numbers truth tables with the first boolean as the most significant bit of the row index\n\
+-bitorder lsb numbers truth tables with the first boolean as the least significant bit of the row index (like ABC)\n\
+-bitorder also sets the order of the truth tables in the output files.  The rows of each html truth table are listed \
+from the highest row index to the lowest, so the row where every boolean is True always comes first\n\
+-decompose adds the disjoint-support decomposition of each truth table to the html files, followed by the \
+decomposition chart of every bound set with an Ashenhurst or Curtis decomposition and the number of functions of the \
+bound set it needs\n\
+-measures adds the sensitivity, block sensitivity, certificate complexity, influences and decision tree depth of \
+each truth table to the html files\n\
+-formula text is the formula that lookup and minimize find the minimum formula of, over p1 to pn, written with ~, \
+&, ^, |, <=>, parentheses, TRUE and FALSE\n\
+-truthtable number looks up the minimum formula of a truth table.  The number can be decimal, hexadecimal with 0x or \
+binary with 0b\n\
+-file path minimizes each formula in a file, one per line.  Blank lines are skipped\n\
+-aiger path minimizes each output of an and-inverter graph in an AIGER file, in either format.  Its inputs are p1 to pn, \
+and it can't have latches";
 
 fn main() {
     let start_time = SystemTime::now();
 
     //Parameters
-    let mut html_mode                    : bool = false;
+    let mut output_mode                  : OutputMode = OutputMode::Text;
+    let mut dot_options                  : DotOptions = DotOptions::new();
+    let mut reordering_method            : Option<ReorderingMethod> = None;
+    let mut auto_reordering_threshold    : Option<usize> = None;
+    let mut cost_model_option            : Option<FormulaCostModel> = None;
+    let mut use_exact_synthesis          : bool = false;
+    let mut keep_full_buckets            : bool = false;
+    let mut max_formulas_per_bucket      : usize = DEFAULT_MAX_FORMULAS_PER_BUCKET;
+    let mut num_threads                  : usize = thread::available_parallelism().map_or(1, |number| number.get());
+    let mut checkpoint_interval_seconds  : u64 = DEFAULT_CHECKPOINT_INTERVAL_SECONDS;
+    let mut resume_from_checkpoint       : bool = false;
+    let mut verify_duality               : bool = false;
+    let mut verify_buckets               : bool = false;
+    let mut progress_interval_seconds    : u64 = DEFAULT_PROGRESS_INTERVAL_SECONDS;
+    let mut memory_budget_megabytes      : u64 = 0;
+    let mut pruning_settings             : PruningSettings = PruningSettings::new();
+    let mut family_settings              : FormulaFamilySettings = FormulaFamilySettings::new();
+    let mut max_shape_operators          : u32 = 0;
+    let mut operator_basis_vec           : Vec<OperatorBasis> = Vec::new();
+    let mut include_decomposition        : bool = false;
+    let mut include_complexity_measures  : bool = false;
     let mut num_booleans_to_precompute   : u32 = 3;
+    let mut convention                   : TruthTableConvention = TruthTableConvention::FirstBooleanMostSignificant;
+    let mut lookup_formula_text          : Option<String> = None;
+    let mut lookup_truth_table_text      : Option<String> = None;
+    let mut formula_file_path            : Option<String> = None;
+    let mut aiger_file_path              : Option<String> = None;
 
     //Read arguments.
     let mut argument_mode = ArgumentMode::Default;
-    let mut env_iterator = env::args();
+    let mut env_iterator = env::args().peekable();
     env_iterator.next(); //Skip the name of the program
 
+    //The command is optional.  Without one, the formulas are precomputed.
+    let program_command =
+        match env_iterator.peek().map(|argument| argument.as_str()) {
+            Some("lookup") => {
+                env_iterator.next();
+                ProgramCommand::Lookup
+            },
+            Some("minimize") => {
+                env_iterator.next();
+                ProgramCommand::Minimize
+            },
+            _ => ProgramCommand::Precompute
+        };
+
     for argument in env_iterator {
         match argument_mode {
             ArgumentMode::Default => {
@@ -53,6 +237,81 @@ fn main() {
                 else if argument == "-output" {
                     argument_mode = ArgumentMode::Output;
                 }
+                else if argument == "-bitorder" {
+                    argument_mode = ArgumentMode::BitOrder;
+                }
+                else if argument == "-dotoptions" {
+                    argument_mode = ArgumentMode::DotOptions;
+                }
+                else if argument == "-reorder" {
+                    argument_mode = ArgumentMode::Reorder;
+                }
+                else if argument == "-reorderthreshold" {
+                    argument_mode = ArgumentMode::ReorderThreshold;
+                }
+                else if argument == "-cost" {
+                    argument_mode = ArgumentMode::Cost;
+                }
+                else if argument == "-method" {
+                    argument_mode = ArgumentMode::Method;
+                }
+                else if argument == "-bucket" {
+                    argument_mode = ArgumentMode::Bucket;
+                }
+                else if argument == "-topk" {
+                    argument_mode = ArgumentMode::TopK;
+                }
+                else if argument == "-threads" {
+                    argument_mode = ArgumentMode::Threads;
+                }
+                else if argument == "-pruning" {
+                    argument_mode = ArgumentMode::Pruning;
+                }
+                else if argument == "-families" {
+                    argument_mode = ArgumentMode::Families;
+                }
+                else if argument == "-shapes" {
+                    argument_mode = ArgumentMode::Shapes;
+                }
+                else if argument == "-bases" {
+                    argument_mode = ArgumentMode::Bases;
+                }
+                else if argument == "-checkpoint" {
+                    argument_mode = ArgumentMode::Checkpoint;
+                }
+                else if argument == "-resume" {
+                    resume_from_checkpoint = true;
+                }
+                else if argument == "-verifyduality" {
+                    verify_duality = true;
+                }
+                else if argument == "-verify" {
+                    verify_buckets = true;
+                }
+                else if argument == "-progress" {
+                    argument_mode = ArgumentMode::Progress;
+                }
+                else if argument == "-memory" {
+                    argument_mode = ArgumentMode::Memory;
+                }
+                else if argument == "-formula" {
+                    argument_mode = ArgumentMode::Formula;
+                }
+                else if argument == "-truthtable" {
+                    argument_mode = ArgumentMode::TruthTable;
+                }
+                else if argument == "-file" {
+                    argument_mode = ArgumentMode::File;
+                }
+                else if argument == "-aiger" {
+                    argument_mode = ArgumentMode::Aiger;
+                }
+                else if argument == "-decompose" {
+                    include_decomposition = true;
+                }
+                else if argument == "-measures" {
+                    include_complexity_measures = true;
+                }
                 else {
                     argument_mode = ArgumentMode::Error;
                     break;
@@ -62,10 +321,15 @@ fn main() {
             ArgumentMode::N => {
                 match argument.parse::<u32>() {
                     Ok(number) => {
-                        if number == 0 || number > MAX_BOOLEANS_TO_PRECOMPUTE {
+                        if number == 0 {
                             argument_mode = ArgumentMode::Error;
                             break;
                         }
+                        if number > MAX_BOOLEANS_TO_PRECOMPUTE {
+                            eprintln!("Error: -n {} is too big.  -n needs n <= {}, since every truth table gets a \
+                                       bucket and n = 5 would need 2^32 of them.", number, MAX_BOOLEANS_TO_PRECOMPUTE);
+                            std::process::exit(1);
+                        }
 
                         //else
                         num_booleans_to_precompute = number;
@@ -80,17 +344,239 @@ fn main() {
             ArgumentMode::Output => {
                 if argument == "html" {
                     argument_mode = ArgumentMode::Default;
-                    html_mode = true;
+                    output_mode = OutputMode::Html;
                 }
                 else if argument == "text" {
                     argument_mode = ArgumentMode::Default;
-                    html_mode = false;
+                    output_mode = OutputMode::Text;
+                }
+                else if argument == "dot" {
+                    argument_mode = ArgumentMode::Default;
+                    output_mode = OutputMode::Dot;
+                }
+                else if argument == "aiger" {
+                    argument_mode = ArgumentMode::Default;
+                    output_mode = OutputMode::Aiger;
+                }
+                else {
+                    argument_mode = ArgumentMode::Error;
+                    break;
+                }
+            }, //End ArgumentMode::Output
+            ArgumentMode::BitOrder => {
+                match TruthTableConvention::parse(&argument) {
+                    Some(parsed_convention) => {
+                        convention = parsed_convention;
+                        argument_mode = ArgumentMode::Default;
+                    },
+                    None => {
+                        argument_mode = ArgumentMode::Error;
+                        break;
+                    }
+                } //End match parse convention
+            }, //End ArgumentMode::BitOrder
+            ArgumentMode::DotOptions => {
+                match DotOptions::parse(&argument) {
+                    Some(parsed_options) => {
+                        dot_options = parsed_options;
+                        argument_mode = ArgumentMode::Default;
+                    },
+                    None => {
+                        argument_mode = ArgumentMode::Error;
+                        break;
+                    }
+                } //End match parse options
+            }, //End ArgumentMode::DotOptions
+            ArgumentMode::Reorder => {
+                if argument == "none" {
+                    reordering_method = None;
+                    argument_mode = ArgumentMode::Default;
+                }
+                else if let Some(parsed_method) = ReorderingMethod::parse(&argument) {
+                    reordering_method = Some(parsed_method);
+                    argument_mode = ArgumentMode::Default;
+                }
+                else {
+                    argument_mode = ArgumentMode::Error;
+                    break;
+                }
+            }, //End ArgumentMode::Reorder
+            ArgumentMode::ReorderThreshold => {
+                match argument.parse::<usize>() {
+                    Ok(number) => {
+                        auto_reordering_threshold = Some(number);
+                        argument_mode = ArgumentMode::Default;
+                    },
+                    Err(_) => {
+                        argument_mode = ArgumentMode::Error;
+                        break;
+                    }
+                } //End match parse argument
+            }, //End ArgumentMode::ReorderThreshold
+            ArgumentMode::Cost => {
+                match FormulaCostModel::parse(&argument) {
+                    Some(parsed_cost_model) => {
+                        cost_model_option = Some(parsed_cost_model);
+                        argument_mode = ArgumentMode::Default;
+                    },
+                    None => {
+                        argument_mode = ArgumentMode::Error;
+                        break;
+                    }
+                } //End match parse cost model
+            }, //End ArgumentMode::Cost
+            ArgumentMode::Method => {
+                if argument == "normal" {
+                    argument_mode = ArgumentMode::Default;
+                    use_exact_synthesis = false;
+                }
+                else if argument == "exact" {
+                    argument_mode = ArgumentMode::Default;
+                    use_exact_synthesis = true;
                 }
                 else {
                     argument_mode = ArgumentMode::Error;
                     break;
                 }
-            } //End ArgumentMode::Output
+            }, //End ArgumentMode::Method
+            ArgumentMode::Bucket => {
+                if argument == "full" {
+                    argument_mode = ArgumentMode::Default;
+                    keep_full_buckets = true;
+                }
+                else if argument == "stream" {
+                    argument_mode = ArgumentMode::Default;
+                    keep_full_buckets = false;
+                }
+                else {
+                    argument_mode = ArgumentMode::Error;
+                    break;
+                }
+            }, //End ArgumentMode::Bucket
+            ArgumentMode::TopK => {
+                match argument.parse::<usize>() {
+                    Ok(number) => {
+                        max_formulas_per_bucket = number;
+                        argument_mode = ArgumentMode::Default;
+                    },
+                    Err(_) => {
+                        argument_mode = ArgumentMode::Error;
+                        break;
+                    }
+                } //End match parse argument
+            } //End ArgumentMode::TopK
+            ArgumentMode::Threads => {
+                match argument.parse::<usize>() {
+                    Ok(number) if number > 0 => {
+                        num_threads = number;
+                        argument_mode = ArgumentMode::Default;
+                    },
+                    _ => {
+                        argument_mode = ArgumentMode::Error;
+                        break;
+                    }
+                } //End match parse argument
+            } //End ArgumentMode::Threads
+            ArgumentMode::Pruning => {
+                match PruningSettings::parse(&argument) {
+                    Some(parsed_settings) => {
+                        pruning_settings = parsed_settings;
+                        argument_mode = ArgumentMode::Default;
+                    },
+                    None => {
+                        argument_mode = ArgumentMode::Error;
+                        break;
+                    }
+                } //End match parse settings
+            }, //End ArgumentMode::Pruning
+            ArgumentMode::Families => {
+                match FormulaFamilySettings::parse(&argument) {
+                    Some(parsed_settings) => {
+                        family_settings = parsed_settings;
+                        argument_mode = ArgumentMode::Default;
+                    },
+                    None => {
+                        argument_mode = ArgumentMode::Error;
+                        break;
+                    }
+                } //End match parse settings
+            }, //End ArgumentMode::Families
+            ArgumentMode::Shapes => {
+                match argument.parse::<u32>() {
+                    Ok(number) => {
+                        max_shape_operators = number;
+                        argument_mode = ArgumentMode::Default;
+                    },
+                    Err(_) => {
+                        argument_mode = ArgumentMode::Error;
+                        break;
+                    }
+                } //End match parse argument
+            } //End ArgumentMode::Shapes
+            ArgumentMode::Bases => {
+                match OperatorBasis::parse_list(&argument) {
+                    Some(parsed_bases) => {
+                        operator_basis_vec = parsed_bases;
+                        argument_mode = ArgumentMode::Default;
+                    },
+                    None => {
+                        argument_mode = ArgumentMode::Error;
+                        break;
+                    }
+                } //End match parse bases
+            }, //End ArgumentMode::Bases
+            ArgumentMode::Checkpoint => {
+                match argument.parse::<u64>() {
+                    Ok(number) => {
+                        checkpoint_interval_seconds = number;
+                        argument_mode = ArgumentMode::Default;
+                    },
+                    Err(_) => {
+                        argument_mode = ArgumentMode::Error;
+                        break;
+                    }
+                } //End match parse argument
+            }, //End ArgumentMode::Checkpoint
+            ArgumentMode::Progress => {
+                match argument.parse::<u64>() {
+                    Ok(number) => {
+                        progress_interval_seconds = number;
+                        argument_mode = ArgumentMode::Default;
+                    },
+                    Err(_) => {
+                        argument_mode = ArgumentMode::Error;
+                        break;
+                    }
+                } //End match parse argument
+            }, //End ArgumentMode::Progress
+            ArgumentMode::Memory => {
+                match argument.parse::<u64>() {
+                    Ok(number) => {
+                        memory_budget_megabytes = number;
+                        argument_mode = ArgumentMode::Default;
+                    },
+                    Err(_) => {
+                        argument_mode = ArgumentMode::Error;
+                        break;
+                    }
+                } //End match parse argument
+            }, //End ArgumentMode::Memory
+            ArgumentMode::Formula => {
+                lookup_formula_text = Some(argument);
+                argument_mode = ArgumentMode::Default;
+            }, //End ArgumentMode::Formula
+            ArgumentMode::TruthTable => {
+                lookup_truth_table_text = Some(argument);
+                argument_mode = ArgumentMode::Default;
+            }, //End ArgumentMode::TruthTable
+            ArgumentMode::File => {
+                formula_file_path = Some(argument);
+                argument_mode = ArgumentMode::Default;
+            }, //End ArgumentMode::File
+            ArgumentMode::Aiger => {
+                aiger_file_path = Some(argument);
+                argument_mode = ArgumentMode::Default;
+            } //End ArgumentMode::Aiger
         } //End match mode
     } //End for each argument
 
@@ -98,44 +584,247 @@ fn main() {
     match argument_mode {
         ArgumentMode::Default => {},
         _ => {
-            panic!(USAGE_TEXT);
+            panic!("{}", USAGE_TEXT);
         }
     } //End match argument_mode to make sure it is the default
 
-    //Compute truth tables for all non-trivial CNFs and DNFs with the specified number of booleans.
-    let tt_bucket_vec : Vec<LogicFormulaBucket> = generate_truth_tables_with_up_to_n_variables(num_booleans_to_precompute);
+    //The exact method only minimizes two-input operators, so that's its default cost.
+    let cost_model = cost_model_option.unwrap_or(
+        if use_exact_synthesis {FormulaCostModel::TwoInputOperators} else {FormulaCostModel::BinaryOperators});
+
+    //The other operator bases are found exactly, which only works for small n.
+    if !operator_basis_vec.is_empty() && num_booleans_to_precompute > MAX_BOOLEANS_FOR_EXACT_SYNTHESIS {
+        panic!("-bases needs n <= {}\n{}", MAX_BOOLEANS_FOR_EXACT_SYNTHESIS, USAGE_TEXT);
+    }
+
+    //Only lookup uses -formula and -truthtable, and it needs exactly one of them.  Only minimize uses -file and
+    //-aiger, and it needs exactly one of -formula, -file and -aiger.
+    let has_lookup_input = lookup_formula_text.is_some() || lookup_truth_table_text.is_some();
+    let has_minimize_file = formula_file_path.is_some() || aiger_file_path.is_some();
+    match program_command {
+        ProgramCommand::Precompute => {
+            if has_lookup_input || has_minimize_file {panic!("{}", USAGE_TEXT);}
+        },
+        ProgramCommand::Minimize => {
+            let num_minimize_inputs = [lookup_formula_text.is_some(), formula_file_path.is_some(),
+                                       aiger_file_path.is_some()].iter().filter(|is_given| **is_given).count();
+            if lookup_truth_table_text.is_some() || num_minimize_inputs != 1 {
+                panic!("{}", USAGE_TEXT);
+            }
+
+            let mut formula_minimizer = FormulaMinimizer::new(&generate_output_directory(), cost_model, convention,
+                                                              auto_reordering_threshold);
+            match (lookup_formula_text, formula_file_path, aiger_file_path) {
+                (Some(formula_text), _, _) => {
+                    if let Err(error_message) = formula_minimizer.minimize(&formula_text) {
+                        panic!("{}\n{}", error_message, USAGE_TEXT);
+                    }
+                },
+                (None, Some(formula_file_path), _) => {
+                    let file_text =
+                        match std::fs::read_to_string(&formula_file_path) {
+                            Ok(file_text) => file_text,
+                            Err(message) => panic!("Cannot read {}: {}\n{}", formula_file_path, message, USAGE_TEXT)
+                        };
+
+                    //A bad line doesn't stop the other lines from being minimized.
+                    for (line_index, line) in file_text.lines().enumerate() {
+                        if line.trim().is_empty() {continue;}
+                        if let Err(error_message) = formula_minimizer.minimize(line.trim()) {
+                            println!("Line {}: {}", line_index + 1, error_message);
+                        }
+                        println!();
+                    } //End for each line
+                },
+                (None, None, Some(aiger_file_path)) => {
+                    let aig =
+                        match std::fs::read(&aiger_file_path) {
+                            Ok(bytes) => match AndInverterGraph::read_aiger(&bytes) {
+                                Ok(aig) => aig,
+                                Err(message) => panic!("Cannot read {}: {}\n{}", aiger_file_path, message, USAGE_TEXT)
+                            },
+                            Err(message) => panic!("Cannot read {}: {}\n{}", aiger_file_path, message, USAGE_TEXT)
+                        };
+
+                    //Each output is minimized like a line of a -file.
+                    for (output_index, output) in aig.get_outputs().iter().enumerate() {
+                        if let Err(error_message) = formula_minimizer.minimize_aig_output(&aig, *output) {
+                            println!("Output {}: {}", output_index, error_message);
+                        }
+                        println!();
+                    } //End for each output
+                },
+                (None, None, None) => {}
+            } //End match the formulas to minimize
+            return;
+        },
+        ProgramCommand::Lookup => {
+            if lookup_formula_text.is_some() == lookup_truth_table_text.is_some() || has_minimize_file {
+                panic!("{}", USAGE_TEXT);
+            }
+
+            let lookup_result = look_up_minimum_formula(&generate_output_directory(), lookup_formula_text,
+                                                        lookup_truth_table_text, convention);
+            if let Err(error_message) = lookup_result {
+                panic!("{}\n{}", error_message, USAGE_TEXT);
+            }
+            return;
+        }
+    } //End match program_command
 
     //Create the output directory.
     let output_directory = generate_output_directory();
 
+    //Compute truth tables for all non-trivial CNFs and DNFs with the specified number of booleans, or find the
+    //minimum formula of each truth table exactly.
+    let phase_start_time = Instant::now();
+    let (tt_bucket_vec, precompute_report_option) : (Vec<LogicFormulaBucket>, Option<PrecomputeReport>) =
+        if use_exact_synthesis {
+            match generate_minimum_formulas_exactly(num_booleans_to_precompute, cost_model) {
+                Ok(formula_buckets) => (formula_buckets, None),
+                Err(error_message) => panic!("{}\n{}", error_message, USAGE_TEXT)
+            }
+        }
+        else {
+            let bucket_mode = if keep_full_buckets {BucketMode::Full} else {BucketMode::Stream(max_formulas_per_bucket)};
+            let mut checkpoint_filepath = output_directory.clone();
+            checkpoint_filepath.push(CHECKPOINT_FILE_NAME);
+            let checkpoint_settings = CheckpointSettings {
+                filepath : checkpoint_filepath,
+                interval : Duration::from_secs(checkpoint_interval_seconds),
+                resume : resume_from_checkpoint
+            };
+
+            //Without checkpoints, a run can't be resumed.
+            let checkpoint_settings_option =
+                if checkpoint_interval_seconds > 0 || resume_from_checkpoint {Some(&checkpoint_settings)} else {None};
+            let precompute_settings = PrecomputeSettings {
+                cost_model : cost_model,
+                bucket_mode : bucket_mode,
+                num_threads : num_threads,
+                pruning_settings : pruning_settings,
+                family_settings : family_settings,
+                max_shape_operators : max_shape_operators,
+                verify_duality : verify_duality,
+                progress_interval : Duration::from_secs(progress_interval_seconds),
+                memory_budget : if memory_budget_megabytes > 0 {Some(memory_budget_megabytes * 1024 * 1024)} else {None}
+            };
+            match generate_truth_tables_with_up_to_n_variables(num_booleans_to_precompute, &precompute_settings,
+                                                               checkpoint_settings_option) {
+                Ok((formula_buckets, precompute_report)) => {
+                    print!("{}", precompute_report.get_as_text(&pruning_settings));
+
+                    //A run that stopped early only has some of the formulas, so don't write anything.
+                    if !precompute_report.is_complete() {return;}
+                    (formula_buckets, Some(precompute_report))
+                },
+                Err(error_message) => panic!("{}\n{}", error_message, USAGE_TEXT)
+            }
+        };
+
+    let generation_duration = phase_start_time.elapsed();
+
+    //Check the buckets against an independent evaluation of their formulas.
+    let phase_start_time = Instant::now();
+    let mut is_verified = true;
+    if verify_buckets {
+        let verification_report = verify_formula_buckets(&tt_bucket_vec, num_booleans_to_precompute, cost_model,
+                                                          convention);
+        print!("{}", verification_report.get_as_text());
+        is_verified = verification_report.is_correct();
+    }
+    let verification_duration = phase_start_time.elapsed();
+
     //Generate the names of the booleans.
     let mut boolean_name_list = Vec::with_capacity(num_booleans_to_precompute as usize);
     for i in 1..=num_booleans_to_precompute {
         boolean_name_list.push(format!("p{}", i));
     }
 
-    //Write the data to file.
-    if html_mode {
-        write_formula_list_to_html_files(&output_directory, &tt_bucket_vec, &boolean_name_list);
+    //Collect the statistics before the output, which can use up the buckets.
+    let method_name = if use_exact_synthesis {EXACT_METHOD_NAME} else {NORMAL_METHOD_NAME};
+    let mut precompute_statistics = PrecomputeStatistics::new(&tt_bucket_vec, num_booleans_to_precompute, convention,
+                                                              method_name, cost_model, precompute_report_option);
+    if use_exact_synthesis {
+        precompute_statistics.add_phase("Exact synthesis", generation_duration);
+    }
+    if verify_buckets {
+        precompute_statistics.add_phase("Verification", verification_duration);
     }
-    else {
-        write_formula_list_to_text_file(&output_directory, tt_bucket_vec, &boolean_name_list);
+
+    //Save the minimum formulas so they can be looked up later.
+    let phase_start_time = Instant::now();
+    if num_booleans_to_precompute <= MAX_BOOLEANS_IN_FORMULA_DATABASE {
+        write_formula_database_file(&output_directory, &tt_bucket_vec, num_booleans_to_precompute, cost_model);
     }
+    precompute_statistics.add_phase("Formula database", phase_start_time.elapsed());
+
+    //Find the minimum formulas over the other operator bases.
+    if !operator_basis_vec.is_empty() {
+        let phase_start_time = Instant::now();
+        let mut basis_bucket_vec = Vec::with_capacity(operator_basis_vec.len());
+        for operator_basis in operator_basis_vec {
+            match generate_minimum_basis_formulas(num_booleans_to_precompute, operator_basis) {
+                Ok(bucket_vec) => basis_bucket_vec.push((operator_basis, bucket_vec)),
+                Err(error_message) => panic!("{}\n{}", error_message, USAGE_TEXT)
+            }
+        } //End for each operator basis
+        write_basis_formulas_to_html_files(&output_directory, &basis_bucket_vec, &boolean_name_list, convention);
+        precompute_statistics.add_phase("Operator bases", phase_start_time.elapsed());
+    } //End if there are operator bases
+
+    //Write the data to file.
+    let phase_start_time = Instant::now();
+    match output_mode {
+        OutputMode::Html => write_formula_list_to_html_files(&output_directory, &tt_bucket_vec, &boolean_name_list,
+                                                             convention, include_decomposition,
+                                                             include_complexity_measures),
+        OutputMode::Text => write_formula_list_to_text_file(&output_directory, &tt_bucket_vec, &boolean_name_list,
+                                                            convention),
+        OutputMode::Dot => write_formula_list_to_dot_files(&output_directory, &tt_bucket_vec, &boolean_name_list,
+                                                           convention, &dot_options, reordering_method,
+                                                           auto_reordering_threshold),
+        OutputMode::Aiger => write_minimum_formulas_to_aiger_files(&output_directory, &tt_bucket_vec,
+                                                                   &boolean_name_list, convention)
+    } //End match output_mode
+    precompute_statistics.add_phase("Output", phase_start_time.elapsed());
+    write_statistics_files(&output_directory, &precompute_statistics);
 
     //End the program.
     let end_time = SystemTime::now();
     println!("Total Execution Time = {:?}", end_time.duration_since(start_time));
+
+    //A failed verification is an error, even though the output was written.
+    if !is_verified {
+        std::process::exit(1);
+    }
 } //End main
 
 //CONSTANTS////////////////////////////////////////////////////////////////////////////////////////////////////////////
-const MAX_BOOLEANS_TO_PRECOMPUTE : u32 = 5;
+const MAX_BOOLEANS_TO_PRECOMPUTE : u32 = MAX_BOOLEANS_FOR_NORMAL_FORMULAS;
+const MAX_BOOLEANS_IN_TRUTH_TABLE : u32 = 5;
+const DEFAULT_PROGRESS_INTERVAL_SECONDS : u64 = 10;
+const DEFAULT_CHECKPOINT_INTERVAL_SECONDS : u64 = 300;
 const NUM_TRUTH_TABLES_PER_FILE : u32 = 256;
 
 const HTML_FILE_EXTENSION: &str = "htm";
+const DOT_FILE_EXTENSION: &str = "dot";
 const TRUTH_TABLE_FILE_NAME_PREFIX : &str = "truthtables";
+const DOT_FILE_NAME_PREFIX : &str = "truthtable";
 const TRUTH_TABLE_SUBDIRECTORIES : [&str;2] = ["Loot Smuggler", "Rust Logic Tools"];
 
 const FORMULA_LIST_FILE_NAME : &str = "formulalist.txt";
+const AIGER_ASCII_FILE_NAME : &str = "minimumformulas.aag";
+const AIGER_BINARY_FILE_NAME : &str = "minimumformulas.aig";
+const FORMULA_DATABASE_FILE_NAME : &str = "minimumformulas.db";
+const BASIS_COMPARISON_FILE_NAME : &str = "basiscomparison.htm";
+const STATISTICS_HTML_FILE_NAME : &str = "statistics.htm";
+const STATISTICS_JSON_FILE_NAME : &str = "statistics.json";
+const NORMAL_METHOD_NAME : &str = "normal";
+const EXACT_METHOD_NAME : &str = "exact";
+const CHECKPOINT_FILE_NAME : &str = "precompute.checkpoint";
+const NUM_SAMPLED_PRIME_COVERS : usize = 3;
+const PRIME_COVER_SAMPLE_SEED : u64 = 1;
 
 //CLASSES//////////////////////////////////////////////////////////////////////////////////////////////////////////////
 ///This enum enumerates different ArgumentModes for parsing the command line arguments.
@@ -143,10 +832,173 @@ enum ArgumentMode {
     Default,
     N,
     Output,
+    BitOrder,
+    DotOptions,
+    Reorder,
+    ReorderThreshold,
+    Cost,
+    Method,
+    Bucket,
+    TopK,
+    Threads,
+    Pruning,
+    Families,
+    Shapes,
+    Bases,
+    Checkpoint,
+    Progress,
+    Memory,
+    Formula,
+    TruthTable,
+    File,
+    Aiger,
 
     Error
 } //End enum ArgumentMode
 
+///This enum enumerates the commands.  The command is the first argument, if there is one.
+enum ProgramCommand {
+    Precompute,
+    Lookup,
+    Minimize
+} //End enum ProgramCommand
+
+///This enum enumerates the kinds of output files.
+enum OutputMode {
+    Text,
+    Html,
+    Dot,
+    Aiger
+} //End enum OutputMode
+
+///This struct finds the minimum formula equivalent to each formula for the minimize command.  It loads the formula
+///database once and keeps the exact synthesizer of each number of booleans it's needed for.
+struct FormulaMinimizer {
+    formula_database_option : Option<FormulaDatabase>,
+    synthesizer_vec : Vec<Option<MinimumFormulaSynthesizer>>,  //synthesizer_vec[n] is for n booleans
+    boolean_name_list : Vec<String>,                           //p1 to p5
+    cost_model : FormulaCostModel,
+    convention : TruthTableConvention,
+    auto_reordering_threshold : Option<usize> //For the BDDs that check the minimum formula
+} //End struct FormulaMinimizer
+
+impl FormulaMinimizer {
+    ///Creates a minimizer that uses the formula database in table_dir_path, if there is one.
+    fn new(table_dir_path : &PathBuf, cost_model : FormulaCostModel, convention : TruthTableConvention,
+           auto_reordering_threshold : Option<usize>) -> FormulaMinimizer
+    {
+        let mut database_filepath = table_dir_path.clone();
+        database_filepath.push(FORMULA_DATABASE_FILE_NAME);
+        FormulaMinimizer {
+            formula_database_option : FormulaDatabase::read_from_file(&database_filepath).ok(),
+            synthesizer_vec : (0..=MAX_BOOLEANS_FOR_EXACT_SYNTHESIS).map(|_| None).collect(),
+            boolean_name_list : (1..=MAX_BOOLEANS_IN_TRUTH_TABLE).map(|i| format!("p{}", i)).collect(),
+            cost_model : cost_model,
+            convention : convention,
+            auto_reordering_threshold : auto_reordering_threshold
+        }
+    } //End new
+
+    ///Prints the truth table of a formula, its minimum formula and the cost of both.
+    ///Return value: an error message if the formula isn't valid or no minimum formula can be found
+    fn minimize(&mut self, formula_text : &str) -> Result<(), String> {
+        println!("Formula: {}", formula_text);
+        let formula = parse_formula(formula_text, &self.boolean_name_list)?;
+        self.minimize_formula(&formula)
+    } //End minimize
+
+    ///Prints the formula of an output of an AIG, and minimizes it like minimize.  The inputs of the AIG are p1 to pn.
+    ///Return value: an error message if the AIG has too many inputs or no minimum formula can be found
+    fn minimize_aig_output(&mut self, aig : &AndInverterGraph, output : AigLiteral) -> Result<(), String> {
+        if aig.get_num_inputs() > MAX_BOOLEANS_IN_TRUTH_TABLE {
+            return Err(format!("The AIG has {} inputs, but formulas can only have {} booleans", aig.get_num_inputs(),
+                               MAX_BOOLEANS_IN_TRUTH_TABLE));
+        }
+
+        let formula = aig.to_formula(output);
+        println!("Formula: {}", formula.get_as_text(&self.boolean_name_list));
+        self.minimize_formula(&formula)
+    } //End minimize_aig_output
+
+    ///Prints the truth table of a parsed formula, its minimum formula and the cost of both.  The minimum formula is
+    ///checked against the formula with BDDs.
+    ///Return value: an error message if no minimum formula can be found or it isn't equivalent to the formula
+    fn minimize_formula(&mut self, formula : &SimpleLogicNode) -> Result<(), String> {
+        let num_booleans = formula.get_max_variable_index().max(1);
+        let truth_table = TruthTableSize5Computer::new(num_booleans).compute_truth_table(formula) &
+                          get_truth_table_mask(num_booleans);
+        println!("Truth Table: {} (n = {})", convert_truth_table_from_internal(truth_table, num_booleans,
+                                                                               self.convention), num_booleans);
+
+        let (minimum_formula, source_text) =
+            match self.look_up_minimum_formula(formula, num_booleans)? {
+                Some(minimum_formula) => (minimum_formula, "formula database"),
+                None => (self.synthesize_minimum_formula(truth_table, num_booleans)?, "exact synthesis")
+            };
+
+        //The database formulas can be over more booleans than the formula, even though they don't depend on them.
+        let cost_num_booleans = num_booleans.max(minimum_formula.get_max_variable_index());
+        let cost_before = self.cost_model.compute_cost(formula, cost_num_booleans);
+        let cost_after = self.cost_model.compute_cost(&minimum_formula, cost_num_booleans);
+
+        //Check the minimum formula with BDDs before trusting the database or the synthesizer.  If it's wrong, show an
+        //assignment where the two formulas differ.
+        let mut bdd_manager = BddManager::new(cost_num_booleans);
+        bdd_manager.set_auto_reordering_threshold(self.auto_reordering_threshold);
+        if !bdd_manager.are_equivalent(formula, &minimum_formula) {
+            let formula_bdd = bdd_manager.build_from_formula(formula);
+            let minimum_bdd = bdd_manager.build_from_formula(&minimum_formula);
+            let difference = bdd_manager.apply(BddOperator::ExclusiveDisjunction, formula_bdd, minimum_bdd);
+            let literal_text_vec : Vec<String> = bdd_manager.get_satisfying_cubes(difference)[0].iter()
+                .map(|literal| SimpleLogicNode::Literal(*literal).get_as_text(&self.boolean_name_list)).collect();
+            return Err(format!("The minimum formula {} isn't equivalent to the formula.  They differ when {}",
+                               minimum_formula.get_as_text(&self.boolean_name_list), literal_text_vec.join(" & ")));
+        }
+
+        if cost_after < cost_before {
+            println!("Minimum Formula: {} (from {})", minimum_formula.get_as_text(&self.boolean_name_list),
+                     source_text);
+            println!("Cost: {} -> {} ({})", cost_before, cost_after, self.cost_model.get_name());
+        }
+        else {
+            println!("Minimum Formula: {} (already minimal)", formula.get_as_text(&self.boolean_name_list));
+            println!("Cost: {} -> {} ({})", cost_before, cost_before, self.cost_model.get_name());
+        }
+
+        Ok(())
+    } //End minimize_formula
+
+    //Looks up the minimum formula in the formula database, if it was written for enough booleans and has one.
+    fn look_up_minimum_formula(&self, formula : &SimpleLogicNode, num_booleans : u32)
+        -> Result<Option<SimpleLogicNode>, String>
+    {
+        match &self.formula_database_option {
+            Some(formula_database) if formula_database.get_num_booleans() >= num_booleans => {
+                Ok(formula_database.look_up_formula(formula)?.get_minimum_formula().cloned())
+            },
+            _ => Ok(None)
+        }
+    } //End look_up_minimum_formula
+
+    //Finds the minimum formula with exact synthesis, which is only run once for each number of booleans.
+    fn synthesize_minimum_formula(&mut self, truth_table : u32, num_booleans : u32)
+        -> Result<SimpleLogicNode, String>
+    {
+        if num_booleans > MAX_BOOLEANS_FOR_EXACT_SYNTHESIS {
+            return Err(format!("There's no formula database for {} booleans, and exact synthesis needs n <= {}",
+                               num_booleans, MAX_BOOLEANS_FOR_EXACT_SYNTHESIS));
+        }
+
+        if self.synthesizer_vec[num_booleans as usize].is_none() {
+            self.synthesizer_vec[num_booleans as usize] = Some(MinimumFormulaSynthesizer::new(num_booleans)?);
+        }
+        match &self.synthesizer_vec[num_booleans as usize] {
+            Some(synthesizer) => Ok(synthesizer.get_formula(truth_table)),
+            None => Err("The exact synthesizer is missing".to_string())
+        }
+    } //End synthesize_minimum_formula
+} //End impl FormulaMinimizer
+
 //FUNCTIONS////////////////////////////////////////////////////////////////////////////////////////////////////////////
 ///Generate the output directory.
 ///Returns the output directory as a PathBuf
@@ -165,7 +1017,7 @@ fn generate_output_directory() -> PathBuf {
 
     //Create the directory.
     match create_dir_all(table_dir_path.clone()) {
-        Ok(result) => {},
+        Ok(_) => {},
         Err(message) => {
             println!("path = {}", table_dir_path.to_str().unwrap());
             panic!("{}", message);
@@ -180,8 +1032,12 @@ fn get_documents_directory() -> Option<PathBuf> {dirs_next::document_dir()}
 ///Writes the list of formulas to html files.
 ///table_dir_path is the directory to write the files to
 ///tt_bucket_vec is the Vec of all the truth tables with the formulas mapped to them
+///convention determines how the truth tables are numbered, ordered between the files and displayed
+///include_decomposition determines whether the functional decomposition of each truth table is added
+///include_complexity_measures determines whether the complexity measures of each truth table are added
 fn write_formula_list_to_html_files(table_dir_path : &PathBuf, tt_bucket_vec : &Vec<LogicFormulaBucket>,
-                                    boolean_name_list : &Vec<String>)
+                                    boolean_name_list : &Vec<String>, convention : TruthTableConvention,
+                                    include_decomposition : bool, include_complexity_measures : bool)
 {
     //Print the truth tables to multiple html files.
     let num_truth_tables = tt_bucket_vec.len() as u32;
@@ -189,7 +1045,8 @@ fn write_formula_list_to_html_files(table_dir_path : &PathBuf, tt_bucket_vec : &
         if num_truth_tables < NUM_TRUTH_TABLES_PER_FILE {1}
         else {num_truth_tables / NUM_TRUTH_TABLES_PER_FILE};
 
-    //Save all the truth table files.
+    //Save all the truth table files.  truth_table is the number of the truth table in the convention.
+    let num_booleans = boolean_name_list.len() as u32;
     let mut truth_table : u32 = 0;
     for file_index in 0..num_truth_files {
         let mut truth_table_html_generator = HtmlGenerator::new();
@@ -200,15 +1057,26 @@ fn write_formula_list_to_html_files(table_dir_path : &PathBuf, tt_bucket_vec : &
 
         //For each truth table in this file
         while truth_table < end_point {
+            let internal_truth_table = convert_truth_table_to_internal(truth_table, num_booleans, convention);
             let html_result : Result<(),String> = add_html_for_truth_table_size_5(&mut truth_table_html_generator,
-                                                                                  truth_table, &truth_table.to_string(), &boolean_name_list);
+                                                                                  internal_truth_table,
+                                                                                  &truth_table.to_string(),
+                                                                                  boolean_name_list, convention);
             match html_result {
                 Ok(()) => (),
                 Err(error_message) => println!("{}", error_message),
             };
 
-            tt_bucket_vec[truth_table as usize].add_html_for_formula_list(&mut truth_table_html_generator,
-                                                                          &boolean_name_list);
+            if include_decomposition {
+                add_html_for_decomposition(&mut truth_table_html_generator, internal_truth_table, boolean_name_list);
+            }
+            if include_complexity_measures {
+                ComplexityMeasures::new(internal_truth_table, num_booleans)
+                    .add_html(&mut truth_table_html_generator, boolean_name_list);
+            }
+
+            tt_bucket_vec[internal_truth_table as usize].add_html_for_formula_list(&mut truth_table_html_generator,
+                                                                          boolean_name_list);
 
             //Increment the counter.
             truth_table = truth_table + 1;
@@ -230,11 +1098,315 @@ fn write_formula_list_to_html_files(table_dir_path : &PathBuf, tt_bucket_vec : &
     } //End for each truth table file
 } //End write_formula_list_to_html_files
 
-///Writes the list of formulas to html files.
+///Writes the minimum formulas over each operator basis to its own html files, named truthtables[basis]X.htm, and a
+///page that compares their costs.
+///table_dir_path is the directory to write the files to
+///basis_bucket_vec is each basis with the minimum formula of every truth table
+///convention determines how the truth tables are numbered, ordered between the files and displayed
+fn write_basis_formulas_to_html_files(table_dir_path : &PathBuf,
+                                      basis_bucket_vec : &Vec<(OperatorBasis, Vec<BasisFormulaBucket>)>,
+                                      boolean_name_list : &Vec<String>, convention : TruthTableConvention)
+{
+    let num_booleans = boolean_name_list.len() as u32;
+    for (operator_basis, bucket_vec) in basis_bucket_vec.iter() {
+        let num_truth_tables = bucket_vec.len() as u32;
+        let num_truth_files : u32 =
+            if num_truth_tables < NUM_TRUTH_TABLES_PER_FILE {1}
+            else {num_truth_tables / NUM_TRUTH_TABLES_PER_FILE};
+
+        //truth_table is the number of the truth table in the convention.
+        let mut truth_table : u32 = 0;
+        for file_index in 0..num_truth_files {
+            let mut truth_table_html_generator = HtmlGenerator::new();
+            if file_index == 0 {
+                truth_table_html_generator.add_header(&format!("Minimum Formulas over {}",
+                                                               operator_basis.get_operator_text()), 1);
+            }
+
+            let end_point : u32 =
+                if file_index + 1 == num_truth_files {num_truth_tables}
+                else {truth_table + NUM_TRUTH_TABLES_PER_FILE};
+
+            //For each truth table in this file
+            while truth_table < end_point {
+                let internal_truth_table = convert_truth_table_to_internal(truth_table, num_booleans, convention);
+                let html_result = add_html_for_truth_table_size_5(&mut truth_table_html_generator, internal_truth_table,
+                                                                  &truth_table.to_string(), boolean_name_list,
+                                                                  convention);
+                if let Err(error_message) = html_result {println!("{}", error_message);}
+
+                bucket_vec[internal_truth_table as usize].add_html_for_formula(&mut truth_table_html_generator,
+                                                                               boolean_name_list);
+                truth_table = truth_table + 1;
+            } //End for each truth table in this file
+
+            //Write the html file.
+            let mut html_filepath = table_dir_path.clone();
+            html_filepath.push(format!("{}{}{}.{}", TRUTH_TABLE_FILE_NAME_PREFIX, operator_basis.get_name(),
+                                       file_index, HTML_FILE_EXTENSION));
+            let mut html_file = std::fs::File::create(html_filepath).expect("create failed");
+            html_file.write_all(format!("{}", truth_table_html_generator).as_bytes()).expect("write failed");
+        } //End for each truth table file
+
+        println!("Minimum formulas over the {} basis written to {} files", operator_basis.get_name(),
+                 num_truth_files);
+    } //End for each operator basis
+
+    let mut comparison_filepath = table_dir_path.clone();
+    comparison_filepath.push(BASIS_COMPARISON_FILE_NAME);
+    let mut comparison_file = std::fs::File::create(&comparison_filepath).expect("create failed");
+    comparison_file.write_all(get_basis_comparison_html(basis_bucket_vec, num_booleans, convention).as_bytes())
+                   .expect("write failed");
+    println!("Operator basis comparison written to file {}", comparison_filepath.to_str().unwrap());
+} //End write_basis_formulas_to_html_files
+
+///Writes the formulas and the BDD of each truth table to its own Graphviz file.
+///table_dir_path is the directory to write the files to
+///tt_bucket_vec is the Vec of all the truth tables with the formulas mapped to them
+///convention determines how the truth tables are numbered in the file names
+///reordering_method is the method that reorders each BDD before it's drawn, if there is one
+///auto_reordering_threshold is the node count past which building a BDD reorders it by sifting, if there is one
+fn write_formula_list_to_dot_files(table_dir_path : &PathBuf, tt_bucket_vec : &Vec<LogicFormulaBucket>,
+                                   boolean_name_list : &Vec<String>, convention : TruthTableConvention,
+                                   dot_options : &DotOptions, reordering_method : Option<ReorderingMethod>,
+                                   auto_reordering_threshold : Option<usize>)
+{
+    let num_booleans = boolean_name_list.len() as u32;
+    let mut bdd_manager = BddManager::new(num_booleans);
+    bdd_manager.set_auto_reordering_threshold(auto_reordering_threshold);
+    let (mut num_nodes_before, mut num_nodes_after) = (0, 0);
+    for truth_table in 0..tt_bucket_vec.len() as u32 {
+        let internal_truth_table = convert_truth_table_to_internal(truth_table, num_booleans, convention);
+        let mut dot_generator = DotGenerator::new(&format!("Truth Table {}", truth_table));
+        if dot_options.show_labels {dot_generator.add_graph_label(&format!("Truth Table {}", truth_table));}
+
+        //Formulas
+        tt_bucket_vec[internal_truth_table as usize].add_dot_for_formula_list(&mut dot_generator, "", boolean_name_list,
+                                                                              dot_options);
+
+        //BDD.  Reordering changes the order of every BDD in a manager, so each reordered BDD gets its own manager.
+        let mut bdd_label = "BDD".to_string();
+        if reordering_method.is_some() {
+            bdd_manager = BddManager::new(num_booleans);
+            bdd_manager.set_auto_reordering_threshold(auto_reordering_threshold);
+        }
+        let bdd = bdd_manager.build_from_truth_table(internal_truth_table, num_booleans);
+        if let Some(reordering_method) = reordering_method {
+            let reordering_report =
+                match bdd_manager.reorder(reordering_method, &[bdd]) {
+                    Ok(reordering_report) => reordering_report,
+                    Err(message) => panic!("{}", message)
+                };
+            num_nodes_before = num_nodes_before + reordering_report.get_size_before();
+            num_nodes_after = num_nodes_after + reordering_report.get_size_after();
+            bdd_label = format!("BDD\n{}", reordering_report.get_as_text(boolean_name_list).trim_end());
+        } //End if the BDD is reordered
+        dot_generator.cluster_create(if dot_options.show_labels {&bdd_label} else {""}, "");
+        add_dot_for_bdd(&mut dot_generator, &bdd_manager, &[bdd], "bdd", boolean_name_list, dot_options);
+        dot_generator.cluster_end();
+
+        //AIG of the minimum formula
+        if let Some(minimum_formula) = tt_bucket_vec[internal_truth_table as usize].get_minimum_formula() {
+            let mut aig = AndInverterGraph::new(num_booleans);
+            let output = aig.build_from_formula(&minimum_formula);
+            aig.add_output(output);
+            dot_generator.cluster_create(if dot_options.show_labels {"AIG"} else {""}, "");
+            add_dot_for_aig(&mut dot_generator, &aig, "aig", boolean_name_list, dot_options);
+            dot_generator.cluster_end();
+        }
+
+        //Write the dot file.
+        let mut dot_filepath = table_dir_path.clone();
+        dot_filepath.push(format!("{}{}.{}", DOT_FILE_NAME_PREFIX, truth_table, DOT_FILE_EXTENSION));
+        let mut dot_file = std::fs::File::create(dot_filepath).expect("create failed");
+        dot_file.write_all(format!("{}", dot_generator).as_bytes()).expect("write failed");
+    } //End for each truth table
+
+    println!("Graphviz files written for {} truth tables", tt_bucket_vec.len());
+    if reordering_method.is_some() {
+        println!("Reordering took the BDDs from {} nodes to {} nodes", num_nodes_before, num_nodes_after);
+    }
+} //End write_formula_list_to_dot_files
+
+///Writes the minimum formulas as one and-inverter graph in both AIGER formats.  Output k is the minimum formula of
+///truth table k, and structural hashing shares gates between the outputs.
+///table_dir_path is the directory to write the files to
+///tt_bucket_vec is the Vec of all the truth tables with the formulas mapped to them
+///convention determines how the truth tables are numbered, which is the order of the outputs
+fn write_minimum_formulas_to_aiger_files(table_dir_path : &PathBuf, tt_bucket_vec : &Vec<LogicFormulaBucket>,
+                                         boolean_name_list : &Vec<String>, convention : TruthTableConvention)
+{
+    let num_booleans = boolean_name_list.len() as u32;
+    let mut aig = AndInverterGraph::new(num_booleans);
+    for truth_table in 0..tt_bucket_vec.len() as u32 {
+        let internal_truth_table = convert_truth_table_to_internal(truth_table, num_booleans, convention);
+        let output =
+            match tt_bucket_vec[internal_truth_table as usize].get_minimum_formula() {
+                Some(minimum_formula) => aig.build_from_formula(&minimum_formula),
+                None => AIG_FALSE
+            };
+        aig.add_output(output);
+    } //End for each truth table
+
+    let mut ascii_filepath = table_dir_path.clone();
+    ascii_filepath.push(AIGER_ASCII_FILE_NAME);
+    let mut ascii_file = std::fs::File::create(&ascii_filepath).expect("create failed");
+    ascii_file.write_all(aig.write_aiger_ascii(Some(boolean_name_list)).as_bytes()).expect("write failed");
+
+    let mut binary_filepath = table_dir_path.clone();
+    binary_filepath.push(AIGER_BINARY_FILE_NAME);
+    let mut binary_file = std::fs::File::create(&binary_filepath).expect("create failed");
+    binary_file.write_all(&aig.write_aiger_binary(Some(boolean_name_list))).expect("write failed");
+
+    println!("AIGER files written with {} AND gates to {}", aig.get_num_and_gates(), ascii_filepath.to_str().unwrap());
+} //End write_minimum_formulas_to_aiger_files
+
+///Writes the minimum formula of each truth table to the binary formula database.
+///table_dir_path is the directory to write the file to
+///tt_bucket_vec is the Vec of all the truth tables with the formulas mapped to them
+///cost_model is the cost model that chose the minimum formulas
+fn write_formula_database_file(table_dir_path : &PathBuf, tt_bucket_vec : &Vec<LogicFormulaBucket>, num_booleans : u32,
+                               cost_model : FormulaCostModel)
+{
+    let formula_database =
+        match FormulaDatabase::build(tt_bucket_vec, num_booleans, cost_model) {
+            Ok(formula_database) => formula_database,
+            Err(error_message) => {
+                println!("{}", error_message);
+                return;
+            }
+        };
+
+    let mut database_filepath = table_dir_path.clone();
+    database_filepath.push(FORMULA_DATABASE_FILE_NAME);
+    let mut database_file = std::fs::File::create(&database_filepath).expect("create failed");
+    database_file.write_all(formula_database.get_bytes()).expect("write failed");
+
+    println!("Formula database written to file {}", database_filepath.to_str().unwrap());
+} //End write_formula_database_file
+
+///Looks up the minimum formula of a formula or a truth table in the formula database and prints it.
+///table_dir_path is the directory the database was written to
+///formula_text is the formula to look up, if there is one
+///truth_table_text is the truth table to look up in the convention, if there's no formula
+///Return value: an error message if the database can't be read or the input isn't valid
+fn look_up_minimum_formula(table_dir_path : &PathBuf, formula_text : Option<String>, truth_table_text : Option<String>,
+                           convention : TruthTableConvention) -> Result<(), String>
+{
+    let mut database_filepath = table_dir_path.clone();
+    database_filepath.push(FORMULA_DATABASE_FILE_NAME);
+    let formula_database = FormulaDatabase::read_from_file(&database_filepath)?;
+
+    let num_booleans = formula_database.get_num_booleans();
+    let mut boolean_name_list = Vec::with_capacity(num_booleans as usize);
+    for i in 1..=num_booleans {
+        boolean_name_list.push(format!("p{}", i));
+    }
+
+    let record =
+        match (formula_text, truth_table_text) {
+            (Some(formula_text), _) => formula_database.look_up_formula(&parse_formula(&formula_text,
+                                                                                       &boolean_name_list)?)?,
+            (None, Some(truth_table_text)) => {
+                formula_database.get_record(parse_truth_table(&truth_table_text, num_booleans, convention)?)?
+            },
+            (None, None) => return Err("Nothing to look up".to_string())
+        };
+
+    println!("Truth Table: {}", convert_truth_table_from_internal(record.get_truth_table(), num_booleans, convention));
+    match (record.get_minimum_formula(), record.get_minimum_cost()) {
+        (Some(minimum_formula), Some(minimum_cost)) => {
+            println!("Minimum Formula: {}", minimum_formula.get_as_text(&boolean_name_list));
+            println!("Cost: {} ({})", minimum_cost, formula_database.get_cost_model().get_name());
+        },
+        _ => println!("Minimum Formula: NONE")
+    };
+    println!("Formulas with this truth table: {}", record.get_num_formulas());
+    print_prime_implicant_covers(record.get_truth_table(), num_booleans, &boolean_name_list);
+
+    Ok(())
+} //End look_up_minimum_formula
+
+///Prints the prime implicants of a truth table and its irredundant covers by prime implicants.  The covers are counted
+///and sampled as a ZDD family without listing them.
+fn print_prime_implicant_covers(truth_table : u32, num_booleans : u32, boolean_name_list : &Vec<String>) {
+    let mut zdd_manager = ZddManager::new();
+    let (cover_family, prime_implicant_list) = build_prime_cover_family(&mut zdd_manager, truth_table, num_booleans);
+    let irredundant_cover_family = zdd_manager.get_minimal_sets(cover_family);
+    let num_covers_option = zdd_manager.count_sets(irredundant_cover_family);
+    let num_covers_text = num_covers_option.map_or("too many to count".to_string(), |number| number.to_string());
+
+    //Element i of the cover family is prime_implicant_list[i - 1].
+    let get_cube = |prime_implicant : &Vec<u32>| {
+        if prime_implicant.is_empty() {SimpleLogicNode::True}
+        else {create_conjunction(prime_implicant.iter().map(|literal| SimpleLogicNode::Literal(*literal)).collect())}
+    };
+    let get_cover_text = |cover : &Vec<u32>| {
+        if cover.is_empty() {return SimpleLogicNode::False.get_as_text(boolean_name_list);}
+        create_disjunction(cover.iter().map(|element| get_cube(&prime_implicant_list[*element as usize - 1])).collect())
+            .get_as_text(boolean_name_list)
+    };
+
+    println!("Prime implicants: {}", prime_implicant_list.len());
+    for (prime_index, prime_implicant) in prime_implicant_list.iter().enumerate() {
+        let covers_with_prime = zdd_manager.get_sets_containing(irredundant_cover_family, prime_index as u32 + 1);
+        let num_covers_with_prime = zdd_manager.count_sets(covers_with_prime);
+        let essential_text = if num_covers_with_prime == num_covers_option {", essential"} else {""};
+        println!("    {} (in {} of the irredundant covers{})", get_cube(prime_implicant).get_as_text(boolean_name_list),
+                 num_covers_with_prime.map_or("too many to count".to_string(), |number| number.to_string()),
+                 essential_text);
+    } //End for each prime implicant
+
+    println!("Irredundant prime covers: {} (a ZDD with {} nodes)", num_covers_text,
+             zdd_manager.count_nodes(irredundant_cover_family));
+    let smallest_cover_family = zdd_manager.get_smallest_sets(irredundant_cover_family);
+    if let Some(smallest_cover) = zdd_manager.get_set_by_index(smallest_cover_family, 0) {
+        println!("Smallest prime covers: {}, with {} prime implicants each",
+                 zdd_manager.count_sets(smallest_cover_family).map_or("too many to count".to_string(),
+                                                                      |number| number.to_string()),
+                 smallest_cover.len());
+    }
+
+    //List the covers if there are only a few of them.  Otherwise sample them.
+    let cover_vec_option =
+        match num_covers_option {
+            Some(num_covers) if num_covers <= NUM_SAMPLED_PRIME_COVERS as u128 => {
+                println!("Irredundant prime covers:");
+                Some(zdd_manager.get_sets(irredundant_cover_family))
+            },
+            _ => {
+                println!("Sampled irredundant prime covers:");
+                zdd_manager.sample_sets(irredundant_cover_family, NUM_SAMPLED_PRIME_COVERS, PRIME_COVER_SAMPLE_SEED)
+            }
+        };
+    for cover in cover_vec_option.unwrap_or_default() {
+        println!("    {}", get_cover_text(&cover));
+    }
+} //End print_prime_implicant_covers
+
+///Writes the statistics of the run as an Html page and as JSON.
+///table_dir_path is the directory to write the files to
+fn write_statistics_files(table_dir_path : &PathBuf, precompute_statistics : &PrecomputeStatistics) {
+    let mut html_filepath = table_dir_path.clone();
+    html_filepath.push(STATISTICS_HTML_FILE_NAME);
+    let mut html_file = std::fs::File::create(&html_filepath).expect("create failed");
+    html_file.write_all(precompute_statistics.get_html().as_bytes()).expect("write failed");
+
+    let mut json_filepath = table_dir_path.clone();
+    json_filepath.push(STATISTICS_JSON_FILE_NAME);
+    let mut json_file = std::fs::File::create(&json_filepath).expect("create failed");
+    json_file.write_all(precompute_statistics.get_json().as_bytes()).expect("write failed");
+
+    println!("Statistics written to files {} and {}", html_filepath.to_str().unwrap(),
+             json_filepath.to_str().unwrap());
+} //End write_statistics_files
+
+///Writes the list of formulas to a text file.
 ///table_dir_path is the directory to write the files to
 ///tt_bucket_vec is the Vec of all the truth tables with the formulas mapped to them
-fn write_formula_list_to_text_file(table_dir_path : &PathBuf, tt_bucket_vec : Vec<LogicFormulaBucket>,
-                                   boolean_name_list : &Vec<String>)
+///convention determines the order of the truth tables.  They're listed in order of their numbers in the convention.
+fn write_formula_list_to_text_file(table_dir_path : &PathBuf, tt_bucket_vec : &Vec<LogicFormulaBucket>,
+                                   boolean_name_list : &Vec<String>, convention : TruthTableConvention)
 {
     //Determine the formula list filepath.
     let mut formula_list_filepath = table_dir_path.clone();
@@ -242,8 +1414,10 @@ fn write_formula_list_to_text_file(table_dir_path : &PathBuf, tt_bucket_vec : Ve
 
     //Write the formula list file.
     let mut formula_list_file = std::fs::File::create(&formula_list_filepath).expect("create failed");
-    for bucket in tt_bucket_vec {
-        formula_list_file.write_all(bucket.get_formula_list_as_text(&boolean_name_list).as_bytes()).expect("write failed");
+    let num_booleans = boolean_name_list.len() as u32;
+    for truth_table in 0..tt_bucket_vec.len() as u32 {
+        let bucket = &tt_bucket_vec[convert_truth_table_to_internal(truth_table, num_booleans, convention) as usize];
+        formula_list_file.write_all(bucket.get_formula_list_as_text(boolean_name_list).as_bytes()).expect("write failed");
     }
 
     println!("Formula list written to file {}", formula_list_filepath.to_str().unwrap());
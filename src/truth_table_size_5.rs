@@ -1,29 +1,29 @@
 /** This file stores truth tables and can format them to be output as html.
     Author: Steven Fletcher
     Created: 01/28/2021
-    Last Updated: 05/05/2021
+    Last Updated: 10/18/2026
 */
 use crate::html_text::*;
 use crate::logic::*;
 
-///The truth tables in this file are only for a tables containing at most 5 booleans.  For tables with fewer booleans,
-///the beginning of the data will be all zeros.
-///
-///The truth tables in this file don't even have a struct.  The data is just a u32.  Callers will have to keep track of
-///which booleans are actually in the table and what order they're in.
-///
-///The data is stored as follows for booleans (p, q, r):
-///bit 7: 1 if p=T, q=T, r=T is True
-///bit 6: 1 if p=T, q=T, r=F is True
-///bit 5: 1 if p=T, q=F, r=T is True
-///bit 4: 1 if p=T, q=F, r=F is True
-///bit 3: 1 if p=F, q=T, r=T is True
-///bit 2: 1 if p=F, q=T, r=F is True
-///bit 1: 1 if p=F, q=F, r=T is True
-///bit 0: 1 if p=F, q=F, r=F is True
-///
-///Creating SimpleLogicNodes to feed into TruthTableSize5Computer: Please note that the booleans are numbered from
-///1 to n.  There is no 0 boolean.  This works fine.
+//The truth tables in this file are only for a tables containing at most 5 booleans.  For tables with fewer booleans,
+//the beginning of the data will be all zeros.
+//
+//The truth tables in this file don't even have a struct.  The data is just a u32.  Callers will have to keep track of
+//which booleans are actually in the table and what order they're in.
+//
+//The data is stored as follows for booleans (p, q, r):
+//bit 7: 1 if p=T, q=T, r=T is True
+//bit 6: 1 if p=T, q=T, r=F is True
+//bit 5: 1 if p=T, q=F, r=T is True
+//bit 4: 1 if p=T, q=F, r=F is True
+//bit 3: 1 if p=F, q=T, r=T is True
+//bit 2: 1 if p=F, q=T, r=F is True
+//bit 1: 1 if p=F, q=F, r=T is True
+//bit 0: 1 if p=F, q=F, r=F is True
+//
+//Creating SimpleLogicNodes to feed into TruthTableSize5Computer: Please note that the booleans are numbered from
+//1 to n.  There is no 0 boolean.  This works fine.
 
 //To get Table1 or Table2, just use bitwise or.  Etc.  Table1 <-> Table2 would require bitwise negation of bitwise xor.
 
@@ -38,15 +38,18 @@ const F_TEXT : &str = "F";
 ///Adds the html for a truth table of size at most 5 to an HtmlGenerator.
 ///
 ///html_generator is the object that is generating the Html
-///table_conclusion is the conclusion column of the table being generated.  1 is True, 0 is False.  The most
-///significant bit is the first row of the conclusion.
+///table_conclusion is the conclusion column of the table being generated, in the internal format described above.
+///1 is True, 0 is False.
 ///table_title is the title to be displayed at the top of the table
 ///boolean_name_list is a list of the names of the booleans used in the table.  These will be the headers of each
 ///column.
+///convention determines the order of the rows.  The rows are listed from the highest row index in the convention to
+///the lowest, so the first row is always the row where every boolean is True.  With FirstBooleanMostSignificant, the
+///last boolean changes on every row, and with FirstBooleanLeastSignificant, the first boolean does.
 ///
 ///Return value: () if the function was successful, an error message if the function failed.
 pub fn add_html_for_truth_table_size_5(html_generator : &mut HtmlGenerator, table_conclusion : u32, table_title : &str,
-    boolean_name_list : &Vec<String>) -> Result<(),String>
+    boolean_name_list : &Vec<String>, convention : TruthTableConvention) -> Result<(),String>
 {
     //Add the header.
     html_generator.add_header(table_title, TABLE_HEADER_NUMBER);
@@ -66,55 +69,35 @@ pub fn add_html_for_truth_table_size_5(html_generator : &mut HtmlGenerator, tabl
     }
     html_generator.table_add_header(TAG_EMPTY_PROPERTIES, table_title);
 
-    //The bitmask for accessing the conclusion from the table conclusion.
     //It should be fine to convert from usize to u32 because the number of booleans will be much less than u32.MAX.
     let num_booleans_u32 : u32 = num_booleans as u32;
-    let mut bitmask : u32 =
-        if num_booleans_u32 == 5 {
-            //We can't use 1 << 32 - 1 because 1 << 32 would overflow.
-            1 << 31
-        }
-        else {
-            /* 1 << num_booleans is pow(2, num_booleans).  Subtracting 1 from that gives the number of bits to shift
-            *  left to get the bit mask.
-            */
-            1 << ((1 << num_booleans) - 1)
-        };
-
-    //This variable stores the current values of each boolean.  The last boolean is stored in the least significant
-    //bit, and each other boolean is stored in the direction of the most significant bit, but it won't use the entire
-    //32 bits.
-    let mut current_boolean_value : u32 = 1;
-    for i in 1..num_booleans {
-        current_boolean_value = current_boolean_value | (1 << i);
-    } //End for each boolean (except the first one)
+    let num_rows = get_num_truth_table_rows(num_booleans_u32);
+    for display_row in 0..num_rows {
+        //Figure out which row of the internal truth table is displayed here.
+        let convention_row = num_rows - 1 - display_row;
+        let row =
+            match convention {
+                TruthTableConvention::FirstBooleanMostSignificant => convention_row,
+                TruthTableConvention::FirstBooleanLeastSignificant => reverse_row_bits(convention_row, num_booleans_u32)
+            };
 
-    loop {
         html_generator.table_add_row(TAG_EMPTY_PROPERTIES);
 
         //Add the columns identifying the booleans' values.
-        for i in (0..num_booleans).rev() {
+        for boolean_index in 1..=num_booleans_u32 {
             //Either output T or F
             let t_or_f_value : &str =
-                if current_boolean_value & (1 << i) > 0 {T_TEXT}
+                if is_boolean_true_in_row(row, boolean_index, num_booleans_u32) {T_TEXT}
                 else {F_TEXT};
             html_generator.table_add_data(TAG_EMPTY_PROPERTIES, t_or_f_value);
         } //End for each boolean
 
         //Add the conclusion column.
         let t_or_f_conclusion =
-            if table_conclusion & bitmask > 0 {T_TEXT}
+            if is_truth_table_true_in_row(table_conclusion, row) {T_TEXT}
             else {F_TEXT};
         html_generator.table_add_data(TAG_EMPTY_PROPERTIES, t_or_f_conclusion);
-
-        //Update the loop condition.
-        if current_boolean_value == 0 {break;}
-        //Else
-        current_boolean_value = current_boolean_value - 1;
-
-        //Adjust the bitmask to get the next line of the table
-        bitmask = bitmask >> 1;
-    } //End loop
+    } //End for each row
 
     //Finish the table.
     html_generator.table_end();
@@ -210,16 +193,7 @@ impl TruthTableSize5Computer {
         match formula {
             SimpleLogicNode::False => 0,
             SimpleLogicNode::True => self.positive_bitmask_vec[0] | self.negative_bitmask_vec[0],
-            SimpleLogicNode::Literal(lit) => {
-                //Just get the correct bitmask.
-                let variable_index = get_variable_index(*lit) as usize;
-                if is_positive_literal(*lit) {
-                    self.positive_bitmask_vec[variable_index - 1]  //(-1 because booleans range from 1 to n)
-                }
-                else {
-                    self.negative_bitmask_vec[variable_index - 1]  //(-1 because booleans range from 1 to n)
-                }
-            },
+            SimpleLogicNode::Literal(lit) => self.compute_literal_truth_table(*lit),
             SimpleLogicNode::Conjunction(operand_vec) => {
                 //Conjunction of each operand.
                 let mut truth_table = u32::MAX;
@@ -236,12 +210,44 @@ impl TruthTableSize5Computer {
                     truth_table = truth_table | self.compute_truth_table(operand);
                 } //End for each operand
 
+                truth_table
+            },
+            SimpleLogicNode::Equivalence(operand_vec) => {
+                //Equivalence of each operand.  p <=> q is the same as ~(p ^ q).
+                let all_true = self.compute_truth_table(&SimpleLogicNode::True);
+                let mut truth_table = all_true;
+                for operand in operand_vec {
+                    truth_table = !(truth_table ^ self.compute_truth_table(operand)) & all_true;
+                } //End for each operand
+
+                truth_table
+            },
+            SimpleLogicNode::ExclusiveDisjunction(operand_vec) => {
+                //Exclusive or of each operand.
+                let mut truth_table = 0;
+                for operand in operand_vec {
+                    truth_table = truth_table ^ self.compute_truth_table(operand);
+                } //End for each operand
+
                 truth_table
             }
         } //End match formula
     } //End compute_truth_table
 
+    ///Computes the truth table of a single literal (sign bit and variable index).
+    pub fn compute_literal_truth_table(&self, literal : u32) -> u32 {
+        //Just get the correct bitmask.
+        let variable_index = get_variable_index(literal) as usize;
+        if is_positive_literal(literal) {
+            self.positive_bitmask_vec[variable_index - 1]  //(-1 because booleans range from 1 to n)
+        }
+        else {
+            self.negative_bitmask_vec[variable_index - 1]  //(-1 because booleans range from 1 to n)
+        }
+    } //End compute_literal_truth_table
+
     ///Prints the bitmasks used by this TTS5Computer for testing purposes.
+    #[allow(dead_code)]
     pub fn print_bitmasks(&self) {
         print!("Positive: ");
         for bitmask in &self.positive_bitmask_vec {
@@ -256,3 +262,313 @@ impl TruthTableSize5Computer {
         println!();
     } //End print_bitmasks
 } //End impl TruthTableSize5Computer
+
+//TRUTH TABLE CONVENTIONS/////////////////////////////////////////////////////////////////////////////////////////////
+///Other tools number truth tables differently.  Internally, truth tables always use the format described at the top
+///of this file.  A TruthTableConvention is only used to convert truth tables when they're displayed, written to file
+///names or parsed from input.
+///In both conventions, bit 0 is the row where every boolean is False, and the row where every boolean is True is the
+///most significant bit.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum TruthTableConvention {
+    ///The internal format.  The first boolean is the most significant bit of the row index.
+    ///For (p, q, r), bit 4 is p=T, q=F, r=F.
+    FirstBooleanMostSignificant,
+    ///The format used by ABC and many papers.  The first boolean is the least significant bit of the row index.
+    ///For (p, q, r), bit 1 is p=T, q=F, r=F.
+    FirstBooleanLeastSignificant
+} //End enum TruthTableConvention
+
+impl TruthTableConvention {
+    ///Parses a convention from a command line argument.  "msb" is FirstBooleanMostSignificant, and "lsb" is
+    ///FirstBooleanLeastSignificant.
+    pub fn parse(text : &str) -> Option<TruthTableConvention> {
+        match text {
+            "msb" => Some(TruthTableConvention::FirstBooleanMostSignificant),
+            "lsb" => Some(TruthTableConvention::FirstBooleanLeastSignificant),
+            _ => None
+        }
+    } //End parse
+} //End impl TruthTableConvention
+
+///Converts a truth table from one convention to another.  The conversion is lossless.
+pub fn convert_truth_table(truth_table : u32, num_booleans : u32, from_convention : TruthTableConvention,
+                           to_convention : TruthTableConvention) -> u32
+{
+    if from_convention == to_convention {truth_table & get_truth_table_mask(num_booleans)}
+    //The two conventions only differ by the order of the booleans.
+    else {reverse_boolean_order(truth_table, num_booleans)}
+} //End convert_truth_table
+
+///Converts a truth table from a convention to the internal format.
+pub fn convert_truth_table_to_internal(truth_table : u32, num_booleans : u32, convention : TruthTableConvention) -> u32 {
+    convert_truth_table(truth_table, num_booleans, convention, TruthTableConvention::FirstBooleanMostSignificant)
+} //End convert_truth_table_to_internal
+
+///Converts a truth table from the internal format to a convention.
+pub fn convert_truth_table_from_internal(truth_table : u32, num_booleans : u32, convention : TruthTableConvention)
+    -> u32
+{
+    convert_truth_table(truth_table, num_booleans, TruthTableConvention::FirstBooleanMostSignificant, convention)
+} //End convert_truth_table_from_internal
+
+///Reverses the order of the booleans in a truth table.  The first boolean becomes the last boolean and so on.
+pub fn reverse_boolean_order(truth_table : u32, num_booleans : u32) -> u32 {
+    let mut reversed_truth_table : u32 = 0;
+    for row in 0..get_num_truth_table_rows(num_booleans) {
+        if is_truth_table_true_in_row(truth_table, row) {
+            reversed_truth_table = reversed_truth_table | (1 << reverse_row_bits(row, num_booleans));
+        }
+    } //End for each row
+
+    reversed_truth_table
+} //End reverse_boolean_order
+
+///Computes the truth table of the dual of a formula from the formula's truth table.  The dual swaps & with | and TRUE
+///with FALSE, so the dual of f is ~f(~p1, ..., ~pn).  Its truth table is f's complemented with the rows in reverse
+///order.
+pub fn compute_dual_truth_table(truth_table : u32, num_booleans : u32) -> u32 {
+    //Reversing every bit reverses the rows, and shifting drops the complemented bits that aren't rows.
+    (!truth_table).reverse_bits() >> (32 - get_num_truth_table_rows(num_booleans))
+} //End compute_dual_truth_table
+
+///Parses a truth table number in a convention and converts it to the internal format.  The number can be decimal,
+///hexadecimal with a 0x prefix or binary with a 0b prefix.
+///Return value: the truth table in the internal format, or an error message
+pub fn parse_truth_table(text : &str, num_booleans : u32, convention : TruthTableConvention) -> Result<u32, String> {
+    let trimmed_text = text.trim();
+    let parse_result =
+        if let Some(hex_text) = trimmed_text.strip_prefix("0x") {u32::from_str_radix(hex_text, 16)}
+        else if let Some(binary_text) = trimmed_text.strip_prefix("0b") {u32::from_str_radix(binary_text, 2)}
+        else {trimmed_text.parse::<u32>()};
+
+    let truth_table =
+        match parse_result {
+            Ok(truth_table) => truth_table,
+            Err(_) => return Err(format!("Invalid truth table {}", text))
+        };
+    if truth_table & !get_truth_table_mask(num_booleans) != 0 {
+        return Err(format!("Truth table {} is too large for {} booleans", text, num_booleans));
+    }
+
+    Ok(convert_truth_table_to_internal(truth_table, num_booleans, convention))
+} //End parse_truth_table
+
+//Reverses the lowest num_booleans bits of a row index.
+fn reverse_row_bits(row : u32, num_booleans : u32) -> u32 {
+    if num_booleans == 0 {0}
+    else {row.reverse_bits() >> (32 - num_booleans)}
+} //End reverse_row_bits
+
+//TRUTH TABLE HELPER FUNCTIONS/////////////////////////////////////////////////////////////////////////////////////////
+//These functions work on the raw u32 truth tables described at the top of this file.  A truth table with n booleans
+//has 2^n rows.  In row r, boolean i (1 to n) is True if bit (n - i) of r is set.
+
+///Gets a bitmask with a 1 in every row of a truth table with num_booleans booleans.
+///num_booleans must be from 0 to 5.
+pub fn get_truth_table_mask(num_booleans : u32) -> u32 {
+    if num_booleans >= 5 {u32::MAX}
+    else {(1 << (1 << num_booleans)) - 1}
+} //End get_truth_table_mask
+
+///Gets the number of rows in a truth table with num_booleans booleans.
+pub fn get_num_truth_table_rows(num_booleans : u32) -> u32 {1 << num_booleans}
+
+///Determines whether a boolean is True in a row of a truth table.
+///row is the row index (0 is the row where every boolean is False)
+///boolean_index is the boolean to check (1 to n)
+///num_booleans is the number of booleans in the truth table
+pub fn is_boolean_true_in_row(row : u32, boolean_index : u32, num_booleans : u32) -> bool {
+    (row >> (num_booleans - boolean_index)) & 1 == 1
+} //End is_boolean_true_in_row
+
+///Determines whether a truth table is True in a given row.
+pub fn is_truth_table_true_in_row(truth_table : u32, row : u32) -> bool {
+    (truth_table >> row) & 1 == 1
+} //End is_truth_table_true_in_row
+
+///Gets the truth table of a single positive literal.  This is the same as the positive bitmasks stored by
+///TruthTableSize5Computer, but it doesn't require constructing one.
+pub fn get_boolean_truth_table(boolean_index : u32, num_booleans : u32) -> u32 {
+    let mut truth_table : u32 = 0;
+    for row in 0..get_num_truth_table_rows(num_booleans) {
+        if is_boolean_true_in_row(row, boolean_index, num_booleans) {
+            truth_table = truth_table | (1 << row);
+        }
+    } //End for each row
+
+    truth_table
+} //End get_boolean_truth_table
+
+///Computes the cofactor of a truth table with respect to a boolean.  The result is still a truth table over all
+///num_booleans booleans, but it no longer depends on boolean_index.
+///value is the value that boolean_index is fixed to
+pub fn compute_cofactor(truth_table : u32, num_booleans : u32, boolean_index : u32, value : bool) -> u32 {
+    let positive_rows = get_boolean_truth_table(boolean_index, num_booleans);
+    let shift = 1 << (num_booleans - boolean_index);
+
+    if value {
+        let true_half = truth_table & positive_rows;
+        true_half | (true_half >> shift)
+    }
+    else {
+        let false_half = truth_table & !positive_rows & get_truth_table_mask(num_booleans);
+        false_half | (false_half << shift)
+    }
+} //End compute_cofactor
+
+///Determines whether a truth table actually depends on a boolean.
+pub fn depends_on_boolean(truth_table : u32, num_booleans : u32, boolean_index : u32) -> bool {
+    compute_cofactor(truth_table, num_booleans, boolean_index, false) !=
+        compute_cofactor(truth_table, num_booleans, boolean_index, true)
+} //End depends_on_boolean
+
+///Computes the support of a truth table, the booleans that the truth table actually depends on.
+///Return value: the indexes of the booleans in increasing order
+pub fn compute_support(truth_table : u32, num_booleans : u32) -> Vec<u32> {
+    let mut support = Vec::with_capacity(num_booleans as usize);
+    for boolean_index in 1..=num_booleans {
+        if depends_on_boolean(truth_table, num_booleans, boolean_index) {
+            support.push(boolean_index);
+        }
+    } //End for each boolean
+
+    support
+} //End compute_support
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clause_iterator::*;
+
+    //Creates the DNF formula and the CNF formula with the same clauses.
+    //Return value: (the DNF formula, the CNF formula)
+    fn create_normal_formulas(clause_vec : &[Vec<u32>]) -> (SimpleLogicNode, SimpleLogicNode) {
+        let to_literals = |clause : &Vec<u32>| clause.iter().map(|literal| SimpleLogicNode::Literal(*literal)).collect();
+        let dnf_formula = SimpleLogicNode::Disjunction(clause_vec.iter().map(|clause| {
+            SimpleLogicNode::Conjunction(to_literals(clause))
+        }).collect());
+        let cnf_formula = SimpleLogicNode::Conjunction(clause_vec.iter().map(|clause| {
+            SimpleLogicNode::Disjunction(to_literals(clause))
+        }).collect());
+        (dnf_formula, cnf_formula)
+    } //End create_normal_formulas
+
+    #[test]
+    fn dual_truth_table_is_the_cnf_truth_table() {
+        for n in 1..=4 {
+            let tt_computer = TruthTableSize5Computer::new(n);
+            let literal_subarray_vec : Vec<Vec<u32>> = ClauseIterator::new(n, n).collect();
+
+            //Every pair of clauses, and every clause on its own
+            for first_index in 0..literal_subarray_vec.len() {
+                for second_index in first_index..literal_subarray_vec.len() {
+                    let clause_vec = if first_index == second_index {vec![literal_subarray_vec[first_index].clone()]}
+                                     else {vec![literal_subarray_vec[first_index].clone(),
+                                                literal_subarray_vec[second_index].clone()]};
+                    let (dnf_formula, cnf_formula) = create_normal_formulas(&clause_vec);
+                    assert_eq!(compute_dual_truth_table(tt_computer.compute_truth_table(&dnf_formula), n),
+                               tt_computer.compute_truth_table(&cnf_formula));
+                } //End for each second clause
+            } //End for each first clause
+        } //End for each number of booleans
+    } //End dual_truth_table_is_the_cnf_truth_table
+
+    #[test]
+    fn dual_of_the_dual_is_the_original() {
+        for n in 1..=4 {
+            for truth_table in 0..=get_truth_table_mask(n) {
+                let dual_truth_table = compute_dual_truth_table(truth_table, n);
+                assert_eq!(dual_truth_table & !get_truth_table_mask(n), 0);
+                assert_eq!(compute_dual_truth_table(dual_truth_table, n), truth_table);
+            }
+        } //End for each number of booleans
+    } //End dual_of_the_dual_is_the_original
+
+    //Computes the truth table of a boolean in a convention, numbering the rows the way the convention does.
+    fn get_boolean_truth_table_in_convention(boolean_index : u32, num_booleans : u32,
+                                             convention : TruthTableConvention) -> u32
+    {
+        let bit = match convention {
+            TruthTableConvention::FirstBooleanMostSignificant => num_booleans - boolean_index,
+            TruthTableConvention::FirstBooleanLeastSignificant => boolean_index - 1
+        };
+        (0..get_num_truth_table_rows(num_booleans)).filter(|row| (row >> bit) & 1 == 1)
+            .fold(0, |truth_table, row| truth_table | (1 << row))
+    } //End get_boolean_truth_table_in_convention
+
+    const CONVENTION_LIST : [TruthTableConvention; 2] =
+        [TruthTableConvention::FirstBooleanMostSignificant, TruthTableConvention::FirstBooleanLeastSignificant];
+
+    #[test]
+    fn conversions_round_trip() {
+        for n in 1..=3 {
+            for truth_table in 0..=get_truth_table_mask(n) {
+                assert_eq!(reverse_boolean_order(reverse_boolean_order(truth_table, n), n), truth_table);
+                for from_convention in CONVENTION_LIST.iter() {
+                    for to_convention in CONVENTION_LIST.iter() {
+                        let converted_truth_table = convert_truth_table(truth_table, n, *from_convention,
+                                                                        *to_convention);
+                        assert_eq!(converted_truth_table & !get_truth_table_mask(n), 0);
+                        assert_eq!(convert_truth_table(converted_truth_table, n, *to_convention, *from_convention),
+                                   truth_table);
+                    }
+                } //End for each convention to convert from
+
+                for convention in CONVENTION_LIST.iter() {
+                    let converted_truth_table = convert_truth_table_from_internal(truth_table, n, *convention);
+                    assert_eq!(convert_truth_table_to_internal(converted_truth_table, n, *convention), truth_table);
+                    for text in [converted_truth_table.to_string(), format!("0x{:x}", converted_truth_table),
+                                 format!("0b{:b}", converted_truth_table)].iter() {
+                        assert_eq!(parse_truth_table(text, n, *convention), Ok(truth_table));
+                    }
+                } //End for each convention
+            } //End for each truth table
+        } //End for each number of booleans
+    } //End conversions_round_trip
+
+    #[test]
+    fn conventions_number_the_rows_of_each_boolean() {
+        for n in 1..=3 {
+            for boolean_index in 1..=n {
+                let internal_truth_table = get_boolean_truth_table(boolean_index, n);
+                for convention in CONVENTION_LIST.iter() {
+                    assert_eq!(convert_truth_table_from_internal(internal_truth_table, n, *convention),
+                               get_boolean_truth_table_in_convention(boolean_index, n, *convention));
+                }
+                assert_eq!(reverse_boolean_order(internal_truth_table, n),
+                           get_boolean_truth_table(n + 1 - boolean_index, n));
+            } //End for each boolean
+        } //End for each number of booleans
+    } //End conventions_number_the_rows_of_each_boolean
+
+    //Renders the html for a truth table and gets the text of its data cells, one string per row.
+    fn get_html_rows(truth_table : u32, boolean_name_list : &Vec<String>, convention : TruthTableConvention)
+        -> Vec<String>
+    {
+        let mut html_generator = HtmlGenerator::new();
+        add_html_for_truth_table_size_5(&mut html_generator, truth_table, "f", boolean_name_list, convention).unwrap();
+        let cell_vec : Vec<String> = html_generator.to_string().split("<td >").skip(1)
+            .map(|cell| cell.trim().chars().next().unwrap().to_string()).collect();
+        cell_vec.chunks(boolean_name_list.len() + 1).map(|row| row.concat()).collect()
+    } //End get_html_rows
+
+    #[test]
+    fn html_lists_the_all_true_row_first_in_both_conventions() {
+        let boolean_name_list = vec!["p".to_string(), "q".to_string()];
+        let truth_table = get_boolean_truth_table(1, 2);
+        assert_eq!(get_html_rows(truth_table, &boolean_name_list, TruthTableConvention::FirstBooleanMostSignificant),
+                   vec!["TTT", "TFT", "FTF", "FFF"]);
+        assert_eq!(get_html_rows(truth_table, &boolean_name_list, TruthTableConvention::FirstBooleanLeastSignificant),
+                   vec!["TTT", "FTF", "TFT", "FFF"]);
+    } //End html_lists_the_all_true_row_first_in_both_conventions
+
+    #[test]
+    fn parse_truth_table_rejects_bad_numbers() {
+        let convention = TruthTableConvention::FirstBooleanMostSignificant;
+        assert!(parse_truth_table("256", 3, convention).is_err());
+        assert!(parse_truth_table("0x100", 3, convention).is_err());
+        assert!(parse_truth_table("abc", 3, convention).is_err());
+        assert_eq!(parse_truth_table(" 0xff ", 3, convention), Ok(0xff));
+    } //End parse_truth_table_rejects_bad_numbers
+} //End mod tests
@@ -0,0 +1,731 @@
+/** This file stores zero-suppressed decision diagrams (ZDDs).  A ZDD represents a family of sets, so whole families of
+    cubes or clause sets can be stored and counted without listing them one by one.
+    Author: Steven Fletcher
+    Created: 10/18/2026
+    Last Updated: 10/18/2026
+*/
+use crate::logic::*;
+use crate::truth_table_size_5::*;
+use std::collections::HashMap;
+
+///ZDD nodes are referenced by their index in the ZddManager that created them.  Ids are only meaningful inside of
+///that ZddManager.
+pub type ZddNodeId = u32;
+
+///The terminal node for the empty family (no sets at all).
+pub const ZDD_EMPTY : ZddNodeId = 0;
+///The terminal node for the family that only contains the empty set.
+pub const ZDD_BASE : ZddNodeId = 1;
+
+///This struct creates and stores the nodes of zero-suppressed decision diagrams.  Each node has an element, a low
+///child (the sets without the element) and a high child (the sets with the element, with it removed).  Nodes whose
+///high child is ZDD_EMPTY are never created, and every node is unique, so two families are equal exactly when they
+///have the same ZddNodeId.
+///
+///Elements are positive numbers.  Smaller elements are closer to the root.
+pub struct ZddManager {
+    node_vec : Vec<ZddNode>,
+    unique_table : HashMap<(u32, ZddNodeId, ZddNodeId), ZddNodeId>,
+    computed_cache : HashMap<(ZddOperator, ZddNodeId, ZddNodeId), ZddNodeId>
+} //End struct ZddManager
+
+impl ZddManager {
+    pub fn new() -> ZddManager {
+        let mut manager = ZddManager {
+            node_vec : Vec::new(),
+            unique_table : HashMap::new(),
+            computed_cache : HashMap::new()
+        };
+
+        //The terminals are the first two nodes.  They are below every element.
+        manager.node_vec.push(ZddNode {element : TERMINAL_ELEMENT, low : ZDD_EMPTY, high : ZDD_EMPTY});
+        manager.node_vec.push(ZddNode {element : TERMINAL_ELEMENT, low : ZDD_BASE, high : ZDD_BASE});
+
+        manager
+    } //End new
+
+
+    //CONSTRUCTION
+    ///Gets the family that contains exactly one set.
+    pub fn get_single_set(&mut self, element_list : &[u32]) -> ZddNodeId {
+        let mut sorted_element_list = element_list.to_vec();
+        sorted_element_list.sort_unstable();
+        sorted_element_list.dedup();
+
+        //Build from the bottom up, so the largest element comes first.
+        let mut result = ZDD_BASE;
+        for element in sorted_element_list.iter().rev() {
+            result = self.make_node(*element, ZDD_EMPTY, result);
+        }
+        result
+    } //End get_single_set
+
+    ///Gets the family of every subset of a set of elements.
+    pub fn get_power_set(&mut self, element_list : &[u32]) -> ZddNodeId {
+        let mut sorted_element_list = element_list.to_vec();
+        sorted_element_list.sort_unstable();
+        sorted_element_list.dedup();
+
+        let mut result = ZDD_BASE;
+        for element in sorted_element_list.iter().rev() {
+            result = self.make_node(*element, result, result);
+        }
+        result
+    } //End get_power_set
+
+
+    //SET OPERATIONS
+    ///Gets the sets that are in either family.
+    pub fn union(&mut self, left : ZddNodeId, right : ZddNodeId) -> ZddNodeId {
+        if left == ZDD_EMPTY {return right;}
+        if right == ZDD_EMPTY || left == right {return left;}
+
+        let cache_key = (ZddOperator::Union, left.min(right), left.max(right));
+        if let Some(result) = self.computed_cache.get(&cache_key) {return *result;}
+
+        let left_node = self.node_vec[left as usize];
+        let right_node = self.node_vec[right as usize];
+        let result =
+            if left_node.element < right_node.element {
+                let low = self.union(left_node.low, right);
+                self.make_node(left_node.element, low, left_node.high)
+            }
+            else if left_node.element > right_node.element {
+                let low = self.union(left, right_node.low);
+                self.make_node(right_node.element, low, right_node.high)
+            }
+            else {
+                let low = self.union(left_node.low, right_node.low);
+                let high = self.union(left_node.high, right_node.high);
+                self.make_node(left_node.element, low, high)
+            };
+
+        self.computed_cache.insert(cache_key, result);
+        result
+    } //End union
+
+    ///Gets the sets that are in both families.
+    pub fn intersection(&mut self, left : ZddNodeId, right : ZddNodeId) -> ZddNodeId {
+        if left == ZDD_EMPTY || right == ZDD_EMPTY {return ZDD_EMPTY;}
+        if left == right {return left;}
+
+        let cache_key = (ZddOperator::Intersection, left.min(right), left.max(right));
+        if let Some(result) = self.computed_cache.get(&cache_key) {return *result;}
+
+        let left_node = self.node_vec[left as usize];
+        let right_node = self.node_vec[right as usize];
+        let result =
+            //Sets of the family with the smaller top element can't contain that element if they're in both.
+            if left_node.element < right_node.element {self.intersection(left_node.low, right)}
+            else if left_node.element > right_node.element {self.intersection(left, right_node.low)}
+            else {
+                let low = self.intersection(left_node.low, right_node.low);
+                let high = self.intersection(left_node.high, right_node.high);
+                self.make_node(left_node.element, low, high)
+            };
+
+        self.computed_cache.insert(cache_key, result);
+        result
+    } //End intersection
+
+    ///Gets the sets that are in the left family but not the right family.
+    pub fn difference(&mut self, left : ZddNodeId, right : ZddNodeId) -> ZddNodeId {
+        if left == ZDD_EMPTY || left == right {return ZDD_EMPTY;}
+        if right == ZDD_EMPTY {return left;}
+
+        let cache_key = (ZddOperator::Difference, left, right);
+        if let Some(result) = self.computed_cache.get(&cache_key) {return *result;}
+
+        let left_node = self.node_vec[left as usize];
+        let right_node = self.node_vec[right as usize];
+        let result =
+            if left_node.element < right_node.element {
+                let low = self.difference(left_node.low, right);
+                self.make_node(left_node.element, low, left_node.high)
+            }
+            else if left_node.element > right_node.element {self.difference(left, right_node.low)}
+            else {
+                let low = self.difference(left_node.low, right_node.low);
+                let high = self.difference(left_node.high, right_node.high);
+                self.make_node(left_node.element, low, high)
+            };
+
+        self.computed_cache.insert(cache_key, result);
+        result
+    } //End difference
+
+
+    //FILTERING
+
+    ///Gets the sets that contain an element, with the element removed from them.
+    pub fn subset1(&mut self, family : ZddNodeId, element : u32) -> ZddNodeId {
+        let node = self.node_vec[family as usize];
+        if node.element > element {return ZDD_EMPTY;}
+        if node.element == element {return node.high;}
+
+        let cache_key = (ZddOperator::Subset1(element), family, ZDD_EMPTY);
+        if let Some(result) = self.computed_cache.get(&cache_key) {return *result;}
+
+        let low = self.subset1(node.low, element);
+        let high = self.subset1(node.high, element);
+        let result = self.make_node(node.element, low, high);
+
+        self.computed_cache.insert(cache_key, result);
+        result
+    } //End subset1
+
+    ///Gets the sets that contain an element, keeping the element in them.
+    pub fn get_sets_containing(&mut self, family : ZddNodeId, element : u32) -> ZddNodeId {
+        let without_element = self.subset1(family, element);
+        self.change(without_element, element)
+    } //End get_sets_containing
+
+    ///Adds an element to every set that doesn't contain it and removes it from every set that does.
+    pub fn change(&mut self, family : ZddNodeId, element : u32) -> ZddNodeId {
+        if family == ZDD_EMPTY {return ZDD_EMPTY;}
+        let node = self.node_vec[family as usize];
+        if node.element > element {return self.make_node(element, ZDD_EMPTY, family);}
+        if node.element == element {return self.make_node(element, node.high, node.low);}
+
+        let cache_key = (ZddOperator::Change(element), family, ZDD_EMPTY);
+        if let Some(result) = self.computed_cache.get(&cache_key) {return *result;}
+
+        let low = self.change(node.low, element);
+        let high = self.change(node.high, element);
+        let result = self.make_node(node.element, low, high);
+
+        self.computed_cache.insert(cache_key, result);
+        result
+    } //End change
+
+    ///Gets the sets of the family that aren't supersets of any set in the filter family.
+    pub fn get_non_supersets(&mut self, family : ZddNodeId, filter : ZddNodeId) -> ZddNodeId {
+        if filter == ZDD_EMPTY {return family;}
+        if family == ZDD_EMPTY || family == filter || self.contains_empty_set(filter) {return ZDD_EMPTY;}
+
+        let cache_key = (ZddOperator::NonSupersets, family, filter);
+        if let Some(result) = self.computed_cache.get(&cache_key) {return *result;}
+
+        let family_node = self.node_vec[family as usize];
+        let filter_node = self.node_vec[filter as usize];
+        let result =
+            //The sets of the family don't contain the filter's top element, so filters that contain it don't matter.
+            if family_node.element > filter_node.element {self.get_non_supersets(family, filter_node.low)}
+            else if family_node.element < filter_node.element {
+                let low = self.get_non_supersets(family_node.low, filter);
+                let high = self.get_non_supersets(family_node.high, filter);
+                self.make_node(family_node.element, low, high)
+            }
+            //Sets with the element must avoid both kinds of filters.
+            else {
+                let low = self.get_non_supersets(family_node.low, filter_node.low);
+                let high_without = self.get_non_supersets(family_node.high, filter_node.low);
+                let high_with = self.get_non_supersets(family_node.high, filter_node.high);
+                let high = self.intersection(high_without, high_with);
+                self.make_node(family_node.element, low, high)
+            };
+
+        self.computed_cache.insert(cache_key, result);
+        result
+    } //End get_non_supersets
+
+    ///Gets the sets of the family that are supersets of at least one set in the filter family.
+    pub fn get_supersets(&mut self, family : ZddNodeId, filter : ZddNodeId) -> ZddNodeId {
+        let non_supersets = self.get_non_supersets(family, filter);
+        self.difference(family, non_supersets)
+    } //End get_supersets
+
+    ///Gets the minimal sets of the family, the sets with no proper subset in the family.  The result is
+    ///subsumption-free.
+    pub fn get_minimal_sets(&mut self, family : ZddNodeId) -> ZddNodeId {
+        if family == ZDD_EMPTY || family == ZDD_BASE {return family;}
+
+        let cache_key = (ZddOperator::Minimal, family, ZDD_EMPTY);
+        if let Some(result) = self.computed_cache.get(&cache_key) {return *result;}
+
+        let node = self.node_vec[family as usize];
+        let low = self.get_minimal_sets(node.low);
+        let minimal_high = self.get_minimal_sets(node.high);
+        let high = self.get_non_supersets(minimal_high, low);
+        let result = self.make_node(node.element, low, high);
+
+        self.computed_cache.insert(cache_key, result);
+        result
+    } //End get_minimal_sets
+
+    ///Gets the sets of the family that have at most a number of elements.
+    pub fn get_sets_up_to_size(&mut self, family : ZddNodeId, max_size : u32) -> ZddNodeId {
+        let mut memo = HashMap::new();
+        self.get_sets_up_to_size_helper(family, max_size, &mut memo)
+    } //End get_sets_up_to_size
+
+    ///Gets the sets of the family with the fewest elements.
+    pub fn get_smallest_sets(&mut self, family : ZddNodeId) -> ZddNodeId {
+        if family == ZDD_EMPTY {return ZDD_EMPTY;}
+
+        let mut max_size = 0;
+        loop {
+            let small_sets = self.get_sets_up_to_size(family, max_size);
+            if small_sets != ZDD_EMPTY {return small_sets;}
+            max_size = max_size + 1;
+        }
+    } //End get_smallest_sets
+
+    //QUERIES
+    ///Determines whether the family contains the empty set.
+    pub fn contains_empty_set(&self, family : ZddNodeId) -> bool {
+        //The empty set is found by never taking a high edge.
+        let mut current = family;
+        while current != ZDD_EMPTY && current != ZDD_BASE {
+            current = self.node_vec[current as usize].low;
+        }
+        current == ZDD_BASE
+    } //End contains_empty_set
+
+
+    ///Counts the sets in the family.
+    ///Return value: the number of sets, or None if it doesn't fit in a u128
+    pub fn count_sets(&self, family : ZddNodeId) -> Option<u128> {
+        let mut memo = HashMap::new();
+        self.count_sets_helper(family, &mut memo)
+    } //End count_sets
+
+    ///Gets every set of the family.  Each set is sorted.  Families can be enormous, so count them first.
+    pub fn get_sets(&self, family : ZddNodeId) -> Vec<Vec<u32>> {
+        let mut set_vec = Vec::new();
+        self.get_sets_helper(family, &mut Vec::new(), &mut set_vec);
+        set_vec
+    } //End get_sets
+
+    ///Gets a set of the family by its index.  Sets are ordered so that every set without the top element comes before
+    ///every set with it, recursively.  This can sample sets without listing the family.
+    ///Return value: the sorted set, or None if the index is past the end of the family
+    pub fn get_set_by_index(&self, family : ZddNodeId, index : u128) -> Option<Vec<u32>> {
+        let mut memo = HashMap::new();
+        if index >= self.count_sets_helper(family, &mut memo)? {return None;}
+
+        let mut set = Vec::new();
+        let mut current = family;
+        let mut remaining_index = index;
+        while current != ZDD_BASE {
+            let node = &self.node_vec[current as usize];
+            let num_low_sets = self.count_sets_helper(node.low, &mut memo)?;
+            if remaining_index < num_low_sets {current = node.low;}
+            else {
+                remaining_index = remaining_index - num_low_sets;
+                set.push(node.element);
+                current = node.high;
+            }
+        } //End while the set isn't finished
+
+        Some(set)
+    } //End get_set_by_index
+
+    ///Samples sets of the family uniformly at random with replacement.  The same seed always gives the same samples.
+    ///Return value: the sampled sets, or None if the family is empty or too large to count
+    pub fn sample_sets(&self, family : ZddNodeId, num_samples : usize, seed : u64) -> Option<Vec<Vec<u32>>> {
+        let num_sets = self.count_sets(family)?;
+        if num_sets == 0 {return None;}
+
+        //xorshift64* needs a nonzero state.
+        let mut state = seed | 1;
+        let mut sample_vec = Vec::with_capacity(num_samples);
+        for _sample in 0..num_samples {
+            let mut random_value : u128 = 0;
+            for _word in 0..2 {
+                state = state ^ (state >> 12);
+                state = state ^ (state << 25);
+                state = state ^ (state >> 27);
+                random_value = (random_value << 64) | state.wrapping_mul(0x2545_F491_4F6C_DD1D) as u128;
+            }
+            sample_vec.push(self.get_set_by_index(family, random_value % num_sets)?);
+        } //End for each sample
+
+        Some(sample_vec)
+    } //End sample_sets
+
+    ///Counts the nodes of a ZDD, including the terminals it reaches.
+    pub fn count_nodes(&self, family : ZddNodeId) -> usize {
+        let mut visited = std::collections::HashSet::new();
+        let mut stack = vec![family];
+        while let Some(current) = stack.pop() {
+            if !visited.insert(current) {continue;}
+            if current != ZDD_EMPTY && current != ZDD_BASE {
+                stack.push(self.node_vec[current as usize].low);
+                stack.push(self.node_vec[current as usize].high);
+            }
+        } //End while there are nodes to visit
+
+        visited.len()
+    } //End count_nodes
+
+    //PRIVATE
+    //Gets the node for (element, low, high), creating it if necessary.  Nodes with an empty high child are never
+    //created.
+    fn make_node(&mut self, element : u32, low : ZddNodeId, high : ZddNodeId) -> ZddNodeId {
+        if high == ZDD_EMPTY {return low;}
+
+        let key = (element, low, high);
+        if let Some(existing_node) = self.unique_table.get(&key) {return *existing_node;}
+
+        let new_node = self.node_vec.len() as ZddNodeId;
+        self.node_vec.push(ZddNode {element : element, low : low, high : high});
+        self.unique_table.insert(key, new_node);
+        new_node
+    } //End make_node
+
+    fn get_sets_up_to_size_helper(&mut self, family : ZddNodeId, max_size : u32,
+                                  memo : &mut HashMap<(ZddNodeId, u32), ZddNodeId>) -> ZddNodeId
+    {
+        if family == ZDD_EMPTY || family == ZDD_BASE {return family;}
+        if max_size == 0 {return if self.contains_empty_set(family) {ZDD_BASE} else {ZDD_EMPTY};}
+        if let Some(result) = memo.get(&(family, max_size)) {return *result;}
+
+        let node = self.node_vec[family as usize];
+        let low = self.get_sets_up_to_size_helper(node.low, max_size, memo);
+        let high = self.get_sets_up_to_size_helper(node.high, max_size - 1, memo);
+        let result = self.make_node(node.element, low, high);
+
+        memo.insert((family, max_size), result);
+        result
+    } //End get_sets_up_to_size_helper
+
+    fn count_sets_helper(&self, family : ZddNodeId, memo : &mut HashMap<ZddNodeId, Option<u128>>) -> Option<u128> {
+        if family == ZDD_EMPTY {return Some(0);}
+        if family == ZDD_BASE {return Some(1);}
+        if let Some(count) = memo.get(&family) {return *count;}
+
+        let node = &self.node_vec[family as usize];
+        let count =
+            match (self.count_sets_helper(node.low, memo), self.count_sets_helper(node.high, memo)) {
+                (Some(low_count), Some(high_count)) => low_count.checked_add(high_count),
+                _ => None
+            };
+
+        memo.insert(family, count);
+        count
+    } //End count_sets_helper
+
+    fn get_sets_helper(&self, family : ZddNodeId, current_set : &mut Vec<u32>, set_vec : &mut Vec<Vec<u32>>) {
+        if family == ZDD_EMPTY {return;}
+        if family == ZDD_BASE {
+            set_vec.push(current_set.clone());
+            return;
+        }
+
+        let node = &self.node_vec[family as usize];
+        self.get_sets_helper(node.low, current_set, set_vec);
+        current_set.push(node.element);
+        self.get_sets_helper(node.high, current_set, set_vec);
+        current_set.pop();
+    } //End get_sets_helper
+} //End impl ZddManager
+
+impl Default for ZddManager {
+    fn default() -> Self {Self::new()}
+} //End impl Default for ZddManager
+
+//CUBES AND CLAUSES////////////////////////////////////////////////////////////////////////////////////////////////////
+//A cube or clause is a set of literals, so a family of them has one element per literal.  A set of clauses (a normal
+//formula) has one element per possible clause instead.
+
+///Gets the element for a literal.  Boolean i gets element 2i - 1 when it's positive and 2i when it's negated, so the
+///elements follow the order of the booleans.
+pub fn get_literal_element(literal : u32) -> u32 {
+    let element = 2 * get_variable_index(literal) - 1;
+    if is_positive_literal(literal) {element} else {element + 1}
+} //End get_literal_element
+
+///Gets the literal for an element made by get_literal_element.
+pub fn get_literal_from_element(element : u32) -> u32 {
+    let variable = element.div_ceil(2);
+    if element % 2 == 1 {variable} else {variable | NEGATIVITY_FLAG}
+} //End get_literal_from_element
+
+
+///Gets the clause for an element made by get_clause_element.  The literals are sorted by boolean.
+pub fn get_clause_from_element(element : u32, num_booleans : u32) -> Vec<u32> {
+    let mut clause = Vec::new();
+    for boolean_index in 1..=num_booleans {
+        match (element / 3u32.pow(num_booleans - boolean_index)) % 3 {
+            1 => clause.push(boolean_index),
+            2 => clause.push(boolean_index | NEGATIVITY_FLAG),
+            _ => ()
+        };
+    } //End for each boolean
+    clause
+} //End get_clause_from_element
+
+///Gets the truth table of a cube (a conjunction of literals) over num_booleans booleans.
+pub fn get_cube_truth_table(cube : &[u32], num_booleans : u32) -> u32 {
+    let mut truth_table = get_truth_table_mask(num_booleans);
+    for literal in cube {
+        let boolean_truth_table = get_boolean_truth_table(get_variable_index(*literal), num_booleans);
+        if is_positive_literal(*literal) {truth_table = truth_table & boolean_truth_table;}
+        else {truth_table = truth_table & !boolean_truth_table;}
+    }
+    truth_table & get_truth_table_mask(num_booleans)
+} //End get_cube_truth_table
+
+///Builds the family of every implicant of a truth table.  Each implicant is a set of literal elements (see
+///get_literal_element).
+pub fn build_implicant_family(manager : &mut ZddManager, truth_table : u32, num_booleans : u32) -> ZddNodeId {
+    let truth_table = truth_table & get_truth_table_mask(num_booleans);
+    let mut family = ZDD_EMPTY;
+    for clause_element in 0..3u32.pow(num_booleans) {
+        let cube = get_clause_from_element(clause_element, num_booleans);
+        if get_cube_truth_table(&cube, num_booleans) & !truth_table == 0 {
+            let element_list : Vec<u32> = cube.iter().map(|literal| get_literal_element(*literal)).collect();
+            let single_set = manager.get_single_set(&element_list);
+            family = manager.union(family, single_set);
+        }
+    } //End for each cube
+    family
+} //End build_implicant_family
+
+///Builds the family of the prime implicants of a truth table.  A prime implicant is an implicant with no removable
+///literal, so the primes are exactly the minimal sets of the implicant family.
+pub fn build_prime_implicant_family(manager : &mut ZddManager, truth_table : u32, num_booleans : u32) -> ZddNodeId {
+    let implicant_family = build_implicant_family(manager, truth_table, num_booleans);
+    manager.get_minimal_sets(implicant_family)
+} //End build_prime_implicant_family
+
+///Builds the family of every set of prime implicants whose disjunction is the truth table.  Element i stands for the
+///prime implicant prime_implicant_list[i - 1].  Use get_minimal_sets on the result to get the irredundant covers.
+///Return value: (the cover family, the prime implicants as lists of literals)
+pub fn build_prime_cover_family(manager : &mut ZddManager, truth_table : u32, num_booleans : u32)
+    -> (ZddNodeId, Vec<Vec<u32>>)
+{
+    let truth_table = truth_table & get_truth_table_mask(num_booleans);
+    let prime_family = build_prime_implicant_family(manager, truth_table, num_booleans);
+    let prime_implicant_list : Vec<Vec<u32>> = manager.get_sets(prime_family).iter()
+        .map(|element_list| element_list.iter().map(|element| get_literal_from_element(*element)).collect())
+        .collect();
+    let prime_truth_table_list : Vec<u32> = prime_implicant_list.iter()
+        .map(|prime_implicant| get_cube_truth_table(prime_implicant, num_booleans)).collect();
+
+    //Every true row needs one of the primes that covers it.  Join the choices for every row.
+    let all_primes : Vec<u32> = (1..=prime_implicant_list.len() as u32).collect();
+    let mut cover_family = manager.get_power_set(&all_primes);
+    for row in 0..get_num_truth_table_rows(num_booleans) {
+        if !is_truth_table_true_in_row(truth_table, row) {continue;}
+
+        let mut row_choices = ZDD_EMPTY;
+        for (prime_index, prime_truth_table) in prime_truth_table_list.iter().enumerate() {
+            if is_truth_table_true_in_row(*prime_truth_table, row) {
+                let single_set = manager.get_single_set(&[prime_index as u32 + 1]);
+                row_choices = manager.union(row_choices, single_set);
+            }
+        } //End for each prime implicant
+        cover_family = manager.get_supersets(cover_family, row_choices);
+    } //End for each row
+
+    (cover_family, prime_implicant_list)
+} //End build_prime_cover_family
+
+
+//PRIVATE//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+///The element stored in the terminal nodes.  It's larger than every real element.
+const TERMINAL_ELEMENT : u32 = u32::MAX;
+
+#[derive(Clone, Copy)]
+struct ZddNode {
+    element : u32,
+    low : ZddNodeId,
+    high : ZddNodeId
+} //End struct ZddNode
+
+//The operations that share the computed cache.  Operations with one family use ZDD_EMPTY as the second key.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum ZddOperator {
+    Union,
+    Intersection,
+    Difference,
+    NonSupersets,
+    Minimal,
+    Subset1(u32),
+    Change(u32)
+} //End enum ZddOperator
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    const NUM_TEST_ELEMENTS : u32 = 4;
+    const NUM_TEST_FAMILIES : usize = 24;
+    const NUM_TEST_BOOLEANS : u32 = 3;
+
+    type SetFamily = BTreeSet<Vec<u32>>;
+
+    //Gets the sets of elements 1..=NUM_TEST_ELEMENTS whose bits are set in a mask of the 2^NUM_TEST_ELEMENTS subsets.
+    fn get_family_from_mask(family_mask : u32) -> SetFamily {
+        let mut family = SetFamily::new();
+        for subset_mask in 0..(1u32 << NUM_TEST_ELEMENTS) {
+            if family_mask & (1 << subset_mask) != 0 {
+                family.insert((1..=NUM_TEST_ELEMENTS).filter(|element| subset_mask & (1 << (element - 1)) != 0)
+                    .collect());
+            }
+        }
+        family
+    } //End get_family_from_mask
+
+    //Gets a fixed spread of families, including the empty family and the family of every set.
+    fn get_test_families() -> Vec<SetFamily> {
+        let all_sets_mask = (1u32 << (1 << NUM_TEST_ELEMENTS)) - 1;
+        let mut family_list = vec![get_family_from_mask(0), get_family_from_mask(1), get_family_from_mask(all_sets_mask)];
+        let mut state : u32 = 12345;
+        while family_list.len() < NUM_TEST_FAMILIES {
+            state = state.wrapping_mul(1103515245).wrapping_add(12345);
+            family_list.push(get_family_from_mask(state >> 16));
+        }
+        family_list
+    } //End get_test_families
+
+    fn get_set_family(manager : &ZddManager, family : ZddNodeId) -> SetFamily {
+        manager.get_sets(family).into_iter().collect()
+    }
+
+    fn build_family(manager : &mut ZddManager, family : &SetFamily) -> ZddNodeId {
+        let mut result = ZDD_EMPTY;
+        for set in family {
+            let single_set = manager.get_single_set(set);
+            result = manager.union(result, single_set);
+        }
+        result
+    }
+
+    #[test]
+    fn families_round_trip() {
+        let mut manager = ZddManager::new();
+        for family in get_test_families() {
+            let zdd = build_family(&mut manager, &family);
+            assert_eq!(get_set_family(&manager, zdd), family);
+            assert_eq!(manager.count_sets(zdd), Some(family.len() as u128));
+        }
+    }
+
+    #[test]
+    fn set_operations_match_brute_force() {
+        let mut manager = ZddManager::new();
+        let family_list = get_test_families();
+        for left_family in &family_list {
+            for right_family in &family_list {
+                let left = build_family(&mut manager, left_family);
+                let right = build_family(&mut manager, right_family);
+
+                let union = manager.union(left, right);
+                let intersection = manager.intersection(left, right);
+                let difference = manager.difference(left, right);
+                assert_eq!(get_set_family(&manager, union), left_family | right_family);
+                assert_eq!(get_set_family(&manager, intersection), left_family & right_family);
+                assert_eq!(get_set_family(&manager, difference), left_family - right_family);
+            } //End for each right family
+        } //End for each left family
+    }
+
+    #[test]
+    fn filters_match_brute_force() {
+        let mut manager = ZddManager::new();
+        for family in get_test_families() {
+            let zdd = build_family(&mut manager, &family);
+            for element in 1..=NUM_TEST_ELEMENTS {
+                let sets_containing = manager.get_sets_containing(zdd, element);
+                let expected_sets_containing : SetFamily = family.iter().filter(|set| set.contains(&element))
+                    .cloned().collect();
+                assert_eq!(get_set_family(&manager, sets_containing), expected_sets_containing);
+            }
+
+            let smallest_sets = manager.get_smallest_sets(zdd);
+            let smallest_size = family.iter().map(|set| set.len()).min();
+            let expected_smallest_sets : SetFamily = family.iter().filter(|set| Some(set.len()) == smallest_size)
+                .cloned().collect();
+            assert_eq!(get_set_family(&manager, smallest_sets), expected_smallest_sets);
+        } //End for each family
+    }
+
+    #[test]
+    fn samples_come_from_the_family() {
+        let mut manager = ZddManager::new();
+        for family in get_test_families() {
+            let zdd = build_family(&mut manager, &family);
+            let sample_option = manager.sample_sets(zdd, 8, 42);
+            if family.is_empty() {
+                assert!(sample_option.is_none());
+                continue;
+            }
+
+            let sample_vec = sample_option.unwrap();
+            assert_eq!(sample_vec.len(), 8);
+            assert!(sample_vec.iter().all(|set| family.contains(set)));
+            assert_eq!(manager.sample_sets(zdd, 8, 42), Some(sample_vec));
+        } //End for each family
+    }
+
+    //Gets the prime implicants of a truth table by checking every cube, as sorted lists of literal elements.
+    fn get_brute_force_prime_implicants(truth_table : u32) -> SetFamily {
+        let is_implicant = |cube : &[u32]| get_cube_truth_table(cube, NUM_TEST_BOOLEANS) & !truth_table == 0;
+        let mut prime_family = SetFamily::new();
+        for cube_element in 0..3u32.pow(NUM_TEST_BOOLEANS) {
+            let cube = get_clause_from_element(cube_element, NUM_TEST_BOOLEANS);
+            if !is_implicant(&cube) {continue;}
+
+            let is_prime = (0..cube.len()).all(|removed_index| {
+                let smaller_cube : Vec<u32> = cube.iter().enumerate().filter(|(index, _)| *index != removed_index)
+                    .map(|(_, literal)| *literal).collect();
+                !is_implicant(&smaller_cube)
+            });
+            if is_prime {
+                let mut element_list : Vec<u32> = cube.iter().map(|literal| get_literal_element(*literal)).collect();
+                element_list.sort();
+                prime_family.insert(element_list);
+            }
+        } //End for each cube
+        prime_family
+    } //End get_brute_force_prime_implicants
+
+    #[test]
+    fn prime_implicants_match_brute_force() {
+        let mut manager = ZddManager::new();
+        for truth_table in 0..=get_truth_table_mask(NUM_TEST_BOOLEANS) {
+            let prime_family = build_prime_implicant_family(&mut manager, truth_table, NUM_TEST_BOOLEANS);
+            assert_eq!(get_set_family(&manager, prime_family), get_brute_force_prime_implicants(truth_table));
+        }
+    }
+
+    #[test]
+    fn prime_covers_match_brute_force() {
+        let mut manager = ZddManager::new();
+        for truth_table in 0..=get_truth_table_mask(NUM_TEST_BOOLEANS) {
+            let (cover_family, prime_implicant_list) =
+                build_prime_cover_family(&mut manager, truth_table, NUM_TEST_BOOLEANS);
+            let prime_element_family : SetFamily = prime_implicant_list.iter()
+                .map(|prime_implicant| prime_implicant.iter().map(|literal| get_literal_element(*literal)).collect())
+                .collect();
+            assert_eq!(prime_element_family, get_brute_force_prime_implicants(truth_table));
+
+            //A set of primes is a cover when the disjunction of the primes is the truth table.
+            let mut expected_covers = SetFamily::new();
+            for prime_mask in 0..(1u32 << prime_implicant_list.len()) {
+                let mut cover_truth_table = 0;
+                let mut cover = Vec::new();
+                for (prime_index, prime_implicant) in prime_implicant_list.iter().enumerate() {
+                    if prime_mask & (1 << prime_index) != 0 {
+                        let prime_truth_table = get_cube_truth_table(prime_implicant, NUM_TEST_BOOLEANS);
+                        cover_truth_table = cover_truth_table | prime_truth_table;
+                        cover.push(prime_index as u32 + 1);
+                    }
+                }
+                if cover_truth_table == truth_table {expected_covers.insert(cover);}
+            } //End for each set of primes
+            assert_eq!(get_set_family(&manager, cover_family), expected_covers);
+
+            let expected_irredundant_covers : SetFamily = expected_covers.iter()
+                .filter(|cover| !expected_covers.iter().any(|other| other != *cover
+                    && other.iter().all(|prime| cover.contains(prime))))
+                .cloned().collect();
+            let irredundant_covers = manager.get_minimal_sets(cover_family);
+            assert_eq!(get_set_family(&manager, irredundant_covers), expected_irredundant_covers);
+        } //End for each truth table
+    }
+} //End mod tests